@@ -37,6 +37,10 @@ pub enum ImageError {
     #[error("Pixel coordinate ({0}, {1}) is out of bounds ({2}, {3})")]
     PixelIndexOutOfBounds(usize, usize, usize, usize),
 
+    /// Error when a rectangle exceeds the image bounds.
+    #[error("Rectangle ({0}, {1}, {2}, {3}) is out of bounds ({4}, {5})")]
+    OutOfBounds(usize, usize, usize, usize, usize, usize),
+
     /// Error when the number of bins is invalid.
     #[error("Invalid number of bins {0}")]
     InvalidHistogramBins(usize),
@@ -45,6 +49,10 @@ pub enum ImageError {
     #[error("Cannot compute the determinant: matrix is singular")]
     CannotComputeDeterminant,
 
+    /// Error when the aspect ratio has a zero term.
+    #[error("Invalid aspect ratio {0}:{1}")]
+    InvalidAspectRatio(usize, usize),
+
     /// Error when the kernel length is invalid.
     #[error("Invalid kernel length {0} and {1}")]
     InvalidKernelLength(usize, usize),
@@ -1,6 +1,7 @@
-use kornia_tensor::{CpuAllocator, Tensor, Tensor2, Tensor3};
+use kornia_tensor::{storage::TensorStorage, CpuAllocator, Tensor, Tensor2, Tensor3};
 
 use crate::error::ImageError;
+use crate::view::{ImageView, Rect};
 
 /// Image size in pixels
 ///
@@ -52,6 +53,59 @@ impl From<ImageSize> for [u32; 2] {
     }
 }
 
+/// Interpolation mode for the resize operation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationMode {
+    /// Bilinear interpolation
+    Bilinear,
+    /// Nearest neighbor interpolation
+    Nearest,
+}
+
+/// Border mode used to fill the pixels outside an image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BorderMode<T> {
+    /// Fill with a constant value on all the channels.
+    Constant(T),
+    /// Mirror the image at its edges, repeating the edge pixels: `cba|abcd|dcb`.
+    Reflect,
+}
+
+/// Addition saturating at the bounds of the integer types.
+///
+/// The floating point types have no bounds to saturate at and use the plain
+/// addition, where e.g. infinity stays infinity.
+pub trait SaturatingAdd: Copy {
+    /// Add `rhs` to the value, saturating at the bounds of the type.
+    fn saturating_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_saturating_add_int {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingAdd for $t {
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_add_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl SaturatingAdd for f32 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl SaturatingAdd for f64 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
 #[derive(Clone)]
 /// Represents an image with pixel data.
 ///
@@ -277,7 +331,7 @@ impl<T, const C: usize> Image<T, C> {
     ///
     /// # Returns
     ///
-    /// A vector of images, each containing one channel of the original image.
+    /// An array of images, each containing one channel of the original image.
     ///
     /// # Examples
     ///
@@ -291,20 +345,257 @@ impl<T, const C: usize> Image<T, C> {
     /// },
     /// 0.0f32).unwrap();
     ///
-    /// let channels = image.split_channels().unwrap();
+    /// let channels = image.split_channels();
     /// assert_eq!(channels.len(), 2);
     /// ```
-    pub fn split_channels(&self) -> Result<Vec<Image<T, 1>>, ImageError>
+    pub fn split_channels(&self) -> [Image<T, 1>; C]
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let data = self.as_slice();
+
+        std::array::from_fn(|ch| {
+            Image(Tensor::from_shape_fn(
+                [rows, cols, 1],
+                CpuAllocator,
+                |[r, c, _]| data[(r * cols + c) * C + ch].clone(),
+            ))
+        })
+    }
+
+    /// Merge single channel images into a multi-channel image.
+    ///
+    /// This is the inverse of [`Image::split_channels`].
+    ///
+    /// # Arguments
+    ///
+    /// * `planes` - The images with the channels, all with the same size.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the channels interleaved.
+    ///
+    /// # Errors
+    ///
+    /// If the planes do not have the same size, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let size = ImageSize {
+    ///     width: 2,
+    ///     height: 1,
+    /// };
+    /// let r = Image::<u8, 1>::new(size, vec![1, 2]).unwrap();
+    /// let g = Image::<u8, 1>::new(size, vec![3, 4]).unwrap();
+    ///
+    /// let image = Image::<u8, 2>::merge_channels(&[r, g]).unwrap();
+    /// assert_eq!(image.as_slice(), &[1, 3, 2, 4]);
+    /// ```
+    pub fn merge_channels(planes: &[Image<T, 1>; C]) -> Result<Image<T, C>, ImageError>
+    where
+        T: Clone,
+    {
+        let size = planes
+            .first()
+            .ok_or(ImageError::ImageDataNotInitialized)?
+            .size();
+
+        if let Some(plane) = planes.iter().find(|plane| plane.size() != size) {
+            return Err(ImageError::InvalidImageSize(
+                size.width,
+                size.height,
+                plane.width(),
+                plane.height(),
+            ));
+        }
+
+        let data = (0..size.width * size.height)
+            .flat_map(|i| planes.iter().map(move |plane| plane.as_slice()[i].clone()))
+            .collect();
+
+        Image::new(size, data)
+    }
+
+    /// Concatenate images side by side from left to right.
+    ///
+    /// # Arguments
+    ///
+    /// * `images` - The images to concatenate, all with the same height.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the sum of the widths of the images.
+    ///
+    /// # Errors
+    ///
+    /// If no images are given or the images do not have the same height, an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let a = Image::<u8, 1>::new(ImageSize { width: 1, height: 2 }, vec![1, 2]).unwrap();
+    /// let b = Image::<u8, 1>::new(ImageSize { width: 2, height: 2 }, vec![3, 4, 5, 6]).unwrap();
+    ///
+    /// let image = Image::concat_horizontal(&[a, b]).unwrap();
+    /// assert_eq!(image.as_slice(), &[1, 3, 4, 2, 5, 6]);
+    /// ```
+    pub fn concat_horizontal(images: &[Image<T, C>]) -> Result<Image<T, C>, ImageError>
+    where
+        T: Clone,
+    {
+        let first = images.first().ok_or(ImageError::ImageDataNotInitialized)?;
+        let height = first.height();
+
+        if let Some(image) = images.iter().find(|image| image.height() != height) {
+            return Err(ImageError::InvalidImageSize(
+                first.width(),
+                height,
+                image.width(),
+                image.height(),
+            ));
+        }
+
+        let width = images.iter().map(|image| image.width()).sum::<usize>();
+
+        // copy each row of the sources one after the other
+        let mut data = Vec::with_capacity(width * height * C);
+        for y in 0..height {
+            for image in images {
+                let row_len = image.width() * C;
+                data.extend_from_slice(&image.as_slice()[y * row_len..(y + 1) * row_len]);
+            }
+        }
+
+        Ok(Image::from_pixel_data(ImageSize { width, height }, data))
+    }
+
+    /// Concatenate images on top of each other from top to bottom.
+    ///
+    /// # Arguments
+    ///
+    /// * `images` - The images to concatenate, all with the same width.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the sum of the heights of the images.
+    ///
+    /// # Errors
+    ///
+    /// If no images are given or the images do not have the same width, an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let a = Image::<u8, 1>::new(ImageSize { width: 2, height: 1 }, vec![1, 2]).unwrap();
+    /// let b = Image::<u8, 1>::new(ImageSize { width: 2, height: 2 }, vec![3, 4, 5, 6]).unwrap();
+    ///
+    /// let image = Image::concat_vertical(&[a, b]).unwrap();
+    /// assert_eq!(image.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn concat_vertical(images: &[Image<T, C>]) -> Result<Image<T, C>, ImageError>
+    where
+        T: Clone,
+    {
+        let first = images.first().ok_or(ImageError::ImageDataNotInitialized)?;
+        let width = first.width();
+
+        if let Some(image) = images.iter().find(|image| image.width() != width) {
+            return Err(ImageError::InvalidImageSize(
+                width,
+                first.height(),
+                image.width(),
+                image.height(),
+            ));
+        }
+
+        let height = images.iter().map(|image| image.height()).sum::<usize>();
+
+        // the rows of the sources are already in order in memory
+        let mut data = Vec::with_capacity(width * height * C);
+        for image in images {
+            data.extend_from_slice(image.as_slice());
+        }
+
+        Ok(Image::from_pixel_data(ImageSize { width, height }, data))
+    }
+
+    /// Convert the image to a planar buffer in channel-major (CHW) order.
+    ///
+    /// The pixels of the image are stored interleaved (HWC). The returned
+    /// buffer has all the values of the first channel in row-major order,
+    /// followed by the values of the second channel and so on, as expected by
+    /// most deep learning frameworks.
+    ///
+    /// # Returns
+    ///
+    /// A buffer with `C * H * W` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 2>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![1, 3, 2, 4],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(image.to_chw(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn to_chw(&self) -> Vec<T>
     where
-        T: Clone + Copy, // TODO: remove this bound
+        T: Clone,
     {
-        let mut channels = Vec::with_capacity(C);
+        let data = self.as_slice();
+        (0..C)
+            .flat_map(|ch| data.iter().skip(ch).step_by(C).cloned())
+            .collect()
+    }
 
-        for i in 0..C {
-            channels.push(self.channel(i)?);
+    /// Create an image from a planar buffer in channel-major (CHW) order.
+    ///
+    /// This is the inverse of [`Image::to_chw`].
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The values of each channel in row-major order, one channel after the other.
+    /// * `size` - The size of the image in pixels.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the channels interleaved.
+    ///
+    /// # Errors
+    ///
+    /// If the length of the buffer is not `C * H * W`, an error is returned.
+    pub fn from_chw(data: &[T], size: ImageSize) -> Result<Image<T, C>, ImageError>
+    where
+        T: Clone,
+    {
+        let plane_len = size.width * size.height;
+        if data.len() != plane_len * C {
+            return Err(ImageError::InvalidChannelShape(data.len(), plane_len * C));
         }
 
-        Ok(channels)
+        Ok(Image(Tensor::from_shape_fn(
+            [size.height, size.width, C],
+            CpuAllocator,
+            |[r, c, ch]| data[ch * plane_len + r * size.width + c].clone(),
+        )))
     }
 
     /// Get the size of the image in pixels.
@@ -486,69 +777,1439 @@ impl<T, const C: usize> Image<T, C> {
 
         Ok(())
     }
-}
 
-/// helper to convert an single channel tensor to a kornia image with try into
-impl<T> TryFrom<Tensor2<T, CpuAllocator>> for Image<T, 1>
-where
-    T: Clone,
-{
-    type Error = ImageError;
+    /// Crop a rectangular region of the image.
+    ///
+    /// The pixels of the region are copied into a new contiguous image.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the top-left corner of the region.
+    /// * `y` - The y-coordinate of the top-left corner of the region.
+    /// * `width` - The width of the region.
+    /// * `height` - The height of the region.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the cropped region.
+    ///
+    /// # Errors
+    ///
+    /// If the region exceeds the image bounds, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 3,
+    ///         height: 2,
+    ///     },
+    ///     vec![0, 1, 2, 3, 4, 5],
+    /// )
+    /// .unwrap();
+    ///
+    /// let cropped = image.crop(1, 0, 2, 2).unwrap();
+    /// assert_eq!(cropped.as_slice(), &[1, 2, 4, 5]);
+    /// ```
+    pub fn crop(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<Image<T, C>, ImageError>
+    where
+        T: Clone,
+    {
+        let exceeds = |start: usize, len: usize, max: usize| {
+            start.checked_add(len).map_or(true, |end| end > max)
+        };
 
-    fn try_from(value: Tensor2<T, CpuAllocator>) -> Result<Self, Self::Error> {
-        Self::from_size_slice(
-            ImageSize {
-                width: value.shape[1],
-                height: value.shape[0],
-            },
-            value.as_slice(),
-        )
-    }
-}
+        if exceeds(x, width, self.width()) || exceeds(y, height, self.height()) {
+            return Err(ImageError::OutOfBounds(
+                x,
+                y,
+                width,
+                height,
+                self.width(),
+                self.height(),
+            ));
+        }
 
-/// helper to convert an multi channel tensor to a kornia image with try into
-impl<T, const C: usize> TryFrom<Tensor3<T, CpuAllocator>> for Image<T, C> {
-    type Error = ImageError;
+        let row_len = self.width() * C;
+        let mut data = Vec::with_capacity(width * height * C);
 
-    fn try_from(value: Tensor3<T, CpuAllocator>) -> Result<Self, Self::Error> {
-        if value.shape[2] != C {
-            return Err(ImageError::InvalidChannelShape(value.shape[2], C));
+        // an image without columns has no rows to copy from
+        if row_len > 0 {
+            for row in self.as_slice().chunks_exact(row_len).skip(y).take(height) {
+                data.extend_from_slice(&row[x * C..(x + width) * C]);
+            }
         }
-        Ok(Self(value))
-    }
-}
 
-impl<T, const C: usize> TryInto<Tensor3<T, CpuAllocator>> for Image<T, C> {
-    type Error = ImageError;
+        Image::new(ImageSize { width, height }, data)
+    }
 
-    fn try_into(self) -> Result<Tensor3<T, CpuAllocator>, Self::Error> {
-        Ok(self.0)
+    /// Get a borrowed view over the whole image.
+    ///
+    /// # Returns
+    ///
+    /// A view sharing the pixel data of the image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 4, height: 2 }, 0).unwrap();
+    ///
+    /// let view = image.view();
+    /// assert_eq!(view.size(), image.size());
+    /// assert_eq!(view.stride(), 12);
+    /// ```
+    pub fn view(&self) -> ImageView<'_, T, C> {
+        self.view_region(Rect {
+            x: 0,
+            y: 0,
+            width: self.width(),
+            height: self.height(),
+        })
+        .expect("the image covers its own bounds")
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::image::{Image, ImageError, ImageSize};
-    use kornia_tensor::{CpuAllocator, Tensor};
+    /// Get a borrowed view over a rectangular region of the image.
+    ///
+    /// Unlike [`Image::crop`], the pixels are not copied: the view points into
+    /// the image data and keeps the row stride of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The region of the view.
+    ///
+    /// # Returns
+    ///
+    /// A view sharing the pixel data of the region.
+    ///
+    /// # Errors
+    ///
+    /// If the region exceeds the image bounds, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize, Rect};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 3,
+    ///         height: 2,
+    ///     },
+    ///     vec![0, 1, 2, 3, 4, 5],
+    /// )
+    /// .unwrap();
+    ///
+    /// let rect = Rect { x: 1, y: 0, width: 2, height: 2 };
+    /// let view = image.view_region(rect).unwrap();
+    /// assert_eq!(view.row(1), Some(&[4, 5][..]));
+    /// assert_eq!(view.stride(), 3);
+    /// ```
+    pub fn view_region(&self, rect: Rect) -> Result<ImageView<'_, T, C>, ImageError> {
+        ImageView::from_region(self.as_slice(), self.size(), rect)
+    }
 
-    #[test]
-    fn test_image_size() {
-        let image_size = ImageSize {
-            width: 10,
-            height: 20,
+    /// Pad the image on each side.
+    ///
+    /// # Arguments
+    ///
+    /// * `top` - The number of rows to add above the image.
+    /// * `bottom` - The number of rows to add below the image.
+    /// * `left` - The number of columns to add on the left of the image.
+    /// * `right` - The number of columns to add on the right of the image.
+    /// * `mode` - The border mode used to fill the added pixels.
+    ///
+    /// # Returns
+    ///
+    /// A new image with size (W + left + right, H + top + bottom).
+    ///
+    /// # Panics
+    ///
+    /// Panics in reflect mode if the image is empty and the padded image is not,
+    /// as there are no pixels to mirror.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{BorderMode, Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![1, 2],
+    /// )
+    /// .unwrap();
+    ///
+    /// let padded = image.pad(0, 0, 1, 1, BorderMode::Constant(0));
+    /// assert_eq!(padded.as_slice(), &[0, 1, 2, 0]);
+    ///
+    /// let padded = image.pad(0, 0, 1, 1, BorderMode::Reflect);
+    /// assert_eq!(padded.as_slice(), &[1, 1, 2, 2]);
+    /// ```
+    pub fn pad(
+        &self,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+        mode: BorderMode<T>,
+    ) -> Image<T, C>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let data = self.as_slice();
+
+        // map a padded coordinate to the source mirroring at the edges
+        let reflect = |i: usize, pad: usize, len: usize| {
+            let i = (i as isize - pad as isize).rem_euclid(2 * len as isize) as usize;
+            if i < len {
+                i
+            } else {
+                2 * len - 1 - i
+            }
         };
-        assert_eq!(image_size.width, 10);
-        assert_eq!(image_size.height, 20);
-    }
 
-    #[test]
-    fn test_image_smoke() -> Result<(), ImageError> {
-        let image = Image::<u8, 3>::new(
-            ImageSize {
-                width: 10,
-                height: 20,
+        Image(Tensor::from_shape_fn(
+            [top + rows + bottom, left + cols + right, C],
+            CpuAllocator,
+            |[r, c, ch]| match &mode {
+                BorderMode::Constant(value) => {
+                    if (top..top + rows).contains(&r) && (left..left + cols).contains(&c) {
+                        data[((r - top) * cols + c - left) * C + ch].clone()
+                    } else {
+                        value.clone()
+                    }
+                }
+                BorderMode::Reflect => {
+                    let (y, x) = (reflect(r, top, rows), reflect(c, left, cols));
+                    data[(y * cols + x) * C + ch].clone()
+                }
             },
-            vec![0u8; 10 * 20 * 3],
+        ))
+    }
+
+    /// Flip the image horizontally (mirror around the vertical axis).
+    ///
+    /// The order of the channels within each pixel is preserved.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the columns in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 2>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![0, 1, 2, 3],
+    /// )
+    /// .unwrap();
+    ///
+    /// let flipped = image.flip_horizontal();
+    /// assert_eq!(flipped.as_slice(), &[2, 3, 0, 1]);
+    /// ```
+    pub fn flip_horizontal(&self) -> Image<T, C>
+    where
+        T: Clone,
+    {
+        let mut flipped = self.clone();
+        let row_len = self.cols() * C;
+        if row_len == 0 {
+            return flipped;
+        }
+
+        // reverse the whole row and then each pixel back to keep the channel order
+        flipped
+            .as_slice_mut()
+            .chunks_exact_mut(row_len)
+            .for_each(|row| {
+                row.reverse();
+                row.chunks_exact_mut(C).for_each(|pixel| pixel.reverse());
+            });
+
+        flipped
+    }
+
+    /// Flip the image vertically (mirror around the horizontal axis).
+    ///
+    /// # Returns
+    ///
+    /// A new image with the rows in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 2>::new(
+    ///     ImageSize {
+    ///         width: 1,
+    ///         height: 2,
+    ///     },
+    ///     vec![0, 1, 2, 3],
+    /// )
+    /// .unwrap();
+    ///
+    /// let flipped = image.flip_vertical();
+    /// assert_eq!(flipped.as_slice(), &[2, 3, 0, 1]);
+    /// ```
+    pub fn flip_vertical(&self) -> Image<T, C>
+    where
+        T: Clone,
+    {
+        let mut flipped = self.clone();
+        let row_len = self.cols() * C;
+        if row_len == 0 {
+            return flipped;
+        }
+
+        flipped
+            .as_slice_mut()
+            .chunks_exact_mut(row_len)
+            .zip(self.as_slice().chunks_exact(row_len).rev())
+            .for_each(|(dst_row, src_row)| dst_row.clone_from_slice(src_row));
+
+        flipped
+    }
+
+    /// Rotate the image by 90 degrees clockwise.
+    ///
+    /// The rotation is exact and the order of the channels within each pixel is
+    /// preserved. The width and height of the image are swapped.
+    ///
+    /// # Returns
+    ///
+    /// A new image with shape (W, H, C).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 2,
+    ///     },
+    ///     vec![0, 1, 2, 3],
+    /// )
+    /// .unwrap();
+    ///
+    /// let rotated = image.rotate_90();
+    /// assert_eq!(rotated.as_slice(), &[2, 0, 3, 1]);
+    /// ```
+    pub fn rotate_90(&self) -> Image<T, C>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let data = self.as_slice();
+
+        Image(Tensor::from_shape_fn(
+            [cols, rows, C],
+            CpuAllocator,
+            |[r, c, ch]| data[((rows - 1 - c) * cols + r) * C + ch].clone(),
+        ))
+    }
+
+    /// Rotate the image by 180 degrees.
+    ///
+    /// The rotation is exact and the order of the channels within each pixel is
+    /// preserved.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the same shape.
+    pub fn rotate_180(&self) -> Image<T, C>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let data = self.as_slice();
+
+        Image(Tensor::from_shape_fn(
+            [rows, cols, C],
+            CpuAllocator,
+            |[r, c, ch]| data[((rows - 1 - r) * cols + cols - 1 - c) * C + ch].clone(),
+        ))
+    }
+
+    /// Rotate the image by 270 degrees clockwise, i.e. 90 degrees counterclockwise.
+    ///
+    /// The rotation is exact and the order of the channels within each pixel is
+    /// preserved. The width and height of the image are swapped.
+    ///
+    /// # Returns
+    ///
+    /// A new image with shape (W, H, C).
+    pub fn rotate_270(&self) -> Image<T, C>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let data = self.as_slice();
+
+        Image(Tensor::from_shape_fn(
+            [cols, rows, C],
+            CpuAllocator,
+            |[r, c, ch]| data[(c * cols + cols - 1 - r) * C + ch].clone(),
+        ))
+    }
+
+    /// Check whether the pixels of the image are tightly packed in memory.
+    ///
+    /// An image is contiguous when its rows follow each other without padding,
+    /// so that `as_slice` returns exactly `width * height * C` values in
+    /// row-major order.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the image data is contiguous, `false` otherwise.
+    pub fn is_contiguous(&self) -> bool {
+        let (rows, cols) = (self.rows(), self.cols());
+        self.strides == [cols * C, C, 1] && self.as_slice().len() == rows * cols * C
+    }
+
+    /// Pack the pixels of a possibly strided image into contiguous storage.
+    ///
+    /// Images wrapping a sliced buffer, e.g. with padding at the end of each
+    /// row, are copied into a new image with tightly packed rows.
+    ///
+    /// # Returns
+    ///
+    /// A new contiguous image with the same size and pixel values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 2,
+    ///     },
+    ///     vec![0, 1, 2, 3],
+    /// )
+    /// .unwrap();
+    ///
+    /// let packed = image.to_contiguous();
+    /// assert!(packed.is_contiguous());
+    /// assert_eq!(packed.as_slice(), image.as_slice());
+    /// ```
+    pub fn to_contiguous(&self) -> Image<T, C>
+    where
+        T: Clone,
+    {
+        if self.is_contiguous() {
+            return self.clone();
+        }
+
+        let data = self.as_slice();
+        let [row_stride, col_stride, ch_stride] = self.strides;
+
+        Image(Tensor::from_shape_fn(
+            [self.rows(), self.cols(), C],
+            CpuAllocator,
+            |[r, c, ch]| data[r * row_stride + c * col_stride + ch * ch_stride].clone(),
+        ))
+    }
+
+    /// Fill a rectangle of the image with a color inplace.
+    ///
+    /// The rectangle is clipped to the image bounds, so it can be partially or
+    /// completely outside of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the top-left corner of the rectangle.
+    /// * `y` - The y-coordinate of the top-left corner of the rectangle.
+    /// * `w` - The width of the rectangle.
+    /// * `h` - The height of the rectangle.
+    /// * `color` - The color of the rectangle as an array of `C` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let mut image = Image::<u8, 1>::from_size_val(
+    ///     ImageSize {
+    ///         width: 3,
+    ///         height: 2,
+    ///     },
+    ///     0,
+    /// )
+    /// .unwrap();
+    ///
+    /// image.fill_rect(1, 0, 5, 1, [9]);
+    /// assert_eq!(image.as_slice(), &[0, 9, 9, 0, 0, 0]);
+    /// ```
+    pub fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: [T; C])
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let (x1, y1) = (x.saturating_add(w).min(cols), y.saturating_add(h).min(rows));
+        if x >= x1 || y >= y1 {
+            return;
+        }
+
+        self.as_slice_mut()
+            .chunks_exact_mut(cols * C)
+            .skip(y)
+            .take(y1 - y)
+            .for_each(|row| {
+                row[x * C..x1 * C]
+                    .chunks_exact_mut(C)
+                    .for_each(|pixel| pixel.clone_from_slice(&color));
+            });
+    }
+
+    /// Draw the outline of a rectangle on the image inplace.
+    ///
+    /// The border is drawn inside the rectangle, i.e. it covers the `thickness`
+    /// outermost rows and columns of the rectangle, and it is clipped to the
+    /// image bounds. A border thicker than half the rectangle fills it.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the top-left corner of the rectangle.
+    /// * `y` - The y-coordinate of the top-left corner of the rectangle.
+    /// * `w` - The width of the rectangle.
+    /// * `h` - The height of the rectangle.
+    /// * `color` - The color of the border as an array of `C` elements.
+    /// * `thickness` - The thickness of the border in pixels.
+    pub fn draw_rect(
+        &mut self,
+        x: usize,
+        y: usize,
+        w: usize,
+        h: usize,
+        color: [T; C],
+        thickness: usize,
+    ) where
+        T: Clone,
+    {
+        let (tx, ty) = (thickness.min(w), thickness.min(h));
+
+        // top and bottom bands across the whole width
+        self.fill_rect(x, y, w, ty, color.clone());
+        self.fill_rect(x, (y + h).saturating_sub(ty), w, ty, color.clone());
+
+        // left and right bands between the top and bottom ones
+        let inner_h = h.saturating_sub(2 * ty);
+        self.fill_rect(x, y + ty, tx, inner_h, color.clone());
+        self.fill_rect((x + w).saturating_sub(tx), y + ty, tx, inner_h, color);
+    }
+
+    /// Apply a function to each pixel of the image producing a new image.
+    ///
+    /// Unlike the element-wise `Tensor::map` of the inner tensor, which stays
+    /// available as `image.map(...)`, the function receives all the channels of
+    /// a pixel at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function mapping the channels of a pixel to the channels of the output pixel.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the same size and the mapped pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 2>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![0, 1, 2, 3],
+    /// )
+    /// .unwrap();
+    ///
+    /// let mapped = image.map_pixels(|[a, b]| [a as f32 * 0.5, b as f32]);
+    /// assert_eq!(mapped.as_slice(), &[0.0, 1.0, 1.0, 3.0]);
+    /// ```
+    pub fn map_pixels<U>(&self, f: impl Fn([T; C]) -> [U; C]) -> Image<U, C>
+    where
+        T: Clone,
+    {
+        let data = self
+            .as_slice()
+            .chunks_exact(C)
+            .flat_map(|pixel| f(std::array::from_fn(|ch| pixel[ch].clone())))
+            .collect();
+
+        Image::from_pixel_data(self.size(), data)
+    }
+
+    /// Combine the pixels of two images of the same size producing a new image.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The image to combine with, with the same size as this image.
+    /// * `f` - The function combining the channels of a pixel of each image into
+    ///   the channels of the output pixel.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the combined pixels.
+    ///
+    /// # Errors
+    ///
+    /// If the images do not have the same size, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let size = ImageSize {
+    ///     width: 2,
+    ///     height: 1,
+    /// };
+    /// let a = Image::<u8, 1>::new(size, vec![10, 20]).unwrap();
+    /// let b = Image::<u8, 1>::new(size, vec![1, 2]).unwrap();
+    ///
+    /// let diff = a.zip_map(&b, |[x], [y]| [x - y]).unwrap();
+    /// assert_eq!(diff.as_slice(), &[9, 18]);
+    /// ```
+    pub fn zip_map<U, V>(
+        &self,
+        other: &Image<U, C>,
+        f: impl Fn([T; C], [U; C]) -> [V; C],
+    ) -> Result<Image<V, C>, ImageError>
+    where
+        T: Clone,
+        U: Clone,
+    {
+        if other.size() != self.size() {
+            return Err(ImageError::InvalidImageSize(
+                self.width(),
+                self.height(),
+                other.width(),
+                other.height(),
+            ));
+        }
+
+        let data = self
+            .as_slice()
+            .chunks_exact(C)
+            .zip(other.as_slice().chunks_exact(C))
+            .flat_map(|(a, b)| {
+                f(
+                    std::array::from_fn(|ch| a[ch].clone()),
+                    std::array::from_fn(|ch| b[ch].clone()),
+                )
+            })
+            .collect();
+
+        Ok(Image::from_pixel_data(self.size(), data))
+    }
+
+    /// Add a value to all the pixels of the image saturating at the bounds of the type.
+    ///
+    /// The integer sums are clamped to the range of `T`, so e.g. `250u8 + 10`
+    /// gives 255 instead of wrapping around. The floating point sums follow the
+    /// IEEE 754 addition.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - The value to add to each channel of each pixel.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the saturated sums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![10, 250],
+    /// )
+    /// .unwrap();
+    ///
+    /// let brighter = image.saturating_add_scalar(10);
+    /// assert_eq!(brighter.as_slice(), &[20, 255]);
+    /// ```
+    pub fn saturating_add_scalar(&self, v: T) -> Image<T, C>
+    where
+        T: SaturatingAdd,
+    {
+        self.map_pixels(|pixel| pixel.map(|x| x.saturating_add(v)))
+    }
+
+    /// Clamp the values of all the pixels of the image to a range.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lower bound of the range.
+    /// * `max` - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the values below `min` set to `min` and the values
+    /// above `max` set to `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<f32, 1>::new(
+    ///     ImageSize {
+    ///         width: 3,
+    ///         height: 1,
+    ///     },
+    ///     vec![-0.5, 0.5, 1.5],
+    /// )
+    /// .unwrap();
+    ///
+    /// let clamped = image.clamp(0.0, 1.0);
+    /// assert_eq!(clamped.as_slice(), &[0.0, 0.5, 1.0]);
+    /// ```
+    pub fn clamp(&self, min: T, max: T) -> Image<T, C>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.map_pixels(|pixel| {
+            pixel.map(|x| {
+                if x < min {
+                    min
+                } else if x > max {
+                    max
+                } else {
+                    x
+                }
+            })
+        })
+    }
+
+    /// Compute the maximum absolute difference between the values of two images.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The image to compare with, with the same size as this image.
+    ///
+    /// # Returns
+    ///
+    /// The largest absolute difference between two corresponding values, or NaN
+    /// if any of the values is NaN.
+    ///
+    /// # Errors
+    ///
+    /// If the images do not have the same size, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let size = ImageSize {
+    ///     width: 2,
+    ///     height: 1,
+    /// };
+    /// let a = Image::<u8, 1>::new(size, vec![10, 20]).unwrap();
+    /// let b = Image::<u8, 1>::new(size, vec![13, 19]).unwrap();
+    ///
+    /// assert_eq!(a.max_abs_diff(&b).unwrap(), 3.0);
+    /// ```
+    pub fn max_abs_diff(&self, other: &Image<T, C>) -> Result<f64, ImageError>
+    where
+        T: num_traits::NumCast + Copy,
+    {
+        if other.size() != self.size() {
+            return Err(ImageError::InvalidImageSize(
+                self.width(),
+                self.height(),
+                other.width(),
+                other.height(),
+            ));
+        }
+
+        let to_f64 = |x: T| x.to_f64().unwrap_or(f64::NAN);
+
+        let max_diff = self
+            .as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .map(|(&a, &b)| (to_f64(a) - to_f64(b)).abs())
+            .fold(0.0, |max, diff| {
+                if diff.is_nan() || diff > max {
+                    diff
+                } else {
+                    max
+                }
+            });
+
+        Ok(max_diff)
+    }
+
+    /// Check whether two images are equal up to a tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The image to compare with.
+    /// * `tolerance` - The maximum absolute difference allowed between two
+    ///   corresponding values.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the images have the same size and all their values differ by
+    /// at most `tolerance`, e.g. to compare an image with its lossy encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let size = ImageSize {
+    ///     width: 2,
+    ///     height: 1,
+    /// };
+    /// let a = Image::<f32, 1>::new(size, vec![0.5, 1.0]).unwrap();
+    /// let b = Image::<f32, 1>::new(size, vec![0.51, 1.0]).unwrap();
+    ///
+    /// assert!(a.is_close(&b, 0.02));
+    /// assert!(!a.is_close(&b, 0.001));
+    /// ```
+    pub fn is_close(&self, other: &Image<T, C>, tolerance: f64) -> bool
+    where
+        T: num_traits::NumCast + Copy,
+    {
+        self.max_abs_diff(other)
+            .is_ok_and(|max_diff| max_diff <= tolerance)
+    }
+
+    // wrap pixel data whose length is known to match the size of the image
+    fn from_pixel_data(size: ImageSize, data: Vec<T>) -> Self {
+        debug_assert_eq!(data.len(), size.width * size.height * C);
+        Image(Tensor {
+            storage: TensorStorage::from_vec(data, CpuAllocator),
+            shape: [size.height, size.width, C],
+            strides: [size.width * C, C, 1],
+        })
+    }
+}
+
+impl<const C: usize> Image<u8, C> {
+    /// Resize the image to a new size.
+    ///
+    /// The pixel centers of both images are aligned and the sampling coordinates
+    /// are clamped at the image edges, so the corner pixels are preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_size` - The size of the resized image.
+    /// * `interpolation` - The interpolation mode used to sample the pixels.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the given size. If the image is empty, the resized image is filled with zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize, InterpolationMode};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![0, 100],
+    /// )
+    /// .unwrap();
+    ///
+    /// let resized = image.resize(
+    ///     ImageSize {
+    ///         width: 4,
+    ///         height: 1,
+    ///     },
+    ///     InterpolationMode::Bilinear,
+    /// );
+    /// assert_eq!(resized.as_slice(), &[0, 25, 75, 100]);
+    /// ```
+    pub fn resize(&self, new_size: ImageSize, interpolation: InterpolationMode) -> Image<u8, C> {
+        let mut resized = Image(Tensor::from_shape_val(
+            [new_size.height, new_size.width, C],
+            0u8,
+            CpuAllocator,
+        ));
+
+        let (cols, rows) = (self.cols(), self.rows());
+        if cols == 0 || rows == 0 || new_size.width == 0 || new_size.height == 0 {
+            return resized;
+        }
+
+        let scale_x = cols as f32 / new_size.width as f32;
+        let scale_y = rows as f32 / new_size.height as f32;
+
+        // map a destination coordinate to the source aligning the pixel centers
+        let src_coord = |dst: usize, scale: f32, len: usize| {
+            ((dst as f32 + 0.5) * scale - 0.5).clamp(0.0, (len - 1) as f32)
+        };
+
+        let src = self.as_slice();
+        let pixel = |x: usize, y: usize| &src[(y * cols + x) * C..(y * cols + x + 1) * C];
+
+        resized
+            .as_slice_mut()
+            .chunks_exact_mut(new_size.width * C)
+            .enumerate()
+            .for_each(|(y, row)| {
+                let sy = src_coord(y, scale_y, rows);
+                row.chunks_exact_mut(C).enumerate().for_each(|(x, dst)| {
+                    let sx = src_coord(x, scale_x, cols);
+                    match interpolation {
+                        InterpolationMode::Nearest => {
+                            dst.copy_from_slice(pixel(sx.round() as usize, sy.round() as usize));
+                        }
+                        InterpolationMode::Bilinear => {
+                            let (x0, y0) = (sx.floor() as usize, sy.floor() as usize);
+                            let (x1, y1) = ((x0 + 1).min(cols - 1), (y0 + 1).min(rows - 1));
+                            let (fx, fy) = (sx - x0 as f32, sy - y0 as f32);
+                            for (c, d) in dst.iter_mut().enumerate() {
+                                let top = pixel(x0, y0)[c] as f32 * (1.0 - fx)
+                                    + pixel(x1, y0)[c] as f32 * fx;
+                                let bottom = pixel(x0, y1)[c] as f32 * (1.0 - fx)
+                                    + pixel(x1, y1)[c] as f32 * fx;
+                                *d = (top * (1.0 - fy) + bottom * fy).round() as u8;
+                            }
+                        }
+                    }
+                });
+            });
+
+        resized
+    }
+
+    /// Sample the image at the coordinates given by a pair of remapping tables.
+    ///
+    /// Each pixel `(x, y)` of the output takes the value of the image at the
+    /// source coordinates `(map_x[y, x], map_y[y, x])`, e.g. the tables computed
+    /// from the intrinsics and the distortion coefficients of a camera to
+    /// undistort its frames. The coordinates are in pixels, with the center of
+    /// the top-left pixel at `(0, 0)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `map_x` - The x-coordinate in the image of each output pixel.
+    /// * `map_y` - The y-coordinate in the image of each output pixel.
+    /// * `interpolation` - The interpolation mode used to sample the pixels.
+    /// * `border` - How to fill the samples that fall outside the image, e.g.
+    ///   `BorderMode::Constant(0)` to fill them with black.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the size of the remapping tables.
+    ///
+    /// # Errors
+    ///
+    /// If the remapping tables do not have the same size, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{BorderMode, Image, ImageSize, InterpolationMode};
+    ///
+    /// let size = ImageSize {
+    ///     width: 3,
+    ///     height: 1,
+    /// };
+    /// let image = Image::<u8, 1>::new(size, vec![10, 20, 30]).unwrap();
+    ///
+    /// // shift the image one pixel to the left
+    /// let map_x = Image::<f32, 1>::new(size, vec![1.0, 2.0, 3.0]).unwrap();
+    /// let map_y = Image::<f32, 1>::from_size_val(size, 0.0).unwrap();
+    ///
+    /// let shifted = image
+    ///     .remap(&map_x, &map_y, InterpolationMode::Nearest, BorderMode::Constant(0))
+    ///     .unwrap();
+    /// assert_eq!(shifted.as_slice(), &[20, 30, 0]);
+    /// ```
+    pub fn remap(
+        &self,
+        map_x: &Image<f32, 1>,
+        map_y: &Image<f32, 1>,
+        interpolation: InterpolationMode,
+        border: BorderMode<u8>,
+    ) -> Result<Image<u8, C>, ImageError> {
+        if map_x.size() != map_y.size() {
+            return Err(ImageError::InvalidImageSize(
+                map_x.width(),
+                map_x.height(),
+                map_y.width(),
+                map_y.height(),
+            ));
+        }
+
+        let (cols, rows) = (self.cols() as isize, self.rows() as isize);
+        let src = self.as_slice();
+
+        // mirror a coordinate at the edges repeating the edge pixels
+        let reflect = |i: isize, len: isize| {
+            let i = i.rem_euclid(2 * len);
+            if i < len {
+                i
+            } else {
+                2 * len - 1 - i
+            }
+        };
+
+        // the value of a channel of a pixel, applying the border mode outside the image
+        let fetch = |x: isize, y: isize, ch: usize| -> f32 {
+            if (0..cols).contains(&x) && (0..rows).contains(&y) {
+                return src[(y * cols + x) as usize * C + ch] as f32;
+            }
+            match border {
+                BorderMode::Constant(value) => value as f32,
+                BorderMode::Reflect if cols > 0 && rows > 0 => {
+                    let (x, y) = (reflect(x, cols), reflect(y, rows));
+                    src[(y * cols + x) as usize * C + ch] as f32
+                }
+                BorderMode::Reflect => 0.0,
+            }
+        };
+
+        let data = map_x
+            .as_slice()
+            .iter()
+            .zip(map_y.as_slice())
+            .flat_map(|(&sx, &sy)| {
+                let sample: [u8; C] = std::array::from_fn(|ch| match interpolation {
+                    InterpolationMode::Nearest => {
+                        fetch(sx.round() as isize, sy.round() as isize, ch) as u8
+                    }
+                    InterpolationMode::Bilinear => {
+                        let (x0, y0) = (sx.floor(), sy.floor());
+                        let (fx, fy) = (sx - x0, sy - y0);
+                        let (x0, y0) = (x0 as isize, y0 as isize);
+                        let top = fetch(x0, y0, ch) * (1.0 - fx) + fetch(x0 + 1, y0, ch) * fx;
+                        let bottom =
+                            fetch(x0, y0 + 1, ch) * (1.0 - fx) + fetch(x0 + 1, y0 + 1, ch) * fx;
+                        (top * (1.0 - fy) + bottom * fy).round() as u8
+                    }
+                });
+                sample
+            })
+            .collect();
+
+        Image::new(map_x.size(), data)
+    }
+
+    /// Convert the image to floating point with values normalized to [0, 1].
+    ///
+    /// # Returns
+    ///
+    /// A new image with the same size and channels and each value divided by 255.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![0, 255],
+    /// )
+    /// .unwrap();
+    ///
+    /// let normalized = image.to_f32_normalized();
+    /// assert_eq!(normalized.as_slice(), &[0.0, 1.0]);
+    /// ```
+    pub fn to_f32_normalized(&self) -> Image<f32, C> {
+        Image(Tensor::from_shape_fn(
+            self.shape,
+            CpuAllocator,
+            |[r, c, ch]| self.as_slice()[(r * self.cols() + c) * C + ch] as f32 / 255.0,
+        ))
+    }
+
+    /// Convert a floating point image with values normalized to [0, 1] to u8.
+    ///
+    /// The values are scaled by 255, rounded and clamped to [0, 255].
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The floating point image.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the same size and channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<f32, 1>::new(
+    ///     ImageSize {
+    ///         width: 3,
+    ///         height: 1,
+    ///     },
+    ///     vec![-0.5, 0.5, 1.5],
+    /// )
+    /// .unwrap();
+    ///
+    /// let image = Image::<u8, 1>::from_f32_normalized(&image);
+    /// assert_eq!(image.as_slice(), &[0, 128, 255]);
+    /// ```
+    pub fn from_f32_normalized(image: &Image<f32, C>) -> Image<u8, C> {
+        Image(Tensor::from_shape_fn(
+            image.shape,
+            CpuAllocator,
+            |[r, c, ch]| {
+                let v = image.as_slice()[(r * image.cols() + c) * C + ch];
+                (v * 255.0).round().clamp(0.0, 255.0) as u8
+            },
+        ))
+    }
+
+    /// Compute the histogram of each channel of the image.
+    ///
+    /// # Returns
+    ///
+    /// The 256 bins of each channel, where bin `v` counts the pixels with value `v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 3,
+    ///         height: 1,
+    ///     },
+    ///     vec![0, 7, 7],
+    /// )
+    /// .unwrap();
+    ///
+    /// let [hist] = image.histogram();
+    /// assert_eq!(hist[0], 1);
+    /// assert_eq!(hist[7], 2);
+    /// ```
+    pub fn histogram(&self) -> [[u32; 256]; C] {
+        let mut hist = [[0u32; 256]; C];
+
+        for pixel in self.as_slice().chunks_exact(C) {
+            for (bins, &value) in hist.iter_mut().zip(pixel) {
+                bins[value as usize] += 1;
+            }
+        }
+
+        hist
+    }
+
+    /// Compute the mean and the standard deviation of each channel of the image.
+    ///
+    /// The statistics are computed in a single pass with Welford's algorithm,
+    /// which avoids the loss of precision of subtracting large sums, e.g. to
+    /// normalize the images of a dataset.
+    ///
+    /// # Returns
+    ///
+    /// The mean and the population standard deviation of each channel, or zeros
+    /// if the image is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![10, 20],
+    /// )
+    /// .unwrap();
+    ///
+    /// let ([mean], [std]) = image.channel_stats();
+    /// assert_eq!(mean, 15.0);
+    /// assert_eq!(std, 5.0);
+    /// ```
+    pub fn channel_stats(&self) -> ([f64; C], [f64; C]) {
+        let mut mean = [0.0f64; C];
+        // the sum of the squared differences from the running mean
+        let mut m2 = [0.0f64; C];
+
+        for (i, pixel) in self.as_slice().chunks_exact(C).enumerate() {
+            let n = (i + 1) as f64;
+            for ((mean, m2), &value) in mean.iter_mut().zip(m2.iter_mut()).zip(pixel) {
+                let delta = value as f64 - *mean;
+                *mean += delta / n;
+                *m2 += delta * (value as f64 - *mean);
+            }
+        }
+
+        let num_pixels = (self.width() * self.height()).max(1) as f64;
+        let std = m2.map(|m2| (m2 / num_pixels).sqrt());
+
+        (mean, std)
+    }
+
+    /// Compute the integral image, or summed-area table, of each channel.
+    ///
+    /// Each pixel of the integral image holds the sum of the pixels above and
+    /// to the left of it, inclusive, so the sum of any box can be computed
+    /// with four lookups, e.g. for box filters or Haar-like features.
+    ///
+    /// The sums wrap around on overflow, which may happen for images with more
+    /// than `u32::MAX / 255` (about 16.8 million) pixels. The box sums computed
+    /// with `wrapping_sub` from the corners are still exact as long as the sum
+    /// of the box itself fits in a `u32`.
+    ///
+    /// # Returns
+    ///
+    /// The integral image with the same size and number of channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 1>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 2,
+    ///     },
+    ///     vec![1, 2, 3, 4],
+    /// )
+    /// .unwrap();
+    ///
+    /// let integral = image.integral_image();
+    /// assert_eq!(integral.as_slice(), &[1, 3, 4, 10]);
+    /// ```
+    pub fn integral_image(&self) -> Image<u32, C> {
+        let row_len = self.width() * C;
+        let mut data = vec![0u32; self.as_slice().len()];
+
+        let rows = self.as_slice().chunks_exact(row_len.max(1));
+        let mut prev_row: Option<&[u32]> = None;
+        for (src, dst) in rows.zip(data.chunks_exact_mut(row_len.max(1))) {
+            let mut row_sum = [0u32; C];
+            for (x, (pixel, out)) in src.chunks_exact(C).zip(dst.chunks_exact_mut(C)).enumerate() {
+                for (ch, (&value, out)) in pixel.iter().zip(out.iter_mut()).enumerate() {
+                    row_sum[ch] = row_sum[ch].wrapping_add(value as u32);
+                    let above = prev_row.map_or(0, |row| row[x * C + ch]);
+                    *out = above.wrapping_add(row_sum[ch]);
+                }
+            }
+            prev_row = Some(dst);
+        }
+
+        Image::new(self.size(), data).expect("the integral image matches the image size")
+    }
+}
+
+impl Image<u8, 3> {
+    /// Convert the RGB image to BGRA with an opaque alpha channel.
+    ///
+    /// The packed BGRA layout is the pixel format expected by most window
+    /// buffers and GUI frameworks.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the channels in BGRA order and the alpha set to 255.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 3>::new(
+    ///     ImageSize {
+    ///         width: 1,
+    ///         height: 1,
+    ///     },
+    ///     vec![10, 20, 30],
+    /// )
+    /// .unwrap();
+    ///
+    /// let bgra = image.to_bgra8();
+    /// assert_eq!(bgra.as_slice(), &[30, 20, 10, 255]);
+    /// ```
+    pub fn to_bgra8(&self) -> Image<u8, 4> {
+        let data = self
+            .as_slice()
+            .chunks_exact(3)
+            .flat_map(|px| [px[2], px[1], px[0], 255])
+            .collect();
+
+        Image::new(self.size(), data).expect("bgra data matches the image size")
+    }
+
+    /// Convert the RGB image to RGBA with a constant alpha channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The alpha value of all the pixels, 255 for opaque.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the RGB channels followed by the alpha channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 3>::new(
+    ///     ImageSize {
+    ///         width: 1,
+    ///         height: 1,
+    ///     },
+    ///     vec![10, 20, 30],
+    /// )
+    /// .unwrap();
+    ///
+    /// let rgba = image.to_rgba8(128);
+    /// assert_eq!(rgba.as_slice(), &[10, 20, 30, 128]);
+    /// ```
+    pub fn to_rgba8(&self, alpha: u8) -> Image<u8, 4> {
+        let data = self
+            .as_slice()
+            .chunks_exact(3)
+            .flat_map(|px| [px[0], px[1], px[2], alpha])
+            .collect();
+
+        Image::new(self.size(), data).expect("rgba data matches the image size")
+    }
+}
+
+impl Image<u8, 4> {
+    /// Convert the RGBA image to RGB dropping the alpha channel.
+    ///
+    /// The color channels are kept as they are, i.e. they are not blended with
+    /// any background.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the RGB channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let image = Image::<u8, 4>::new(
+    ///     ImageSize {
+    ///         width: 1,
+    ///         height: 1,
+    ///     },
+    ///     vec![10, 20, 30, 0],
+    /// )
+    /// .unwrap();
+    ///
+    /// let rgb = image.to_rgb8();
+    /// assert_eq!(rgb.as_slice(), &[10, 20, 30]);
+    /// ```
+    pub fn to_rgb8(&self) -> Image<u8, 3> {
+        let data = self
+            .as_slice()
+            .chunks_exact(4)
+            .flat_map(|px| [px[0], px[1], px[2]])
+            .collect();
+
+        Image::new(self.size(), data).expect("rgb data matches the image size")
+    }
+}
+
+/// helper to convert an single channel tensor to a kornia image with try into
+impl<T> TryFrom<Tensor2<T, CpuAllocator>> for Image<T, 1>
+where
+    T: Clone,
+{
+    type Error = ImageError;
+
+    fn try_from(value: Tensor2<T, CpuAllocator>) -> Result<Self, Self::Error> {
+        Self::from_size_slice(
+            ImageSize {
+                width: value.shape[1],
+                height: value.shape[0],
+            },
+            value.as_slice(),
+        )
+    }
+}
+
+/// helper to convert an multi channel tensor to a kornia image with try into
+impl<T, const C: usize> TryFrom<Tensor3<T, CpuAllocator>> for Image<T, C> {
+    type Error = ImageError;
+
+    fn try_from(value: Tensor3<T, CpuAllocator>) -> Result<Self, Self::Error> {
+        if value.shape[2] != C {
+            return Err(ImageError::InvalidChannelShape(value.shape[2], C));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl<T, const C: usize> TryInto<Tensor3<T, CpuAllocator>> for Image<T, C> {
+    type Error = ImageError;
+
+    fn try_into(self) -> Result<Tensor3<T, CpuAllocator>, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::image::{BorderMode, Image, ImageError, ImageSize, InterpolationMode};
+    use kornia_tensor::{CpuAllocator, Tensor};
+
+    #[test]
+    fn test_image_size() {
+        let image_size = ImageSize {
+            width: 10,
+            height: 20,
+        };
+        assert_eq!(image_size.width, 10);
+        assert_eq!(image_size.height, 20);
+    }
+
+    #[test]
+    fn test_image_smoke() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 10,
+                height: 20,
+            },
+            vec![0u8; 10 * 20 * 3],
         )?;
         assert_eq!(image.size().width, 10);
         assert_eq!(image.size().height, 20);
@@ -633,7 +2294,7 @@ mod tests {
             vec![0., 1., 2., 3., 4., 5.],
         )
         .unwrap();
-        let channels = image.split_channels()?;
+        let channels = image.split_channels();
         assert_eq!(channels.len(), 3);
         assert_eq!(channels[0].get([1, 0, 0]), Some(&3.0f32));
         assert_eq!(channels[1].get([1, 0, 0]), Some(&4.0f32));
@@ -761,4 +2422,821 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_crop() -> Result<(), ImageError> {
+        let image = Image::<u8, 2>::new(
+            ImageSize {
+                width: 4,
+                height: 4,
+            },
+            (0..32).collect(),
+        )?;
+
+        // top-left corner
+        let cropped = image.crop(0, 0, 2, 2)?;
+        assert_eq!(
+            cropped.size(),
+            ImageSize {
+                width: 2,
+                height: 2
+            }
+        );
+        assert_eq!(cropped.as_slice(), &[0, 1, 2, 3, 8, 9, 10, 11]);
+
+        // bottom-right corner
+        let cropped = image.crop(2, 2, 2, 2)?;
+        assert_eq!(cropped.as_slice(), &[20, 21, 22, 23, 28, 29, 30, 31]);
+
+        // center
+        let cropped = image.crop(1, 1, 2, 2)?;
+        assert_eq!(cropped.as_slice(), &[10, 11, 12, 13, 18, 19, 20, 21]);
+
+        // single row spanning the full width
+        let cropped = image.crop(0, 3, 4, 1)?;
+        assert_eq!(cropped.as_slice(), &[24, 25, 26, 27, 28, 29, 30, 31]);
+
+        assert!(matches!(
+            image.crop(3, 0, 2, 1),
+            Err(ImageError::OutOfBounds(3, 0, 2, 1, 4, 4))
+        ));
+        assert!(matches!(
+            image.crop(0, 1, 1, 4),
+            Err(ImageError::OutOfBounds(..))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flip() -> Result<(), ImageError> {
+        #[rustfmt::skip]
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            vec![
+                0, 1, 2,    3, 4, 5,    6, 7, 8,
+                9, 10, 11,  12, 13, 14, 15, 16, 17,
+            ],
+        )?;
+
+        let flipped = image.flip_horizontal();
+        assert_eq!(flipped.size(), image.size());
+        #[rustfmt::skip]
+        assert_eq!(
+            flipped.as_slice(),
+            &[
+                6, 7, 8,    3, 4, 5,    0, 1, 2,
+                15, 16, 17, 12, 13, 14, 9, 10, 11,
+            ]
+        );
+        assert_eq!(flipped.flip_horizontal().as_slice(), image.as_slice());
+
+        let flipped = image.flip_vertical();
+        assert_eq!(flipped.size(), image.size());
+        #[rustfmt::skip]
+        assert_eq!(
+            flipped.as_slice(),
+            &[
+                9, 10, 11,  12, 13, 14, 15, 16, 17,
+                0, 1, 2,    3, 4, 5,    6, 7, 8,
+            ]
+        );
+        assert_eq!(flipped.flip_vertical().as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let a = Image::<u8, 2>::new(size, (0..8).collect())?;
+        let b = Image::<u8, 2>::new(size, (10..18).collect())?;
+
+        let horizontal = Image::concat_horizontal(&[a.clone(), b.clone()])?;
+        assert_eq!(
+            horizontal.size(),
+            ImageSize {
+                width: 4,
+                height: 2
+            }
+        );
+        assert_eq!(
+            horizontal.as_slice(),
+            &[0, 1, 2, 3, 10, 11, 12, 13, 4, 5, 6, 7, 14, 15, 16, 17]
+        );
+        assert_eq!(horizontal.get_pixel(2, 1, 1)?, b.get_pixel(0, 1, 1)?);
+
+        let vertical = Image::concat_vertical(&[a.clone(), b.clone()])?;
+        assert_eq!(
+            vertical.size(),
+            ImageSize {
+                width: 2,
+                height: 4
+            }
+        );
+        assert_eq!(&vertical.as_slice()[..8], a.as_slice());
+        assert_eq!(&vertical.as_slice()[8..], b.as_slice());
+        assert_eq!(vertical.get_pixel(1, 3, 0)?, b.get_pixel(1, 1, 0)?);
+
+        let tall = Image::<u8, 2>::from_size_val(
+            ImageSize {
+                width: 2,
+                height: 3,
+            },
+            0,
+        )?;
+        assert!(matches!(
+            Image::concat_horizontal(&[a.clone(), tall.clone()]),
+            Err(ImageError::InvalidImageSize(2, 2, 2, 3))
+        ));
+        assert_eq!(Image::concat_vertical(&[a.clone(), tall])?.height(), 5);
+
+        let wide = Image::<u8, 2>::from_size_val(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            0,
+        )?;
+        assert!(Image::concat_vertical(&[a, wide]).is_err());
+        assert!(Image::<u8, 2>::concat_horizontal(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_stats() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 2,
+        };
+
+        // a horizontal gradient, a vertical gradient and a constant channel
+        let data = (0..size.width * size.height)
+            .flat_map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                [x as u8, (y * 100) as u8, 7]
+            })
+            .collect();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        let (mean, std) = image.channel_stats();
+
+        // mean(0, 1, 2, 3) = 1.5 and var = (2.25 + 0.25 + 0.25 + 2.25) / 4 = 1.25
+        let expected_mean = [1.5, 50.0, 7.0];
+        let expected_std = [1.25f64.sqrt(), 50.0, 0.0];
+        for ch in 0..3 {
+            assert!((mean[ch] - expected_mean[ch]).abs() < 1e-12, "{mean:?}");
+            assert!((std[ch] - expected_std[ch]).abs() < 1e-12, "{std:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_saturating_add_clamp() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let image = Image::<u8, 2>::new(size, vec![0, 100, 154, 155, 200, 250, 254, 255])?;
+
+        // the values near 255 saturate instead of wrapping around
+        let added = image.saturating_add_scalar(100);
+        assert_eq!(added.as_slice(), &[100, 200, 254, 255, 255, 255, 255, 255]);
+        assert_eq!(added.size(), size);
+
+        let clamped = image.clamp(100, 200);
+        assert_eq!(
+            clamped.as_slice(),
+            &[100, 100, 154, 155, 200, 200, 200, 200]
+        );
+
+        let image = Image::<f32, 1>::new(size, vec![-1.0, 0.25, 0.75, 2.0])?;
+        assert_eq!(
+            image.saturating_add_scalar(0.5).as_slice(),
+            &[-0.5, 0.75, 1.25, 2.5]
+        );
+        assert_eq!(image.clamp(0.0, 1.0).as_slice(), &[0.0, 0.25, 0.75, 1.0]);
+
+        let image = Image::<i16, 1>::new(size, vec![-32000, -10, 10, 32000])?;
+        assert_eq!(
+            image.saturating_add_scalar(-1000).as_slice(),
+            &[-32768, -1010, -990, 31000]
+        );
+
+        let image = Image::<u64, 1>::new(size, vec![0, 10, u64::MAX - 1, u64::MAX])?;
+        assert_eq!(
+            image.saturating_add_scalar(10).as_slice(),
+            &[10, 20, u64::MAX, u64::MAX]
+        );
+
+        // the values above 2^53 are kept exact
+        let large = (1u64 << 60) + 1;
+        let image = Image::<u64, 1>::new(size, vec![large, large, u64::MAX - 1000, 0])?;
+        assert_eq!(image.saturating_add_scalar(0).as_slice(), image.as_slice());
+        assert_eq!(
+            image.saturating_add_scalar(10).as_slice(),
+            &[large + 10, large + 10, u64::MAX - 990, 10]
+        );
+
+        let image =
+            Image::<f32, 1>::new(size, vec![f32::INFINITY, f32::NEG_INFINITY, f32::MAX, 0.0])?;
+        assert_eq!(
+            image.saturating_add_scalar(1.0).as_slice(),
+            &[f32::INFINITY, f32::NEG_INFINITY, f32::MAX, 1.0]
+        );
+
+        let image = Image::<i64, 1>::new(size, vec![i64::MIN, i64::MIN + 1, -10, 0])?;
+        assert_eq!(
+            image.saturating_add_scalar(-10).as_slice(),
+            &[i64::MIN, i64::MIN, -20, -10]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_close() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 3,
+        };
+        let image = Image::<u8, 3>::new(size, (0..36).map(|i| i * 7).collect())?;
+
+        // perturb a few values by up to 3 in both directions
+        let mut perturbed = image.clone();
+        perturbed.set_pixel(0, 0, 0, 3)?;
+        perturbed.set_pixel(2, 1, 1, image.get_pixel(2, 1, 1)? - 2)?;
+        perturbed.set_pixel(3, 2, 2, image.get_pixel(3, 2, 2)? + 1)?;
+
+        assert_eq!(image.max_abs_diff(&image)?, 0.0);
+        assert_eq!(image.max_abs_diff(&perturbed)?, 3.0);
+        assert_eq!(perturbed.max_abs_diff(&image)?, 3.0);
+        assert!(image.is_close(&perturbed, 3.0));
+        assert!(!image.is_close(&perturbed, 2.5));
+
+        let other_size = Image::<u8, 3>::from_size_val(
+            ImageSize {
+                width: 3,
+                height: 4,
+            },
+            0,
+        )?;
+        assert!(image.max_abs_diff(&other_size).is_err());
+        assert!(!image.is_close(&other_size, 255.0));
+
+        let a = Image::<f32, 1>::new(size, vec![0.25; 12])?;
+        let mut b = a.clone();
+        b.set_pixel(1, 1, 0, 0.2501)?;
+        assert!(a.is_close(&b, 1e-3));
+        assert!(!a.is_close(&b, 1e-5));
+        b.set_pixel(2, 2, 0, f32::NAN)?;
+        assert!(a.max_abs_diff(&b)?.is_nan());
+        assert!(!a.is_close(&b, f64::INFINITY));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remap() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 4,
+        };
+        let image = Image::<u8, 2>::new(size, (0..40).map(|i| i * 5).collect())?;
+
+        let grid = |f: &dyn Fn(usize, usize) -> f32| {
+            let data = (0..size.width * size.height)
+                .map(|i| f(i % size.width, i / size.width))
+                .collect();
+            Image::<f32, 1>::new(size, data)
+        };
+
+        // the identity map returns the image
+        let map_x = grid(&|x, _| x as f32)?;
+        let map_y = grid(&|_, y| y as f32)?;
+        for interpolation in [InterpolationMode::Nearest, InterpolationMode::Bilinear] {
+            let remapped = image.remap(&map_x, &map_y, interpolation, BorderMode::Constant(0))?;
+            assert_eq!(remapped.as_slice(), image.as_slice());
+        }
+
+        // sampling two pixels to the right and one down translates the image
+        let map_x = grid(&|x, _| x as f32 + 2.0)?;
+        let map_y = grid(&|_, y| y as f32 + 1.0)?;
+        let shifted = image.remap(
+            &map_x,
+            &map_y,
+            InterpolationMode::Bilinear,
+            BorderMode::Constant(7),
+        )?;
+        for y in 0..size.height {
+            for x in 0..size.width {
+                for ch in 0..2 {
+                    let expected = if x + 2 < size.width && y + 1 < size.height {
+                        *image.get_pixel(x + 2, y + 1, ch)?
+                    } else {
+                        7
+                    };
+                    assert_eq!(*shifted.get_pixel(x, y, ch)?, expected);
+                }
+            }
+        }
+
+        // the reflected border repeats the edge pixels
+        let reflected = image.remap(
+            &map_x,
+            &map_y,
+            InterpolationMode::Nearest,
+            BorderMode::Reflect,
+        )?;
+        assert_eq!(reflected.get_pixel(3, 0, 0)?, image.get_pixel(4, 1, 0)?);
+        assert_eq!(reflected.get_pixel(4, 3, 1)?, image.get_pixel(3, 3, 1)?);
+
+        // a half pixel shift averages the neighbors
+        let map_x = grid(&|x, _| x as f32 + 0.5)?;
+        let map_y = grid(&|_, y| y as f32)?;
+        let half = image.remap(
+            &map_x,
+            &map_y,
+            InterpolationMode::Bilinear,
+            BorderMode::Reflect,
+        )?;
+        assert_eq!(*half.get_pixel(1, 2, 0)?, 115);
+
+        let other = Image::<f32, 1>::from_size_val(
+            ImageSize {
+                width: 4,
+                height: 5,
+            },
+            0.0,
+        )?;
+        assert!(image
+            .remap(
+                &map_x,
+                &other,
+                InterpolationMode::Nearest,
+                BorderMode::Reflect
+            )
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chw() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let data = (0..size.width * size.height * 3).map(|i| i as u8).collect();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        // the red values of all the pixels come first, then green and blue
+        let chw = image.to_chw();
+        assert_eq!(
+            chw,
+            vec![0, 3, 6, 9, 12, 15, 1, 4, 7, 10, 13, 16, 2, 5, 8, 11, 14, 17]
+        );
+
+        let image_back = Image::<u8, 3>::from_chw(&chw, size)?;
+        assert_eq!(image_back.size(), size);
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        assert!(matches!(
+            Image::<u8, 3>::from_chw(&chw[1..], size),
+            Err(ImageError::InvalidChannelShape(17, 18))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_draw_rect() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 10,
+            height: 8,
+        };
+        let mut image = Image::<u8, 3>::from_size_val(size, 0)?;
+
+        let (x, y, w, h) = (2, 1, 6, 5);
+        image.draw_rect(x, y, w, h, [255, 0, 0], 1);
+
+        for py in 0..size.height {
+            for px in 0..size.width {
+                let inside = (x..x + w).contains(&px) && (y..y + h).contains(&py);
+                let border = inside && (px == x || px == x + w - 1 || py == y || py == y + h - 1);
+                let expected = if border { [255, 0, 0] } else { [0, 0, 0] };
+                for (ch, &value) in expected.iter().enumerate() {
+                    assert_eq!(*image.get_pixel(px, py, ch)?, value, "pixel ({px}, {py})");
+                }
+            }
+        }
+
+        // a thick border clipped at the right and bottom of the image
+        let mut image = Image::<u8, 1>::from_size_val(size, 0)?;
+        image.draw_rect(6, 4, 10, 10, [7], 2);
+        let row = |image: &Image<u8, 1>, y: usize| image.as_slice()[y * 10..(y + 1) * 10].to_vec();
+        assert_eq!(row(&image, 3), [0; 10]);
+        assert_eq!(row(&image, 4), [0, 0, 0, 0, 0, 0, 7, 7, 7, 7]);
+        assert_eq!(row(&image, 5), [0, 0, 0, 0, 0, 0, 7, 7, 7, 7]);
+        assert_eq!(row(&image, 6), [0, 0, 0, 0, 0, 0, 7, 7, 0, 0]);
+        assert_eq!(row(&image, 7), [0, 0, 0, 0, 0, 0, 7, 7, 0, 0]);
+
+        // a solid fill and a rectangle outside of the image
+        let mut image = Image::<u8, 1>::from_size_val(size, 0)?;
+        image.fill_rect(8, 6, 4, 4, [3]);
+        image.fill_rect(20, 0, 4, 4, [5]);
+        assert_eq!(image.as_slice().iter().filter(|&&v| v == 3).count(), 4);
+        assert!(image.as_slice().iter().all(|&v| v != 5));
+        assert_eq!(*image.get_pixel(9, 7, 0)?, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let image = Image::<u8, 3>::new(
+            size,
+            vec![0, 50, 100, 150, 200, 250, 10, 20, 30, 40, 230, 60],
+        )?;
+
+        // increase the brightness saturating at 255
+        let brighter = image.map_pixels(|px| px.map(|v| v.saturating_add(20)));
+        assert_eq!(brighter.size(), size);
+        assert_eq!(
+            brighter.as_slice(),
+            &[20, 70, 120, 170, 220, 255, 30, 40, 50, 60, 250, 80]
+        );
+
+        // the element-wise map of the tensor is still reachable
+        let halved = image.map(|v| v / 2);
+        assert_eq!(&halved.as_slice()[..6], &[0, 25, 50, 75, 100, 125]);
+
+        // blend each pixel with a flat gray image
+        let gray = Image::<f32, 3>::from_size_val(size, 100.0)?;
+        let blended = image.zip_map(&gray, |a, b| {
+            std::array::from_fn(|ch| (0.5 * a[ch] as f32 + 0.5 * b[ch]).round() as u8)
+        })?;
+        assert_eq!(
+            blended.as_slice(),
+            &[50, 75, 100, 125, 150, 175, 55, 60, 65, 70, 165, 80]
+        );
+
+        let other = Image::<u8, 3>::from_size_val([3, 2].into(), 0)?;
+        assert!(matches!(
+            image.zip_map(&other, |a, _| a),
+            Err(ImageError::InvalidImageSize(2, 2, 3, 2))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_contiguous() -> Result<(), ImageError> {
+        use kornia_tensor::storage::TensorStorage;
+
+        // a 3x2 rgb image stored in rows of 4 pixels
+        let (rows, cols, padded_cols) = (2, 3, 4);
+        let data = (0..rows * padded_cols * 3).map(|i| i as u8).collect();
+        let image = Image::<u8, 3>(Tensor {
+            storage: TensorStorage::from_vec(data, CpuAllocator),
+            shape: [rows, cols, 3],
+            strides: [padded_cols * 3, 3, 1],
+        });
+        assert!(!image.is_contiguous());
+
+        let packed = image.to_contiguous();
+        assert!(packed.is_contiguous());
+        assert_eq!(packed.size(), image.size());
+        assert_eq!(
+            packed.as_slice(),
+            &[0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 13, 14, 15, 16, 17, 18, 19, 20]
+        );
+
+        let contiguous = Image::<u8, 3>::from_size_val(packed.size(), 7)?;
+        assert!(contiguous.is_contiguous());
+        assert_eq!(contiguous.to_contiguous().as_slice(), contiguous.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotate() -> Result<(), ImageError> {
+        // the pixels (a, b, c) over (d, e, f) with two channels each
+        #[rustfmt::skip]
+        let image = Image::<u8, 2>::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            vec![
+                0, 1,   2, 3,   4, 5,
+                6, 7,   8, 9,   10, 11,
+            ],
+        )?;
+
+        let rotated = image.rotate_90();
+        assert_eq!(rotated.width(), 2);
+        assert_eq!(rotated.height(), 3);
+        #[rustfmt::skip]
+        assert_eq!(
+            rotated.as_slice(),
+            &[
+                6, 7,   0, 1,
+                8, 9,   2, 3,
+                10, 11, 4, 5,
+            ]
+        );
+
+        let rotated = image.rotate_180();
+        assert_eq!(rotated.size(), image.size());
+        #[rustfmt::skip]
+        assert_eq!(
+            rotated.as_slice(),
+            &[
+                10, 11, 8, 9,   6, 7,
+                4, 5,   2, 3,   0, 1,
+            ]
+        );
+
+        let rotated = image.rotate_270();
+        assert_eq!(rotated.width(), 2);
+        assert_eq!(rotated.height(), 3);
+        #[rustfmt::skip]
+        assert_eq!(
+            rotated.as_slice(),
+            &[
+                4, 5,   10, 11,
+                2, 3,   8, 9,
+                0, 1,   6, 7,
+            ]
+        );
+
+        // four quarter turns return the original image
+        let full_turn = image.rotate_90().rotate_90().rotate_90().rotate_90();
+        assert_eq!(full_turn.size(), image.size());
+        assert_eq!(full_turn.as_slice(), image.as_slice());
+
+        assert_eq!(image.rotate_90().rotate_270().as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize() -> Result<(), ImageError> {
+        let image = Image::<u8, 1>::new(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            vec![0, 255, 255, 0],
+        )?;
+        let new_size = ImageSize {
+            width: 4,
+            height: 4,
+        };
+
+        let nearest = image.resize(new_size, InterpolationMode::Nearest);
+        assert_eq!(nearest.size(), new_size);
+        #[rustfmt::skip]
+        assert_eq!(
+            nearest.as_slice(),
+            &[
+                0, 0, 255, 255,
+                0, 0, 255, 255,
+                255, 255, 0, 0,
+                255, 255, 0, 0,
+            ]
+        );
+
+        let resized = image.resize(new_size, InterpolationMode::Bilinear);
+        assert_eq!(resized.size(), new_size);
+        assert_eq!(resized.get_pixel(0, 0, 0)?, &0);
+        assert_eq!(resized.get_pixel(3, 0, 0)?, &255);
+        assert_eq!(resized.get_pixel(0, 3, 0)?, &255);
+        assert_eq!(resized.get_pixel(3, 3, 0)?, &0);
+        // the inner pixels are blended
+        assert_eq!(resized.get_pixel(1, 1, 0)?, &96);
+
+        // downscaling back to the original size
+        let resized = nearest.resize(image.size(), InterpolationMode::Nearest);
+        assert_eq!(resized.as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_pixels() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 1,
+            },
+            vec![0, 10, 100, 200, 254, 255],
+        )?;
+
+        let inverted = image.map_pixels(|pixel| pixel.map(|v| 255 - v));
+        assert_eq!(inverted.size(), image.size());
+        inverted
+            .as_slice()
+            .iter()
+            .zip(image.as_slice())
+            .for_each(|(&out, &inp)| assert_eq!(out, 255 - inp));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_histogram() -> Result<(), ImageError> {
+        let image = Image::<u8, 2>::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            vec![0, 255, 0, 10, 5, 10, 5, 10, 0, 255, 255, 255],
+        )?;
+
+        let [first, second] = image.histogram();
+        assert_eq!((first[0], first[5], first[255]), (3, 2, 1));
+        assert_eq!((second[10], second[255]), (3, 3));
+
+        for bins in [first, second] {
+            assert_eq!(bins.iter().sum::<u32>(), 6);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_integral_image() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 3,
+        };
+        let image = Image::<u8, 2>::from_size_val(size, 1)?;
+
+        let integral = image.integral_image();
+        assert_eq!(integral.size(), size);
+        assert_eq!(integral.get_pixel(2, 2, 0)?, &9);
+        assert_eq!(integral.get_pixel(2, 2, 1)?, &9);
+        assert_eq!(integral.get_pixel(1, 2, 0)?, &6);
+        assert_eq!(integral.get_pixel(0, 0, 1)?, &1);
+
+        // the sum of a box from the four corners
+        let image = Image::<u8, 1>::new(size, (1..=9).collect())?;
+        let integral = image.integral_image();
+        let at = |x, y| integral.get_pixel(x, y, 0).copied();
+        assert_eq!(at(2, 2)? - at(0, 2)? - at(2, 0)? + at(0, 0)?, 5 + 6 + 8 + 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_bgra8() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 1,
+            },
+            vec![255, 128, 0, 1, 2, 3],
+        )?;
+
+        let bgra = image.to_bgra8();
+        assert_eq!(bgra.size(), image.size());
+        assert_eq!(bgra.num_channels(), 4);
+        assert_eq!(bgra.as_slice(), &[0, 128, 255, 255, 3, 2, 1, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_rgba8() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 1,
+            },
+            vec![255, 128, 0, 1, 2, 3],
+        )?;
+
+        let rgba = image.to_rgba8(200);
+        assert_eq!(rgba.size(), image.size());
+        assert_eq!(rgba.as_slice(), &[255, 128, 0, 200, 1, 2, 3, 200]);
+
+        // the color survives the round trip
+        let rgb = rgba.to_rgb8();
+        assert_eq!(rgb.size(), image.size());
+        assert_eq!(rgb.as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_f32_normalized() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 16,
+            height: 16,
+        };
+        let data = (0..size.width * size.height * 2).map(|i| i as u8).collect();
+        let image = Image::<u8, 2>::new(size, data)?;
+
+        let normalized = image.to_f32_normalized();
+        assert_eq!(normalized.size(), size);
+        assert_eq!(normalized.num_channels(), 2);
+        assert!(normalized
+            .as_slice()
+            .iter()
+            .all(|v| (0.0..=1.0).contains(v)));
+        assert_eq!(normalized.get_pixel(0, 0, 1)?, &(1.0 / 255.0));
+
+        let restored = Image::<u8, 2>::from_f32_normalized(&normalized);
+        assert_eq!(restored.size(), size);
+        restored
+            .as_slice()
+            .iter()
+            .zip(image.as_slice())
+            .for_each(|(&out, &inp)| assert!(out.abs_diff(inp) <= 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_merge_channels() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            (0..12).collect(),
+        )?;
+
+        let planes = image.split_channels();
+        assert_eq!(planes[1].as_slice(), &[1, 4, 7, 10]);
+
+        let merged = Image::merge_channels(&planes)?;
+        assert_eq!(merged.size(), image.size());
+        assert_eq!(merged.as_slice(), image.as_slice());
+
+        let [r, g, _] = planes;
+        let b = Image::<u8, 1>::from_size_val([1, 1].into(), 0)?;
+        assert!(Image::merge_channels(&[r, g, b]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pad() -> Result<(), ImageError> {
+        let image = Image::<u8, 1>::new(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            vec![1, 2, 3, 4],
+        )?;
+
+        let padded = image.pad(1, 1, 1, 1, BorderMode::Constant(9));
+        assert_eq!(padded.cols(), 4);
+        assert_eq!(padded.rows(), 4);
+        #[rustfmt::skip]
+        assert_eq!(
+            padded.as_slice(),
+            &[
+                9, 9, 9, 9,
+                9, 1, 2, 9,
+                9, 3, 4, 9,
+                9, 9, 9, 9,
+            ]
+        );
+
+        let padded = image.pad(1, 1, 1, 1, BorderMode::Reflect);
+        assert_eq!(padded.cols(), 4);
+        assert_eq!(padded.rows(), 4);
+        #[rustfmt::skip]
+        assert_eq!(
+            padded.as_slice(),
+            &[
+                1, 1, 2, 2,
+                1, 1, 2, 2,
+                3, 3, 4, 4,
+                3, 3, 4, 4,
+            ]
+        );
+
+        // padding larger than the image keeps mirroring
+        let padded = image.pad(0, 0, 3, 0, BorderMode::Reflect);
+        assert_eq!(padded.as_slice(), &[2, 2, 1, 1, 2, 4, 4, 3, 3, 4]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,100 @@
+use crate::{error::ImageError, image::Image, image::ImageSize};
+
+// take the pixel data of the image in row-major order, copying it only if the
+// image is not contiguous
+fn into_pixel_data<const C: usize>(image: Image<u8, C>) -> (u32, u32, Vec<u8>) {
+    let image = if image.is_contiguous() {
+        image
+    } else {
+        image.to_contiguous()
+    };
+    (image.cols() as u32, image.rows() as u32, image.0.into_vec())
+}
+
+fn image_size<P: image::Pixel>(buffer: &image::ImageBuffer<P, Vec<P::Subpixel>>) -> ImageSize {
+    ImageSize {
+        width: buffer.width() as usize,
+        height: buffer.height() as usize,
+    }
+}
+
+impl From<Image<u8, 3>> for image::RgbImage {
+    /// Convert a RGB8 image into an `image` crate buffer without copying the pixels.
+    fn from(image: Image<u8, 3>) -> Self {
+        let (width, height, data) = into_pixel_data(image);
+        image::RgbImage::from_raw(width, height, data).expect("the data matches the image size")
+    }
+}
+
+impl TryFrom<image::RgbImage> for Image<u8, 3> {
+    type Error = ImageError;
+
+    /// Convert an `image` crate buffer into a RGB8 image without copying the pixels.
+    fn try_from(buffer: image::RgbImage) -> Result<Self, Self::Error> {
+        let size = image_size(&buffer);
+        Image::new(size, buffer.into_raw())
+    }
+}
+
+impl From<Image<u8, 1>> for image::GrayImage {
+    /// Convert a grayscale image into an `image` crate buffer without copying the pixels.
+    fn from(image: Image<u8, 1>) -> Self {
+        let (width, height, data) = into_pixel_data(image);
+        image::GrayImage::from_raw(width, height, data).expect("the data matches the image size")
+    }
+}
+
+impl TryFrom<image::GrayImage> for Image<u8, 1> {
+    type Error = ImageError;
+
+    /// Convert an `image` crate buffer into a grayscale image without copying the pixels.
+    fn try_from(buffer: image::GrayImage) -> Result<Self, Self::Error> {
+        let size = image_size(&buffer);
+        Image::new(size, buffer.into_raw())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_interop_rgb() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let image = Image::<u8, 3>::new(size, (0..18).collect())?;
+
+        let buffer = image::RgbImage::from(image.clone());
+        assert_eq!(buffer.dimensions(), (3, 2));
+        assert_eq!(buffer.get_pixel(2, 1).0, [15, 16, 17]);
+        assert_eq!(buffer.as_raw().as_slice(), image.as_slice());
+
+        let image_back = Image::<u8, 3>::try_from(buffer)?;
+        assert_eq!(image_back.size(), size);
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interop_gray() -> Result<(), ImageError> {
+        let buffer = image::GrayImage::from_fn(4, 3, |x, y| image::Luma([(y * 4 + x) as u8]));
+
+        let image = Image::<u8, 1>::try_from(buffer.clone())?;
+        assert_eq!(
+            image.size(),
+            ImageSize {
+                width: 4,
+                height: 3,
+            }
+        );
+        assert_eq!(*image.get_pixel(1, 2, 0)?, 9);
+
+        let buffer_back: image::GrayImage = image.into();
+        assert_eq!(buffer_back, buffer);
+
+        Ok(())
+    }
+}
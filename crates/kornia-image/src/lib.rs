@@ -7,8 +7,16 @@ pub mod image;
 /// Error types for the image module.
 pub mod error;
 
+/// conversions from and to the `image` crate buffers.
+#[cfg(feature = "image-interop")]
+pub mod interop;
+
 /// module containing ops implementations.
 pub mod ops;
 
+/// borrowed views over image data.
+pub mod view;
+
 pub use crate::error::ImageError;
-pub use crate::image::{Image, ImageSize};
+pub use crate::image::{BorderMode, Image, ImageSize, InterpolationMode, SaturatingAdd};
+pub use crate::view::{ImageView, Rect};
@@ -0,0 +1,258 @@
+use crate::{error::ImageError, image::ImageSize};
+
+/// A rectangular region of an image in pixels.
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::Rect;
+///
+/// let rect = Rect {
+///     x: 2,
+///     y: 1,
+///     width: 4,
+///     height: 3,
+/// };
+///
+/// assert_eq!(rect.width, 4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+    /// The x-coordinate of the top-left corner
+    pub x: usize,
+    /// The y-coordinate of the top-left corner
+    pub y: usize,
+    /// Width of the region in pixels
+    pub width: usize,
+    /// Height of the region in pixels
+    pub height: usize,
+}
+
+/// A borrowed view over the pixel data of an image or a region of it.
+///
+/// The view does not copy the pixels. The rows of the view are `stride`
+/// elements apart in memory, which is the row length of the parent image, so
+/// a view of a region can be passed to code expecting a pointer, a size and a
+/// row stride, e.g. a C library.
+#[derive(Clone, Copy, Debug)]
+pub struct ImageView<'a, T, const C: usize> {
+    // the data from the first pixel of the region to the last one
+    data: &'a [T],
+    size: ImageSize,
+    stride: usize,
+}
+
+impl<'a, T, const C: usize> ImageView<'a, T, C> {
+    /// Create a view over a region of a contiguous image buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The pixel data of the whole image.
+    /// * `image_size` - The size of the whole image in pixels.
+    /// * `rect` - The region of the view.
+    ///
+    /// # Errors
+    ///
+    /// If the region exceeds the image bounds or the data does not match the
+    /// image size, an error is returned.
+    pub(crate) fn from_region(
+        data: &'a [T],
+        image_size: ImageSize,
+        rect: Rect,
+    ) -> Result<Self, ImageError> {
+        let expected = image_size.width * image_size.height * C;
+        if data.len() != expected {
+            return Err(ImageError::InvalidChannelShape(data.len(), expected));
+        }
+
+        let exceeds = |start: usize, len: usize, max: usize| {
+            start.checked_add(len).map_or(true, |end| end > max)
+        };
+
+        if exceeds(rect.x, rect.width, image_size.width)
+            || exceeds(rect.y, rect.height, image_size.height)
+        {
+            return Err(ImageError::OutOfBounds(
+                rect.x,
+                rect.y,
+                rect.width,
+                rect.height,
+                image_size.width,
+                image_size.height,
+            ));
+        }
+
+        let stride = image_size.width * C;
+        let start = rect.y * stride + rect.x * C;
+        let len = if rect.width == 0 || rect.height == 0 {
+            0
+        } else {
+            (rect.height - 1) * stride + rect.width * C
+        };
+
+        Ok(Self {
+            data: &data[start..start + len],
+            size: ImageSize {
+                width: rect.width,
+                height: rect.height,
+            },
+            stride,
+        })
+    }
+
+    /// Get a raw pointer to the first pixel of the view.
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Get the size of the view in pixels.
+    pub fn size(&self) -> ImageSize {
+        self.size
+    }
+
+    /// Get the number of columns of the view.
+    pub fn cols(&self) -> usize {
+        self.size.width
+    }
+
+    /// Get the number of rows of the view.
+    pub fn rows(&self) -> usize {
+        self.size.height
+    }
+
+    /// Get the distance between the start of two consecutive rows in elements.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Get the distance between the start of two consecutive rows in bytes.
+    pub fn stride_bytes(&self) -> usize {
+        self.stride * std::mem::size_of::<T>()
+    }
+
+    /// Get the pixel data of a row of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - The index of the row.
+    ///
+    /// # Returns
+    ///
+    /// The `width * C` values of the row, or `None` if the row is out of bounds.
+    pub fn row(&self, y: usize) -> Option<&'a [T]> {
+        if y >= self.size.height {
+            return None;
+        }
+        // the data of an empty region does not span the rows
+        if self.size.width == 0 {
+            return Some(&[]);
+        }
+        let start = y * self.stride;
+        Some(&self.data[start..start + self.size.width * C])
+    }
+
+    /// Get the pixel value at the given coordinates of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the pixel.
+    /// * `y` - The y-coordinate of the pixel.
+    /// * `ch` - The channel index of the pixel.
+    ///
+    /// # Returns
+    ///
+    /// The pixel value at the given coordinates.
+    pub fn get_pixel(&self, x: usize, y: usize, ch: usize) -> Result<&'a T, ImageError> {
+        if x >= self.size.width || y >= self.size.height {
+            return Err(ImageError::PixelIndexOutOfBounds(
+                x,
+                y,
+                self.size.width,
+                self.size.height,
+            ));
+        }
+
+        if ch >= C {
+            return Err(ImageError::ChannelIndexOutOfBounds(ch, C));
+        }
+
+        Ok(&self.data[y * self.stride + x * C + ch])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::image::{Image, ImageSize};
+    use crate::view::Rect;
+    use crate::ImageError;
+
+    #[test]
+    fn test_view_region() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 4,
+        };
+        let data = (0..size.width * size.height * 2).map(|i| i as u8).collect();
+        let image = Image::<u8, 2>::new(size, data)?;
+
+        let view = image.view();
+        assert_eq!(view.size(), size);
+        assert_eq!(view.stride(), 10);
+        assert_eq!(view.as_ptr(), image.as_slice().as_ptr());
+
+        let rect = Rect {
+            x: 1,
+            y: 2,
+            width: 3,
+            height: 2,
+        };
+        let region = image.view_region(rect)?;
+        assert_eq!(region.cols(), 3);
+        assert_eq!(region.rows(), 2);
+        assert_eq!(region.stride(), 10);
+        assert_eq!(region.stride_bytes(), 10);
+
+        // the view borrows the parent data without copying
+        assert_eq!(region.as_ptr(), image.get_pixel(1, 2, 0)? as *const u8);
+
+        for y in 0..rect.height {
+            for x in 0..rect.width {
+                for ch in 0..2 {
+                    assert_eq!(
+                        region.get_pixel(x, y, ch)?,
+                        image.get_pixel(rect.x + x, rect.y + y, ch)?
+                    );
+                }
+            }
+        }
+
+        let cropped = image.crop(rect.x, rect.y, rect.width, rect.height)?;
+        assert_eq!(region.row(1), Some(&cropped.as_slice()[6..]));
+        assert_eq!(region.row(2), None);
+
+        assert!(region.get_pixel(3, 0, 0).is_err());
+        assert!(image
+            .view_region(Rect {
+                x: 3,
+                y: 0,
+                width: 3,
+                height: 1,
+            })
+            .is_err());
+
+        // a region without columns has empty rows
+        let empty = image.view_region(Rect {
+            x: 2,
+            y: 0,
+            width: 0,
+            height: 3,
+        })?;
+        assert_eq!(empty.row(2), Some(&[][..]));
+        assert_eq!(empty.row(3), None);
+
+        let image = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        assert_eq!(image.view().stride_bytes(), 20);
+
+        Ok(())
+    }
+}
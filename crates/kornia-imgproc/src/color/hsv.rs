@@ -100,6 +100,102 @@ pub fn hsv_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(),
     Ok(())
 }
 
+/// Convert a RGB8 image to a HSV image with the hue in degrees.
+///
+/// # Arguments
+///
+/// * `image` - The input RGB image with values in the range [0, 255].
+///
+/// # Returns
+///
+/// The HSV image with the following channels:
+///
+/// * H: The hue in degrees in the range [0, 360), 0 for gray pixels.
+/// * S: The saturation in the range [0, 1].
+/// * V: The value in the range [0, 1].
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::rgb8_to_hsv;
+///
+/// let image = Image::<u8, 3>::new(ImageSize { width: 1, height: 1 }, vec![0, 255, 0]).unwrap();
+///
+/// let hsv = rgb8_to_hsv(&image);
+/// assert_eq!(hsv.as_slice(), &[120.0, 1.0, 1.0]);
+/// ```
+pub fn rgb8_to_hsv(image: &Image<u8, 3>) -> Image<f32, 3> {
+    image.map_pixels(|px| {
+        let [r, g, b] = px.map(|v| v as f32 / 255.0);
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        [h, s, max]
+    })
+}
+
+/// Convert a HSV image with the hue in degrees to a RGB8 image.
+///
+/// This is the inverse of [`rgb8_to_hsv`]. The hue wraps around 360 degrees
+/// and the saturation and the value are clamped to [0, 1].
+///
+/// # Arguments
+///
+/// * `image` - The input HSV image with the hue in degrees and the saturation
+///   and the value in the range [0, 1].
+///
+/// # Returns
+///
+/// The RGB image with values in the range [0, 255].
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::hsv_to_rgb8;
+///
+/// let hsv = Image::<f32, 3>::new(ImageSize { width: 1, height: 1 }, vec![240.0, 1.0, 1.0]).unwrap();
+///
+/// let image = hsv_to_rgb8(&hsv);
+/// assert_eq!(image.as_slice(), &[0, 0, 255]);
+/// ```
+pub fn hsv_to_rgb8(image: &Image<f32, 3>) -> Image<u8, 3> {
+    image.map_pixels(|[h, s, v]| {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+
+        let c = v * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        [r, g, b].map(|channel| ((channel + m) * 255.0).round().clamp(0.0, 255.0) as u8)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
@@ -137,4 +233,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn rgb8_hsv_roundtrip() -> Result<(), ImageError> {
+        // red, green, blue, yellow, gray and black
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            vec![
+                255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 0, 128, 128, 128, 0, 0, 0,
+            ],
+        )?;
+
+        let hsv = super::rgb8_to_hsv(&image);
+        let expected = [
+            [0.0, 1.0, 1.0],
+            [120.0, 1.0, 1.0],
+            [240.0, 1.0, 1.0],
+            [60.0, 1.0, 1.0],
+            [0.0, 0.0, 128.0 / 255.0],
+            [0.0, 0.0, 0.0],
+        ];
+        for (px, expected) in hsv.as_slice().chunks_exact(3).zip(expected.iter()) {
+            for (a, b) in px.iter().zip(expected.iter()) {
+                assert!((a - b).abs() < 1e-5, "{px:?} != {expected:?}");
+            }
+        }
+
+        let image_back = super::hsv_to_rgb8(&hsv);
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        // all the colors of a coarse RGB grid survive the round trip
+        let data = (0..16 * 16 * 16)
+            .flat_map(|i| [(i % 16) * 17, (i / 16 % 16) * 17, (i / 256) * 17].map(|v| v as u8))
+            .collect();
+        let image = Image::<u8, 3>::new([64, 64].into(), data)?;
+        let image_back = super::hsv_to_rgb8(&super::rgb8_to_hsv(&image));
+        for (a, b) in image.as_slice().iter().zip(image_back.as_slice()) {
+            assert!(a.abs_diff(*b) <= 1, "{a} != {b}");
+        }
+
+        // out of range values wrap the hue and clamp the saturation and the value
+        let hsv = Image::<f32, 3>::new([2, 1].into(), vec![480.0, 2.0, 1.5, -120.0, 1.0, 1.0])?;
+        assert_eq!(super::hsv_to_rgb8(&hsv).as_slice(), &[0, 255, 0, 0, 0, 255]);
+
+        Ok(())
+    }
 }
@@ -2,4 +2,7 @@ mod gray;
 mod hsv;
 
 pub use gray::{bgr_from_rgb, gray_from_rgb, gray_from_rgb_u8, rgb_from_gray};
-pub use hsv::hsv_from_rgb;
+pub use hsv::{hsv_from_rgb, hsv_to_rgb8, rgb8_to_hsv};
+
+#[cfg(feature = "simd")]
+pub use gray::gray_from_rgb_u8_simd;
@@ -66,6 +66,49 @@ pub fn std_mean(image: &Image<u8, 3>) -> ([f64; 3], [f64; 3]) {
     (variance, mean)
 }
 
+/// Check if a grayscale image is blank, i.e. has a near-uniform intensity.
+///
+/// The variance of the pixel intensities is computed in one pass and compared
+/// against the given threshold. Empty images are considered blank.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image.
+/// * `variance_threshold` - The variance below which the image is blank.
+///
+/// # Returns
+///
+/// `true` if the variance of the image is below the threshold.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::core::is_blank;
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 4, height: 4 }, 128).unwrap();
+///
+/// assert!(is_blank(&image, 1.0));
+/// ```
+pub fn is_blank(image: &Image<u8, 1>, variance_threshold: f64) -> bool {
+    let n = image.as_slice().len();
+    if n == 0 {
+        return true;
+    }
+
+    let (sum, sq_sum) = image
+        .as_slice()
+        .iter()
+        .fold((0u64, 0u64), |(sum, sq_sum), &p| {
+            (sum + p as u64, sq_sum + (p as u64).pow(2))
+        });
+
+    let mean = sum as f64 / n as f64;
+    let variance = sq_sum as f64 / n as f64 - mean.powi(2);
+
+    variance < variance_threshold
+}
+
 /// Perform a bitwise AND operation between two images using a mask.
 ///
 /// The mask is a binary image where the value 0 is considered as False
@@ -277,6 +320,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_is_blank() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 6,
+        };
+
+        let solid = Image::<u8, 1>::from_size_val(size, 200)?;
+        assert!(super::is_blank(&solid, 1.0));
+
+        // a checkerboard texture
+        let data = (0..size.width * size.height)
+            .map(|i| {
+                if (i % size.width + i / size.width) % 2 == 0 {
+                    50
+                } else {
+                    150
+                }
+            })
+            .collect();
+        let textured = Image::<u8, 1>::new(size, data)?;
+        assert!(!super::is_blank(&textured, 1.0));
+        assert!(super::is_blank(&textured, 2501.0));
+
+        Ok(())
+    }
+
     #[test]
     fn test_bitwise_and() -> Result<(), ImageError> {
         let image = Image::<u8, 3>::new(
@@ -1,9 +1,11 @@
-use kornia_image::{Image, ImageError};
+use kornia_image::{Image, ImageError, ImageSize, InterpolationMode};
 use rayon::{
     iter::{IndexedParallelIterator, ParallelIterator},
     slice::ParallelSliceMut,
 };
 
+use crate::{color::gray_from_rgb_u8, saliency::estimate_subject_box};
+
 /// Crop an image to a specified region.
 ///
 /// # Arguments
@@ -58,6 +60,156 @@ where
     Ok(())
 }
 
+/// Slice a sprite sheet into equally sized frames.
+///
+/// The sheet is divided into a grid of `cols` x `rows` cells of equal size and
+/// each cell is copied into its own image.
+///
+/// # Arguments
+///
+/// * `image` - The sprite sheet with shape (H, W, 4).
+/// * `cols` - The number of cells along the horizontal axis.
+/// * `rows` - The number of cells along the vertical axis.
+///
+/// # Returns
+///
+/// A vector with the frames in row-major order.
+///
+/// # Errors
+///
+/// Returns an error if the sheet dimensions are not divisible by the grid size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::crop::slice_sprite_sheet;
+///
+/// let sheet = Image::<u8, 4>::from_size_val(ImageSize { width: 8, height: 4 }, 0).unwrap();
+///
+/// let frames = slice_sprite_sheet(&sheet, 4, 2).unwrap();
+///
+/// assert_eq!(frames.len(), 8);
+/// assert_eq!(frames[0].size(), ImageSize { width: 2, height: 2 });
+/// ```
+pub fn slice_sprite_sheet(
+    image: &Image<u8, 4>,
+    cols: usize,
+    rows: usize,
+) -> Result<Vec<Image<u8, 4>>, ImageError> {
+    if cols == 0 || rows == 0 || image.cols() % cols != 0 || image.rows() % rows != 0 {
+        return Err(ImageError::InvalidImageSize(
+            image.cols(),
+            image.rows(),
+            cols,
+            rows,
+        ));
+    }
+
+    let frame_size = ImageSize {
+        width: image.cols() / cols,
+        height: image.rows() / rows,
+    };
+
+    let mut frames = Vec::with_capacity(cols * rows);
+    for row in 0..rows {
+        for col in 0..cols {
+            let mut frame = Image::from_size_val(frame_size, 0u8)?;
+            crop_image(
+                image,
+                &mut frame,
+                col * frame_size.width,
+                row * frame_size.height,
+            )?;
+            frames.push(frame);
+        }
+    }
+
+    Ok(frames)
+}
+
+/// Crop an image to a target size keeping its most salient region.
+///
+/// The subject of the image is located with
+/// [`estimate_subject_box`](crate::saliency::estimate_subject_box) on the
+/// grayscale image. The largest window with the aspect ratio of `target` is
+/// then centered on the subject, shifted to stay inside the image, and the
+/// cropped window is resized to `target` with bilinear interpolation.
+///
+/// # Arguments
+///
+/// * `image` - The input image with shape (H, W, 3).
+/// * `target` - The size of the output image.
+///
+/// # Returns
+///
+/// The cropped image with the target size.
+///
+/// # Errors
+///
+/// Returns an error if the target or the image has a zero dimension.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::crop::smart_crop;
+///
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 32, height: 18 }, 1).unwrap();
+///
+/// let cropped = smart_crop(&image, ImageSize { width: 8, height: 8 }).unwrap();
+///
+/// assert_eq!(cropped.size(), ImageSize { width: 8, height: 8 });
+/// ```
+pub fn smart_crop(image: &Image<u8, 3>, target: ImageSize) -> Result<Image<u8, 3>, ImageError> {
+    let (cols, rows) = (image.cols(), image.rows());
+
+    if target.width == 0 || target.height == 0 {
+        return Err(ImageError::InvalidAspectRatio(target.width, target.height));
+    }
+
+    if cols == 0 || rows == 0 {
+        return Err(ImageError::InvalidImageSize(
+            cols,
+            rows,
+            target.width,
+            target.height,
+        ));
+    }
+
+    let mut gray = Image::from_size_val(image.size(), 0u8)?;
+    gray_from_rgb_u8(image, &mut gray)?;
+    let (sx, sy, sw, sh) = estimate_subject_box(&gray);
+
+    // the largest window inside the image with the target aspect ratio
+    let window = if cols * target.height > rows * target.width {
+        ImageSize {
+            width: (rows * target.width / target.height).max(1),
+            height: rows,
+        }
+    } else {
+        ImageSize {
+            width: cols,
+            height: (cols * target.height / target.width).max(1),
+        }
+    };
+
+    // center the window on the subject and clamp it to the image
+    let place =
+        |center: usize, len: usize, max: usize| center.saturating_sub(len / 2).min(max - len);
+    let x = place(sx + sw / 2, window.width, cols);
+    let y = place(sy + sh / 2, window.height, rows);
+
+    let mut cropped = Image::from_size_val(window, 0u8)?;
+    crop_image(image, &mut cropped, x, y)?;
+
+    if window == target {
+        return Ok(cropped);
+    }
+
+    Ok(cropped.resize(target, InterpolationMode::Bilinear))
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
@@ -94,4 +246,93 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_slice_sprite_sheet() -> Result<(), ImageError> {
+        // each 2x2 cell is filled with its own frame index
+        let sheet_size = ImageSize {
+            width: 8,
+            height: 4,
+        };
+        let mut data = vec![0u8; sheet_size.width * sheet_size.height * 4];
+        for y in 0..sheet_size.height {
+            for x in 0..sheet_size.width {
+                let frame_idx = (y / 2) * 4 + x / 2;
+                let offset = (y * sheet_size.width + x) * 4;
+                data[offset..offset + 4].copy_from_slice(&[frame_idx as u8; 4]);
+            }
+        }
+        let sheet = Image::<u8, 4>::new(sheet_size, data)?;
+
+        let frames = super::slice_sprite_sheet(&sheet, 4, 2)?;
+
+        assert_eq!(frames.len(), 8);
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(frame.width(), 2);
+            assert_eq!(frame.height(), 2);
+            assert!(frame.as_slice().iter().all(|&v| v == i as u8));
+        }
+
+        assert!(super::slice_sprite_sheet(&sheet, 3, 2).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_smart_crop() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 96,
+            height: 64,
+        };
+
+        // a checkerboard subject close to the right border on a flat background
+        let (ox, oy, ow, oh) = (70, 20, 20, 20);
+        let data = (0..size.width * size.height)
+            .flat_map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                let inside = (ox..ox + ow).contains(&x) && (oy..oy + oh).contains(&y);
+                let v = match (inside, (x / 2 + y / 2) % 2) {
+                    (true, 0) => 250,
+                    (true, _) => 10,
+                    (false, _) => 128,
+                };
+                [v; 3]
+            })
+            .collect();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        // a square window shifted to the right border includes the subject
+        let target = ImageSize {
+            width: 64,
+            height: 64,
+        };
+        let cropped = super::smart_crop(&image, target)?;
+        assert_eq!(cropped.size(), target);
+        assert_eq!(cropped.as_slice(), image.crop(32, 0, 64, 64)?.as_slice());
+
+        // a geometric center crop would cut the subject
+        let center = image.crop(16, 0, 64, 64)?;
+        assert_ne!(cropped.as_slice(), center.as_slice());
+
+        let resized = super::smart_crop(
+            &image,
+            ImageSize {
+                width: 16,
+                height: 16,
+            },
+        )?;
+        assert_eq!(resized.width(), 16);
+        assert_eq!(resized.height(), 16);
+
+        assert!(super::smart_crop(
+            &image,
+            ImageSize {
+                width: 0,
+                height: 16,
+            }
+        )
+        .is_err());
+
+        Ok(())
+    }
 }
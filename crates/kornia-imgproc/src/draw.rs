@@ -70,9 +70,45 @@ pub fn draw_line<const C: usize>(
     }
 }
 
+/// Draws a grid of horizontal and vertical lines on an image inplace.
+///
+/// The lines are drawn every `spacing` pixels starting from the top-left corner,
+/// i.e. at the columns and rows `spacing`, `2 * spacing`, ... inside the image.
+/// Nothing is drawn if `spacing` is zero or larger than the image.
+///
+/// # Arguments
+///
+/// * `img` - The image to draw on.
+/// * `spacing` - The distance in pixels between two consecutive lines.
+/// * `color` - The color of the lines as an array of `C` elements.
+pub fn draw_grid<const C: usize>(img: &mut Image<u8, C>, spacing: usize, color: [u8; C]) {
+    if spacing == 0 {
+        return;
+    }
+
+    let cols = img.cols();
+
+    img.as_slice_mut()
+        .chunks_exact_mut(cols * C)
+        .enumerate()
+        .for_each(|(y, row)| {
+            if y > 0 && y % spacing == 0 {
+                // horizontal line
+                row.chunks_exact_mut(C)
+                    .for_each(|pixel| pixel.copy_from_slice(&color));
+            } else {
+                // vertical lines
+                row.chunks_exact_mut(C)
+                    .skip(spacing)
+                    .step_by(spacing)
+                    .for_each(|pixel| pixel.copy_from_slice(&color));
+            }
+        });
+}
+
 #[cfg(test)]
 mod tests {
-    use super::draw_line;
+    use super::{draw_grid, draw_line};
     use kornia_image::{Image, ImageError, ImageSize};
 
     #[rustfmt::skip]
@@ -98,4 +134,31 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_draw_grid() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 10,
+            height: 7,
+        };
+        let mut img = Image::<u8, 3>::from_size_val(size, 0)?;
+        draw_grid(&mut img, 3, [255, 0, 128]);
+
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let on_grid = (x > 0 && x % 3 == 0) || (y > 0 && y % 3 == 0);
+                let expected = if on_grid { [255, 0, 128] } else { [0, 0, 0] };
+                for (c, &v) in expected.iter().enumerate() {
+                    assert_eq!(img.get_pixel(x, y, c)?, &v);
+                }
+            }
+        }
+
+        // spacing larger than the image draws nothing
+        let mut img = Image::<u8, 3>::from_size_val(size, 0)?;
+        draw_grid(&mut img, 20, [255, 255, 255]);
+        assert!(img.as_slice().iter().all(|&v| v == 0));
+
+        Ok(())
+    }
 }
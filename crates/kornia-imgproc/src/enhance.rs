@@ -1,4 +1,5 @@
 use kornia_image::{Image, ImageError};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::parallel;
 
@@ -60,6 +61,126 @@ where
     Ok(())
 }
 
+/// Blend modes to composite a top layer over a base layer.
+///
+/// The formulas operate on the pixel values normalized to [0, 1], with `a` the
+/// base value and `b` the top value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Darkens the base: `a * b`.
+    Multiply,
+    /// Lightens the base: `1 - (1 - a) * (1 - b)`.
+    Screen,
+    /// Multiplies the dark areas and screens the light areas of the base:
+    /// `2 * a * b` if `a < 0.5`, otherwise `1 - 2 * (1 - a) * (1 - b)`.
+    Overlay,
+    /// Linear dodge: `min(a + b, 1)`.
+    Add,
+}
+
+/// Composites a top layer over a base layer with the given blend mode.
+///
+/// # Arguments
+///
+/// * `base` - The base layer.
+/// * `top` - The top layer with the same size as the base layer.
+/// * `mode` - The blend mode to combine the layers.
+///
+/// # Returns
+///
+/// Returns a new `Image` with the blended layers, rounded to the nearest integer.
+///
+/// # Errors
+///
+/// Returns an error if the sizes of `base` and `top` do not match.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::enhance::{blend_mode, BlendMode};
+///
+/// let size = ImageSize { width: 2, height: 2 };
+/// let base = Image::<u8, 3>::from_size_val(size, 100).unwrap();
+/// let top = Image::<u8, 3>::from_size_val(size, 200).unwrap();
+///
+/// let blended = blend_mode(&base, &top, BlendMode::Add).unwrap();
+/// assert!(blended.as_slice().iter().all(|&v| v == 255));
+/// ```
+pub fn blend_mode(
+    base: &Image<u8, 3>,
+    top: &Image<u8, 3>,
+    mode: BlendMode,
+) -> Result<Image<u8, 3>, ImageError> {
+    if base.size() != top.size() {
+        return Err(ImageError::InvalidImageSize(
+            base.cols(),
+            base.rows(),
+            top.cols(),
+            top.rows(),
+        ));
+    }
+
+    let mut dst = Image::from_size_val(base.size(), 0u8)?;
+
+    parallel::par_iter_rows_val_two(base, top, &mut dst, |&base_pixel, &top_pixel, dst_pixel| {
+        let a = base_pixel as f32 / 255.0;
+        let b = top_pixel as f32 / 255.0;
+        let blended = match mode {
+            BlendMode::Multiply => a * b,
+            BlendMode::Screen => 1.0 - (1.0 - a) * (1.0 - b),
+            BlendMode::Overlay if a < 0.5 => 2.0 * a * b,
+            BlendMode::Overlay => 1.0 - 2.0 * (1.0 - a) * (1.0 - b),
+            BlendMode::Add => (a + b).min(1.0),
+        };
+        *dst_pixel = (blended * 255.0).round() as u8;
+    });
+
+    Ok(dst)
+}
+
+/// Add Gaussian noise to an image, e.g. to simulate film grain.
+///
+/// A value drawn from a normal distribution with zero mean and standard
+/// deviation `sigma` is added to each channel of each pixel and the result is
+/// clamped to [0, 255]. The noise is generated by a random number generator
+/// seeded with `seed`, so the same seed always produces the same output.
+///
+/// # Arguments
+///
+/// * `image` - The RGB image to add the noise to.
+/// * `sigma` - The standard deviation of the noise in intensity levels.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::enhance::add_noise;
+///
+/// let size = ImageSize { width: 4, height: 4 };
+/// let mut image = Image::<u8, 3>::from_size_val(size, 128).unwrap();
+///
+/// add_noise(&mut image, 8.0, 42);
+/// assert!(image.as_slice().iter().any(|&v| v != 128));
+/// ```
+pub fn add_noise(image: &mut Image<u8, 3>, sigma: f32, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // the Box-Muller transform maps two uniform samples to two normal samples
+    for values in image.as_slice_mut().chunks_mut(2) {
+        let u1 = 1.0 - rng.random::<f32>();
+        let u2 = rng.random::<f32>();
+        let radius = sigma * (-2.0 * u1.ln()).sqrt();
+        let angle = 2.0 * std::f32::consts::PI * u2;
+
+        let noise = [radius * angle.cos(), radius * angle.sin()];
+        for (v, n) in values.iter_mut().zip(noise) {
+            *v = (*v as f32 + n).round().clamp(0.0, 255.0) as u8;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
@@ -101,4 +222,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_add_noise() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 64,
+            height: 48,
+        };
+        let image = Image::<u8, 3>::from_size_val(size, 128)?;
+
+        let mut noisy = image.clone();
+        super::add_noise(&mut noisy, 10.0, 7);
+
+        // the same seed produces the same noise
+        let mut noisy_again = image.clone();
+        super::add_noise(&mut noisy_again, 10.0, 7);
+        assert_eq!(noisy.as_slice(), noisy_again.as_slice());
+
+        let mut other = image.clone();
+        super::add_noise(&mut other, 10.0, 8);
+        assert_ne!(noisy.as_slice(), other.as_slice());
+
+        // the noise has zero mean and the requested spread
+        let n = noisy.as_slice().len() as f32;
+        let mean = noisy.as_slice().iter().map(|&v| v as f32).sum::<f32>() / n;
+        let var = noisy
+            .as_slice()
+            .iter()
+            .map(|&v| (v as f32 - mean).powi(2))
+            .sum::<f32>()
+            / n;
+        assert!((mean - 128.0).abs() < 1.0, "mean: {mean}");
+        assert!((var.sqrt() - 10.0).abs() < 1.0, "std: {}", var.sqrt());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blend_mode() -> Result<(), ImageError> {
+        use super::BlendMode;
+
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+        let base = Image::<u8, 3>::new(size, vec![100, 200, 0, 200, 100, 255])?;
+        let top = Image::<u8, 3>::new(size, vec![200, 100, 255, 100, 200, 0])?;
+
+        let cases = [
+            (BlendMode::Multiply, [78, 78, 0, 78, 78, 0]),
+            (BlendMode::Screen, [222, 222, 255, 222, 222, 255]),
+            (BlendMode::Overlay, [157, 188, 0, 188, 157, 255]),
+            (BlendMode::Add, [255, 255, 255, 255, 255, 255]),
+        ];
+
+        for (mode, expected) in cases {
+            let blended = super::blend_mode(&base, &top, mode)?;
+            assert_eq!(blended.size(), size);
+            assert_eq!(blended.as_slice(), &expected, "mode: {mode:?}");
+        }
+
+        let small = Image::<u8, 3>::from_size_val([1, 1].into(), 0)?;
+        assert!(super::blend_mode(&base, &small, BlendMode::Add).is_err());
+
+        Ok(())
+    }
 }
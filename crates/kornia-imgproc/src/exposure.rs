@@ -0,0 +1,135 @@
+use kornia_image::Image;
+
+/// Exposure statistics of a rectangular region of a grayscale image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExposureZone {
+    /// The x coordinate of the top-left corner of the region.
+    pub x: usize,
+    /// The y coordinate of the top-left corner of the region.
+    pub y: usize,
+    /// The width of the region in pixels.
+    pub width: usize,
+    /// The height of the region in pixels.
+    pub height: usize,
+    /// The mean brightness of the region in the range [0, 255].
+    pub mean: f32,
+    /// The fraction of pixels clipped to black (value 0).
+    pub shadows_clipped: f32,
+    /// The fraction of pixels clipped to white (value 255).
+    pub highlights_clipped: f32,
+}
+
+/// Compute the exposure of the cells of a grid over a grayscale image.
+///
+/// The image is divided into a grid of cells of approximately the same size and
+/// the mean brightness and clipping fractions are computed for each cell. This
+/// can be used to decide the exposure bracketing of a scene, e.g. a cell with a
+/// large fraction of clipped highlights needs a shorter exposure.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image with shape (H, W, 1).
+/// * `grid` - The number of cells as (columns, rows).
+///
+/// # Returns
+///
+/// The exposure of each cell in row-major order. Cells not covering any pixel,
+/// when the grid is larger than the image, have all their statistics set to 0.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::exposure::exposure_zones;
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 8, height: 8 }, 255).unwrap();
+///
+/// let zones = exposure_zones(&image, (2, 2));
+///
+/// assert_eq!(zones.len(), 4);
+/// assert!(zones.iter().all(|zone| zone.highlights_clipped == 1.0));
+/// ```
+pub fn exposure_zones(image: &Image<u8, 1>, grid: (usize, usize)) -> Vec<ExposureZone> {
+    let (grid_cols, grid_rows) = grid;
+    let (cols, rows) = (image.cols(), image.rows());
+    let data = image.as_slice();
+
+    // the cell boundaries along one axis
+    let bounds = |i: usize, n: usize, len: usize| (i * len / n)..((i + 1) * len / n);
+
+    let mut zones = Vec::with_capacity(grid_cols * grid_rows);
+
+    for gy in 0..grid_rows {
+        let ys = bounds(gy, grid_rows, rows);
+        for gx in 0..grid_cols {
+            let xs = bounds(gx, grid_cols, cols);
+
+            let (mut sum, mut num_black, mut num_white) = (0u64, 0usize, 0usize);
+            for y in ys.clone() {
+                for &v in &data[y * cols + xs.start..y * cols + xs.end] {
+                    sum += v as u64;
+                    num_black += (v == 0) as usize;
+                    num_white += (v == 255) as usize;
+                }
+            }
+
+            let n = (xs.len() * ys.len()).max(1) as f32;
+            zones.push(ExposureZone {
+                x: xs.start,
+                y: ys.start,
+                width: xs.len(),
+                height: ys.len(),
+                mean: sum as f32 / n,
+                shadows_clipped: num_black as f32 / n,
+                highlights_clipped: num_white as f32 / n,
+            });
+        }
+    }
+
+    zones
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_exposure_zones() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 6,
+        };
+
+        // a mid-gray image with a clipped highlight in the top-right cell
+        let data = (0..size.width * size.height)
+            .map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                if x >= 4 && y < 3 {
+                    255
+                } else {
+                    100
+                }
+            })
+            .collect();
+        let image = Image::<u8, 1>::new(size, data)?;
+
+        let zones = super::exposure_zones(&image, (2, 2));
+        assert_eq!(zones.len(), 4);
+
+        let bright = zones[1];
+        assert_eq!((bright.x, bright.y), (4, 0));
+        assert_eq!((bright.width, bright.height), (4, 3));
+        assert_eq!(bright.mean, 255.0);
+        assert_eq!(bright.highlights_clipped, 1.0);
+        assert_eq!(bright.shadows_clipped, 0.0);
+
+        for zone in [zones[0], zones[2], zones[3]] {
+            assert_eq!(zone.mean, 100.0);
+            assert_eq!(zone.highlights_clipped, 0.0);
+        }
+
+        assert!(super::exposure_zones(&image, (0, 2)).is_empty());
+
+        Ok(())
+    }
+}
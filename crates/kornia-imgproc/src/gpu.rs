@@ -0,0 +1,84 @@
+use kornia_image::Image;
+
+/// Pack an RGB image into an RGBA buffer with aligned rows for GPU upload.
+///
+/// Each pixel is expanded to RGBA with an opaque alpha (255) and every row is
+/// padded with zeros to a multiple of `row_alignment` bytes, as required for
+/// example by wgpu buffer to texture copies (256 bytes).
+///
+/// # Arguments
+///
+/// * `image` - The input RGB image with shape (H, W, 3).
+/// * `row_alignment` - The alignment in bytes of each row. A value of 0 is treated as 1.
+///
+/// # Returns
+///
+/// The packed buffer and the padded row stride in bytes.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::gpu::to_gpu_buffer;
+///
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 10, height: 2 }, 7).unwrap();
+///
+/// let (buffer, stride) = to_gpu_buffer(&image, 256);
+/// assert_eq!(stride, 256);
+/// assert_eq!(buffer.len(), 2 * 256);
+/// assert_eq!(&buffer[..4], &[7, 7, 7, 255]);
+/// ```
+pub fn to_gpu_buffer(image: &Image<u8, 3>, row_alignment: usize) -> (Vec<u8>, usize) {
+    let row_len = image.cols() * 4;
+    let stride = row_len.next_multiple_of(row_alignment.max(1));
+
+    let mut buffer = vec![0u8; stride * image.rows()];
+
+    if image.cols() > 0 {
+        buffer
+            .chunks_exact_mut(stride)
+            .zip(image.as_slice().chunks_exact(image.cols() * 3))
+            .for_each(|(dst_row, src_row)| {
+                dst_row[..row_len]
+                    .chunks_exact_mut(4)
+                    .zip(src_row.chunks_exact(3))
+                    .for_each(|(dst, src)| {
+                        dst[..3].copy_from_slice(src);
+                        dst[3] = 255;
+                    });
+            });
+    }
+
+    (buffer, stride)
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_to_gpu_buffer() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let data = (0..size.width * size.height * 3).map(|i| i as u8).collect();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        let (buffer, stride) = super::to_gpu_buffer(&image, 256);
+        assert_eq!(stride % 256, 0);
+        assert_eq!(buffer.len(), stride * size.height);
+
+        // the first row holds the real pixels followed by the padding
+        assert_eq!(&buffer[..12], &[0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 255]);
+        assert!(buffer[12..stride].iter().all(|&v| v == 0));
+        assert_eq!(&buffer[stride..stride + 4], &[9, 10, 11, 255]);
+
+        // an alignment smaller than the row keeps the rows tightly packed
+        let (buffer, stride) = super::to_gpu_buffer(&image, 4);
+        assert_eq!(stride, 12);
+        assert_eq!(buffer.len(), 24);
+
+        Ok(())
+    }
+}
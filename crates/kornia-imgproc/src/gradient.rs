@@ -0,0 +1,213 @@
+use kornia_image::{Image, ImageError};
+use rayon::prelude::*;
+
+// compute the 3x3 sobel derivatives (gx, gy) at (x, y) replicating the border pixels
+pub(crate) fn sobel_at(data: &[u8], cols: usize, rows: usize, x: usize, y: usize) -> (f32, f32) {
+    let xs = [x.saturating_sub(1), x, (x + 1).min(cols - 1)];
+    let ys = [y.saturating_sub(1), y, (y + 1).min(rows - 1)];
+    let px = |i: usize, j: usize| data[ys[j] * cols + xs[i]] as f32;
+
+    let gx = (px(2, 0) + 2.0 * px(2, 1) + px(2, 2)) - (px(0, 0) + 2.0 * px(0, 1) + px(0, 2));
+    let gy = (px(0, 2) + 2.0 * px(1, 2) + px(2, 2)) - (px(0, 0) + 2.0 * px(1, 0) + px(2, 0));
+
+    (gx, gy)
+}
+
+/// Compute the gradient magnitude and orientation of a grayscale image.
+///
+/// The horizontal and vertical derivatives are computed with a 3x3 Sobel
+/// operator, replicating the border pixels outside the image.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image with shape (H, W, 1).
+///
+/// # Returns
+///
+/// A tuple with the magnitude and the orientation images with shape (H, W, 1).
+/// The orientation is in radians in the range [-pi, pi], where 0 points along
+/// the positive x axis.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::gradient::image_gradients;
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 4, height: 4 }, 100).unwrap();
+///
+/// let (magnitude, orientation) = image_gradients(&image).unwrap();
+///
+/// assert!(magnitude.as_slice().iter().all(|&v| v == 0.0));
+/// assert_eq!(orientation.size(), image.size());
+/// ```
+pub fn image_gradients(image: &Image<u8, 1>) -> Result<(Image<f32, 1>, Image<f32, 1>), ImageError> {
+    let mut magnitude = Image::from_size_val(image.size(), 0.0f32)?;
+    let mut orientation = Image::from_size_val(image.size(), 0.0f32)?;
+
+    let (cols, rows) = (image.cols(), image.rows());
+    if cols == 0 || rows == 0 {
+        return Ok((magnitude, orientation));
+    }
+    let data = image.as_slice();
+
+    magnitude
+        .as_slice_mut()
+        .par_chunks_exact_mut(cols)
+        .zip(orientation.as_slice_mut().par_chunks_exact_mut(cols))
+        .enumerate()
+        .for_each(|(y, (mag_row, ori_row))| {
+            mag_row
+                .iter_mut()
+                .zip(ori_row.iter_mut())
+                .enumerate()
+                .for_each(|(x, (mag, ori))| {
+                    let (gx, gy) = sobel_at(data, cols, rows, x, y);
+                    *mag = (gx * gx + gy * gy).sqrt();
+                    *ori = gy.atan2(gx);
+                });
+        });
+
+    Ok((magnitude, orientation))
+}
+
+/// Compute a tangent-space normal map from a grayscale heightmap.
+///
+/// The height gradients are computed with a 3x3 Sobel operator on the heights
+/// normalized to [0, 1], replicating the border pixels outside the image. The
+/// normal at each pixel is `normalize(-strength * dh/dx, -strength * dh/dy, 1)`,
+/// with x along the columns and y along the rows.
+///
+/// # Arguments
+///
+/// * `height` - The input heightmap with shape (H, W, 1).
+/// * `strength` - The scale of the height gradients. Larger values produce steeper normals.
+///
+/// # Returns
+///
+/// The normal map with shape (H, W, 3), where each component is encoded in the RGB
+/// channels with the mapping `(n + 1) / 2 * 255`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::gradient::heightmap_to_normalmap;
+///
+/// let height = Image::<u8, 1>::from_size_val(ImageSize { width: 4, height: 4 }, 100).unwrap();
+///
+/// let normals = heightmap_to_normalmap(&height, 1.0).unwrap();
+///
+/// assert_eq!(normals.get_pixel(0, 0, 2).unwrap(), &255);
+/// ```
+pub fn heightmap_to_normalmap(
+    height: &Image<u8, 1>,
+    strength: f32,
+) -> Result<Image<u8, 3>, ImageError> {
+    let mut normals = Image::from_size_val(height.size(), 0u8)?;
+
+    let (cols, rows) = (height.cols(), height.rows());
+    if cols == 0 || rows == 0 {
+        return Ok(normals);
+    }
+    let data = height.as_slice();
+
+    // the sobel kernel weights sum to 8 on each side
+    let scale = strength / (8.0 * 255.0);
+    let encode = |n: f32| ((n + 1.0) * 0.5 * 255.0).round() as u8;
+
+    normals
+        .as_slice_mut()
+        .par_chunks_exact_mut(3 * cols)
+        .enumerate()
+        .for_each(|(y, row)| {
+            row.chunks_exact_mut(3).enumerate().for_each(|(x, pixel)| {
+                let (gx, gy) = sobel_at(data, cols, rows, x, y);
+                let (nx, ny, nz) = (-gx * scale, -gy * scale, 1.0);
+                let norm = (nx * nx + ny * ny + nz * nz).sqrt();
+                pixel[0] = encode(nx / norm);
+                pixel[1] = encode(ny / norm);
+                pixel[2] = encode(nz / norm);
+            });
+        });
+
+    Ok(normals)
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_image_gradients() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 5,
+        };
+
+        // vertical edge between columns 3 and 4: dark on the left, bright on the right
+        let data = (0..size.width * size.height)
+            .map(|i| if i % size.width < 4 { 0 } else { 200 })
+            .collect::<Vec<_>>();
+        let image = Image::<u8, 1>::new(size, data)?;
+
+        let (magnitude, orientation) = super::image_gradients(&image)?;
+        assert_eq!(magnitude.size(), size);
+        assert_eq!(orientation.size(), size);
+
+        let max = magnitude
+            .as_slice()
+            .iter()
+            .fold(0.0f32, |acc, &v| acc.max(v));
+        assert_eq!(max, 800.0);
+
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let mag = *magnitude.get_pixel(x, y, 0)?;
+                if x == 3 || x == 4 {
+                    // the magnitude peaks at the edge with a horizontal gradient
+                    assert_eq!(mag, max);
+                    assert!(orientation.get_pixel(x, y, 0)?.abs() < 1e-6);
+                } else {
+                    assert_eq!(mag, 0.0);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_heightmap_to_normalmap() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 4,
+        };
+
+        // a flat heightmap points all the normals up
+        let flat = Image::<u8, 1>::from_size_val(size, 80)?;
+        let normals = super::heightmap_to_normalmap(&flat, 2.0)?;
+        assert_eq!(normals.size(), size);
+        normals
+            .as_slice()
+            .chunks_exact(3)
+            .for_each(|pixel| assert_eq!(pixel, &[128, 128, 255]));
+
+        // a ramp rising along x tilts the normals towards -x
+        let data = (0..size.width * size.height)
+            .map(|i| (i % size.width * 40) as u8)
+            .collect();
+        let ramp = Image::<u8, 1>::new(size, data)?;
+        let normals = super::heightmap_to_normalmap(&ramp, 2.0)?;
+        let pixel = [
+            *normals.get_pixel(2, 1, 0)?,
+            *normals.get_pixel(2, 1, 1)?,
+            *normals.get_pixel(2, 1, 2)?,
+        ];
+        assert!(pixel[0] < 128, "pixel: {pixel:?}");
+        assert_eq!(pixel[1], 128);
+        assert!(pixel[2] < 255, "pixel: {pixel:?}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use kornia_image::{Image, ImageError};
+
+// downscale a grayscale image to (width, height) averaging the pixels of each cell
+fn resize_area(image: &Image<u8, 1>, width: usize, height: usize) -> Result<Vec<f32>, ImageError> {
+    let (cols, rows) = (image.cols(), image.rows());
+    if cols == 0 || rows == 0 {
+        return Err(ImageError::ImageDataNotInitialized);
+    }
+
+    // the cell boundaries, each cell covers at least one pixel
+    let bounds = |i: usize, n: usize, len: usize| {
+        let start = (i * len / n).min(len - 1);
+        let end = ((i + 1) * len / n).max(start + 1);
+        start..end
+    };
+
+    let data = image.as_slice();
+    let mut cells = Vec::with_capacity(width * height);
+
+    for cy in 0..height {
+        let ys = bounds(cy, height, rows);
+        for cx in 0..width {
+            let xs = bounds(cx, width, cols);
+            let sum = ys
+                .clone()
+                .flat_map(|y| data[y * cols + xs.start..y * cols + xs.end].iter())
+                .map(|&v| v as u64)
+                .sum::<u64>();
+            cells.push((sum as f64 / (ys.len() * xs.len()) as f64) as f32);
+        }
+    }
+
+    Ok(cells)
+}
+
+/// Compute the average hash (aHash) of a grayscale image.
+///
+/// The image is downscaled to 8x8 by area averaging and each bit of the hash is
+/// set if the corresponding cell is brighter than the mean of all the cells.
+/// Similar images produce hashes with a small Hamming distance.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image with shape (H, W, 1).
+///
+/// # Returns
+///
+/// The 64-bit hash, with the top-left cell in the most significant bit.
+///
+/// # Errors
+///
+/// Returns an error if the image is empty.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::hash::average_hash;
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 16, height: 16 }, 0).unwrap();
+///
+/// assert_eq!(average_hash(&image).unwrap(), 0);
+/// ```
+pub fn average_hash(image: &Image<u8, 1>) -> Result<u64, ImageError> {
+    let cells = resize_area(image, 8, 8)?;
+    let mean = cells.iter().sum::<f32>() / cells.len() as f32;
+
+    let hash = cells
+        .iter()
+        .fold(0u64, |hash, &v| (hash << 1) | (v > mean) as u64);
+
+    Ok(hash)
+}
+
+/// Compute the difference hash (dHash) of a grayscale image.
+///
+/// The image is downscaled to 9x8 by area averaging and each bit of the hash is
+/// set if a cell is brighter than its right neighbour, encoding the direction
+/// of the horizontal gradients. Similar images produce hashes with a small
+/// Hamming distance.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image with shape (H, W, 1).
+///
+/// # Returns
+///
+/// The 64-bit hash, with the top-left comparison in the most significant bit.
+///
+/// # Errors
+///
+/// Returns an error if the image is empty.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::hash::difference_hash;
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 16, height: 16 }, 0).unwrap();
+///
+/// assert_eq!(difference_hash(&image).unwrap(), 0);
+/// ```
+pub fn difference_hash(image: &Image<u8, 1>) -> Result<u64, ImageError> {
+    let cells = resize_area(image, 9, 8)?;
+
+    let hash = cells
+        .chunks_exact(9)
+        .flat_map(|row| row.windows(2))
+        .fold(0u64, |hash, w| (hash << 1) | (w[0] > w[1]) as u64);
+
+    Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    // a horizontal ramp with some vertical texture
+    fn test_image() -> Result<Image<u8, 1>, ImageError> {
+        let size = ImageSize {
+            width: 72,
+            height: 64,
+        };
+        let data = (0..size.width * size.height)
+            .map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                (x * 3 + (y / 8 % 2) * 20) as u8
+            })
+            .collect();
+        Image::new(size, data)
+    }
+
+    #[test]
+    fn test_average_hash() -> Result<(), ImageError> {
+        let image = test_image()?;
+        let hash = super::average_hash(&image)?;
+        assert_ne!(hash, 0);
+
+        // a brighter copy keeps the same structure
+        let mut brighter = image.clone();
+        brighter
+            .as_slice_mut()
+            .iter_mut()
+            .for_each(|v| *v = v.saturating_add(10));
+
+        let distance = (hash ^ super::average_hash(&brighter)?).count_ones();
+        assert!(distance <= 4, "distance: {distance}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_difference_hash() -> Result<(), ImageError> {
+        let image = test_image()?;
+        let hash = super::difference_hash(&image)?;
+
+        // the mirrored image reverses all the horizontal gradients
+        let mut mirrored = image.clone();
+        crate::flip::horizontal_flip(&image, &mut mirrored)?;
+
+        let distance = (hash ^ super::difference_hash(&mirrored)?).count_ones();
+        assert!(distance >= 48, "distance: {distance}");
+
+        Ok(())
+    }
+}
@@ -2,14 +2,7 @@ use super::bilinear::bilinear_interpolation;
 use super::nearest::nearest_neighbor_interpolation;
 use kornia_image::Image;
 
-/// Interpolation mode for the resize operation
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum InterpolationMode {
-    /// Bilinear interpolation
-    Bilinear,
-    /// Nearest neighbor interpolation
-    Nearest,
-}
+pub use kornia_image::InterpolationMode;
 
 /// Kernel for interpolating a pixel value
 ///
@@ -21,6 +21,9 @@ pub mod draw;
 /// image enhancement module.
 pub mod enhance;
 
+/// exposure analysis module.
+pub mod exposure;
+
 /// feature detection module.
 pub mod features;
 
@@ -30,6 +33,15 @@ pub mod filter;
 /// image flipping module.
 pub mod flip;
 
+/// utilities to prepare images for GPU upload.
+pub mod gpu;
+
+/// image gradient module.
+pub mod gradient;
+
+/// perceptual image hashing module.
+pub mod hash;
+
 /// compute image histogram module.
 pub mod histogram;
 
@@ -42,12 +54,24 @@ pub mod parallel;
 /// image processing metrics module.
 pub mod metrics;
 
+/// motion detection and background modeling module.
+pub mod motion;
+
 /// operations to normalize images.
 pub mod normalize;
 
+/// image padding module.
+pub mod pad;
+
+/// image pyramids module.
+pub mod pyramid;
+
 /// utility functions for resizing images.
 pub mod resize;
 
+/// saliency estimation module.
+pub mod saliency;
+
 /// operations to threshold images.
 pub mod threshold;
 
@@ -0,0 +1,135 @@
+use kornia_image::Image;
+
+// the size of the DCT blocks of JPEG and most block-based codecs
+const BLOCK_SIZE: usize = 8;
+
+/// Compute the blockiness score of a grayscale image.
+///
+/// Block-based codecs such as JPEG compress each 8x8 block independently, so
+/// heavy compression produces discontinuities at the block boundaries. The
+/// score is the mean absolute difference between neighboring pixels across the
+/// 8-pixel block boundaries divided by the mean absolute difference between
+/// neighboring pixels within the blocks, in both directions.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image with shape (H, W, 1).
+///
+/// # Returns
+///
+/// The blockiness score. A score close to 1.0 means the boundaries are no
+/// different from the rest of the image, higher scores mean stronger blocking
+/// artifacts. Images without block boundaries, e.g. smaller than 9x9 pixels,
+/// or without any variation score 1.0.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::metrics::blockiness_score;
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 16, height: 16 }, 100).unwrap();
+///
+/// assert_eq!(blockiness_score(&image), 1.0);
+/// ```
+pub fn blockiness_score(image: &Image<u8, 1>) -> f64 {
+    let (cols, rows) = (image.cols(), image.rows());
+    let data = image.as_slice();
+
+    // accumulated differences and counts across and within the block boundaries
+    let (mut boundary, mut boundary_count) = (0u64, 0u64);
+    let (mut within, mut within_count) = (0u64, 0u64);
+
+    let mut accumulate = |a: u8, b: u8, is_boundary: bool| {
+        let diff = a.abs_diff(b) as u64;
+        if is_boundary {
+            boundary += diff;
+            boundary_count += 1;
+        } else {
+            within += diff;
+            within_count += 1;
+        }
+    };
+
+    for y in 0..rows {
+        for x in 0..cols {
+            let value = data[y * cols + x];
+            if x + 1 < cols {
+                accumulate(
+                    value,
+                    data[y * cols + x + 1],
+                    x % BLOCK_SIZE == BLOCK_SIZE - 1,
+                );
+            }
+            if y + 1 < rows {
+                accumulate(
+                    value,
+                    data[(y + 1) * cols + x],
+                    y % BLOCK_SIZE == BLOCK_SIZE - 1,
+                );
+            }
+        }
+    }
+
+    if boundary_count == 0 || within_count == 0 {
+        return 1.0;
+    }
+
+    let boundary_mean = boundary as f64 / boundary_count as f64;
+    let within_mean = within as f64 / within_count as f64;
+
+    match (boundary_mean > 0.0, within_mean > 0.0) {
+        (false, false) => 1.0,
+        (true, false) => f64::INFINITY,
+        _ => boundary_mean / within_mean,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageSize};
+
+    #[test]
+    fn test_blockiness_score() -> Result<(), Box<dyn std::error::Error>> {
+        let original = image::open("../../tests/data/dog.png")?.to_luma8();
+        let (width, height) = original.dimensions();
+
+        // compress the lossless original heavily
+        let mut jpeg_data = Vec::new();
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg_data, 5).encode(
+            original.as_raw(),
+            width,
+            height,
+            image::ExtendedColorType::L8,
+        )?;
+        let compressed = image::load_from_memory(&jpeg_data)?.to_luma8();
+
+        let size = ImageSize {
+            width: width as usize,
+            height: height as usize,
+        };
+        let original = Image::<u8, 1>::new(size, original.into_raw())?;
+        let compressed = Image::<u8, 1>::new(size, compressed.into_raw())?;
+
+        let original_score = super::blockiness_score(&original);
+        let compressed_score = super::blockiness_score(&compressed);
+        assert!(
+            compressed_score > original_score,
+            "compressed: {compressed_score}, original: {original_score}"
+        );
+
+        // a pattern with steps at the block boundaries only
+        let steps = Image::<u8, 1>::new(
+            ImageSize {
+                width: 16,
+                height: 16,
+            },
+            (0..256)
+                .map(|i| if (i % 16) < 8 { 50 } else { 60 })
+                .collect(),
+        )?;
+        assert_eq!(super::blockiness_score(&steps), f64::INFINITY);
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+mod blockiness;
 mod huber;
 mod l1;
 mod mse;
 
+pub use blockiness::blockiness_score;
 pub use huber::huber;
 pub use l1::l1_loss;
 pub use mse::{mse, psnr};
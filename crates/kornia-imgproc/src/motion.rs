@@ -0,0 +1,408 @@
+use kornia_image::{Image, ImageError};
+
+/// Running background model based on an exponential moving average.
+///
+/// The model keeps a floating point estimate of the background that is updated
+/// with every new frame as:
+///
+/// bg(x,y,c) = (1 - alpha) * bg(x,y,c) + alpha * frame(x,y,c)
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::motion::BackgroundModel;
+///
+/// let frame = Image::<u8, 3>::from_size_val(ImageSize { width: 4, height: 4 }, 10).unwrap();
+///
+/// let mut model = BackgroundModel::new();
+/// model.update(&frame, 0.1).unwrap();
+///
+/// let mask = model.foreground_mask(&frame, 20.0).unwrap();
+/// assert!(mask.as_slice().iter().all(|&v| v == 0));
+/// ```
+#[derive(Clone, Default)]
+pub struct BackgroundModel {
+    background: Option<Image<f32, 3>>,
+}
+
+impl BackgroundModel {
+    /// Create a new empty background model.
+    ///
+    /// The model is initialized with the first frame passed to [`BackgroundModel::update`].
+    pub fn new() -> Self {
+        Self { background: None }
+    }
+
+    /// Get the current background estimate, if any frame has been seen.
+    pub fn background(&self) -> Option<&Image<f32, 3>> {
+        self.background.as_ref()
+    }
+
+    /// Update the background estimate with a new frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The new frame with shape (H, W, 3).
+    /// * `alpha` - The learning rate in the range [0, 1]. Higher values adapt faster.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame size does not match the model size.
+    pub fn update(&mut self, frame: &Image<u8, 3>, alpha: f32) -> Result<(), ImageError> {
+        let background = match self.background.as_mut() {
+            Some(background) => background,
+            None => {
+                self.background = Some(frame.cast::<f32>()?);
+                return Ok(());
+            }
+        };
+
+        if background.size() != frame.size() {
+            return Err(ImageError::InvalidImageSize(
+                frame.cols(),
+                frame.rows(),
+                background.cols(),
+                background.rows(),
+            ));
+        }
+
+        background
+            .as_slice_mut()
+            .iter_mut()
+            .zip(frame.as_slice().iter())
+            .for_each(|(bg, &px)| {
+                *bg = (1.0 - alpha) * *bg + alpha * px as f32;
+            });
+
+        Ok(())
+    }
+
+    /// Compute the foreground mask of a frame against the current background.
+    ///
+    /// A pixel is flagged as foreground (255) if any of its channels differs from
+    /// the background by more than `threshold`, otherwise it is set to 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The frame to test with shape (H, W, 3).
+    /// * `threshold` - The absolute difference above which a pixel is foreground.
+    ///
+    /// # Returns
+    ///
+    /// The binary motion mask with shape (H, W, 1).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model has not been updated yet or the frame size
+    /// does not match the model size.
+    pub fn foreground_mask(
+        &self,
+        frame: &Image<u8, 3>,
+        threshold: f32,
+    ) -> Result<Image<u8, 1>, ImageError> {
+        let background = self
+            .background
+            .as_ref()
+            .ok_or(ImageError::ImageDataNotInitialized)?;
+
+        if background.size() != frame.size() {
+            return Err(ImageError::InvalidImageSize(
+                frame.cols(),
+                frame.rows(),
+                background.cols(),
+                background.rows(),
+            ));
+        }
+
+        let mut mask = Image::from_size_val(frame.size(), 0u8)?;
+
+        mask.as_slice_mut()
+            .iter_mut()
+            .zip(frame.as_slice().chunks_exact(3))
+            .zip(background.as_slice().chunks_exact(3))
+            .for_each(|((m, px), bg)| {
+                let is_foreground = px
+                    .iter()
+                    .zip(bg.iter())
+                    .any(|(&p, &b)| (p as f32 - b).abs() > threshold);
+                *m = if is_foreground { 255 } else { 0 };
+            });
+
+        Ok(mask)
+    }
+}
+
+/// Compute the per-pixel temporal median of a sequence of frames.
+///
+/// For each pixel and channel the median value across all the frames is
+/// computed. With an even number of frames the two middle values are averaged
+/// and rounded to the nearest integer.
+///
+/// # Arguments
+///
+/// * `frames` - The frames with shape (H, W, 3). All frames must have the same size.
+///
+/// # Returns
+///
+/// The median image with shape (H, W, 3).
+///
+/// # Errors
+///
+/// Returns an error if `frames` is empty or the frames have different sizes.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::motion::temporal_median;
+///
+/// let size = ImageSize { width: 2, height: 2 };
+/// let frames = vec![
+///     Image::<u8, 3>::from_size_val(size, 10).unwrap(),
+///     Image::<u8, 3>::from_size_val(size, 200).unwrap(),
+///     Image::<u8, 3>::from_size_val(size, 10).unwrap(),
+/// ];
+///
+/// let median = temporal_median(&frames).unwrap();
+/// assert!(median.as_slice().iter().all(|&v| v == 10));
+/// ```
+pub fn temporal_median(frames: &[Image<u8, 3>]) -> Result<Image<u8, 3>, ImageError> {
+    let first = frames.first().ok_or(ImageError::ImageDataNotInitialized)?;
+
+    for frame in frames.iter() {
+        if frame.size() != first.size() {
+            return Err(ImageError::InvalidImageSize(
+                frame.cols(),
+                frame.rows(),
+                first.cols(),
+                first.rows(),
+            ));
+        }
+    }
+
+    let mut median = Image::from_size_val(first.size(), 0u8)?;
+
+    // reuse the buffer to gather the values of each pixel across frames
+    let mut values = Vec::with_capacity(frames.len());
+    let mid = frames.len() / 2;
+
+    median
+        .as_slice_mut()
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, dst)| {
+            values.clear();
+            values.extend(frames.iter().map(|frame| frame.as_slice()[i]));
+            values.sort_unstable();
+
+            *dst = if values.len() % 2 == 0 {
+                (values[mid - 1] as u16 + values[mid] as u16).div_ceil(2) as u8
+            } else {
+                values[mid]
+            };
+        });
+
+    Ok(median)
+}
+
+/// Compute the absolute difference between two consecutive grayscale frames.
+///
+/// # Arguments
+///
+/// * `prev` - The previous frame with shape (H, W, 1).
+/// * `curr` - The current frame with shape (H, W, 1).
+///
+/// # Returns
+///
+/// The per-pixel absolute difference with shape (H, W, 1).
+///
+/// # Errors
+///
+/// Returns an error if the frames have different sizes.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::motion::frame_difference;
+///
+/// let size = ImageSize { width: 2, height: 2 };
+/// let prev = Image::<u8, 1>::from_size_val(size, 30).unwrap();
+/// let curr = Image::<u8, 1>::from_size_val(size, 10).unwrap();
+///
+/// let diff = frame_difference(&prev, &curr).unwrap();
+/// assert!(diff.as_slice().iter().all(|&v| v == 20));
+/// ```
+pub fn frame_difference(
+    prev: &Image<u8, 1>,
+    curr: &Image<u8, 1>,
+) -> Result<Image<u8, 1>, ImageError> {
+    if prev.size() != curr.size() {
+        return Err(ImageError::InvalidImageSize(
+            curr.cols(),
+            curr.rows(),
+            prev.cols(),
+            prev.rows(),
+        ));
+    }
+
+    let data = prev
+        .as_slice()
+        .iter()
+        .zip(curr.as_slice().iter())
+        .map(|(&p, &c)| p.abs_diff(c))
+        .collect();
+
+    Image::new(curr.size(), data)
+}
+
+/// Compute the binary motion mask between two consecutive grayscale frames.
+///
+/// A pixel is flagged as moving (255) if its absolute difference between the
+/// frames is greater than `threshold`, otherwise it is set to 0.
+///
+/// # Arguments
+///
+/// * `prev` - The previous frame with shape (H, W, 1).
+/// * `curr` - The current frame with shape (H, W, 1).
+/// * `threshold` - The absolute difference above which a pixel is moving.
+///
+/// # Returns
+///
+/// The binary motion mask with shape (H, W, 1).
+///
+/// # Errors
+///
+/// Returns an error if the frames have different sizes.
+pub fn frame_difference_mask(
+    prev: &Image<u8, 1>,
+    curr: &Image<u8, 1>,
+    threshold: u8,
+) -> Result<Image<u8, 1>, ImageError> {
+    let mut mask = frame_difference(prev, curr)?;
+
+    mask.as_slice_mut()
+        .iter_mut()
+        .for_each(|v| *v = if *v > threshold { 255 } else { 0 });
+
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_background_model() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 4,
+        };
+        let background = Image::<u8, 3>::from_size_val(size, 50)?;
+
+        let mut model = super::BackgroundModel::new();
+        for _ in 0..5 {
+            model.update(&background, 0.5)?;
+        }
+
+        // place a bright 2x2 object at (x=3, y=1)
+        let mut frame = background.clone();
+        for y in 1..3 {
+            for x in 3..5 {
+                for c in 0..3 {
+                    frame.set_pixel(x, y, c, 200)?;
+                }
+            }
+        }
+
+        let mask = model.foreground_mask(&frame, 30.0)?;
+
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let inside = (1..3).contains(&y) && (3..5).contains(&x);
+                let expected = if inside { 255 } else { 0 };
+                assert_eq!(mask.get_pixel(x, y, 0)?, &expected);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_temporal_median() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 3,
+        };
+        let background = Image::<u8, 3>::from_size_val(size, 40)?;
+
+        // an object passing through a different pixel on each frame
+        let mut frames = Vec::new();
+        for i in 0..5 {
+            let mut frame = background.clone();
+            for c in 0..3 {
+                frame.set_pixel(i % 4, 1, c, 250)?;
+            }
+            frames.push(frame);
+        }
+
+        let median = super::temporal_median(&frames)?;
+        assert_eq!(median.as_slice(), background.as_slice());
+
+        // even number of frames averages the two middle values
+        let frames = vec![
+            Image::<u8, 3>::from_size_val(size, 10)?,
+            Image::<u8, 3>::from_size_val(size, 20)?,
+            Image::<u8, 3>::from_size_val(size, 31)?,
+            Image::<u8, 3>::from_size_val(size, 200)?,
+        ];
+        let median = super::temporal_median(&frames)?;
+        assert!(median.as_slice().iter().all(|&v| v == 26));
+
+        assert!(super::temporal_median(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_difference() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 4,
+        };
+
+        // a bright 2x2 object moving two pixels to the right
+        let mut prev = Image::<u8, 1>::from_size_val(size, 20)?;
+        let mut curr = prev.clone();
+        for y in 1..3 {
+            for x in 1..3 {
+                prev.set_pixel(x, y, 0, 220)?;
+                curr.set_pixel(x + 2, y, 0, 220)?;
+            }
+        }
+
+        let diff = super::frame_difference(&prev, &curr)?;
+        let mask = super::frame_difference_mask(&prev, &curr, 50)?;
+
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let moved = (1..3).contains(&y) && (1..5).contains(&x);
+                let (expected_diff, expected_mask) = if moved { (200, 255) } else { (0, 0) };
+                assert_eq!(diff.get_pixel(x, y, 0)?, &expected_diff);
+                assert_eq!(mask.get_pixel(x, y, 0)?, &expected_mask);
+            }
+        }
+
+        let other = Image::<u8, 1>::from_size_val(
+            ImageSize {
+                width: 4,
+                height: 4,
+            },
+            0,
+        )?;
+        assert!(super::frame_difference(&prev, &other).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+use kornia_image::{Image, ImageError, ImageSize};
+
+// create an image of the given size with every pixel set to `fill`
+fn filled_image<const C: usize>(
+    size: ImageSize,
+    fill: [u8; C],
+) -> Result<Image<u8, C>, ImageError> {
+    let data = fill
+        .iter()
+        .copied()
+        .cycle()
+        .take(size.width * size.height * C)
+        .collect();
+    Image::new(size, data)
+}
+
+// copy `src` into `dst` with its top-left corner at (x, y)
+fn paste<const C: usize>(src: &Image<u8, C>, dst: &mut Image<u8, C>, x: usize, y: usize) {
+    let src_row_len = src.cols() * C;
+    let dst_cols = dst.cols();
+    dst.as_slice_mut()
+        .chunks_exact_mut(dst_cols * C)
+        .skip(y)
+        .zip(src.as_slice().chunks_exact(src_row_len))
+        .for_each(|(dst_row, src_row)| {
+            dst_row[x * C..x * C + src_row_len].copy_from_slice(src_row);
+        });
+}
+
+/// Pad an image to the next power-of-two width and height.
+///
+/// The padding is added to the right and bottom of the image so that the
+/// original pixels stay in the top-left corner. Images whose dimensions are
+/// already powers of two are returned unchanged.
+///
+/// # Arguments
+///
+/// * `image` - The input image with shape (H, W, C).
+/// * `fill` - The pixel value used for the padded area.
+///
+/// # Returns
+///
+/// The padded image.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::pad::pad_to_power_of_two;
+///
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 5, height: 3 }, 1).unwrap();
+///
+/// let padded = pad_to_power_of_two(&image, [0, 0, 0]).unwrap();
+///
+/// assert_eq!(padded.size(), ImageSize { width: 8, height: 4 });
+/// ```
+pub fn pad_to_power_of_two<const C: usize>(
+    image: &Image<u8, C>,
+    fill: [u8; C],
+) -> Result<Image<u8, C>, ImageError> {
+    let padded_size = ImageSize {
+        width: image.cols().next_power_of_two(),
+        height: image.rows().next_power_of_two(),
+    };
+
+    if padded_size == image.size() {
+        return Ok(image.clone());
+    }
+
+    let mut padded = filled_image(padded_size, fill)?;
+    paste(image, &mut padded, 0, 0);
+
+    Ok(padded)
+}
+
+/// The strategy used to fit an image to an aspect ratio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AspectMode {
+    /// Add borders around the image, keeping all the pixels.
+    Pad,
+    /// Crop the center of the image, keeping the pixels at full size.
+    Crop,
+}
+
+/// Pad or crop an image to an exact aspect ratio without distorting it.
+///
+/// With [`AspectMode::Pad`] the image is centered on a canvas filled with `fill`
+/// that is just large enough to have the target aspect ratio. With
+/// [`AspectMode::Crop`] the largest centered region with the target aspect ratio
+/// is kept. When the ratio cannot be matched exactly in whole pixels, the
+/// closest size is used.
+///
+/// # Arguments
+///
+/// * `image` - The input image with shape (H, W, 3).
+/// * `ratio_w` - The width term of the aspect ratio.
+/// * `ratio_h` - The height term of the aspect ratio.
+/// * `mode` - Whether to pad or crop the image.
+/// * `fill` - The pixel value used for the padded area.
+///
+/// # Returns
+///
+/// The image with the target aspect ratio.
+///
+/// # Errors
+///
+/// Returns an error if any of the ratio terms is zero.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::pad::{fit_aspect_ratio, AspectMode};
+///
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 32, height: 18 }, 1).unwrap();
+///
+/// let padded = fit_aspect_ratio(&image, 1, 1, AspectMode::Pad, [0, 0, 0]).unwrap();
+/// assert_eq!(padded.size(), ImageSize { width: 32, height: 32 });
+///
+/// let cropped = fit_aspect_ratio(&image, 1, 1, AspectMode::Crop, [0, 0, 0]).unwrap();
+/// assert_eq!(cropped.size(), ImageSize { width: 18, height: 18 });
+/// ```
+pub fn fit_aspect_ratio(
+    image: &Image<u8, 3>,
+    ratio_w: usize,
+    ratio_h: usize,
+    mode: AspectMode,
+    fill: [u8; 3],
+) -> Result<Image<u8, 3>, ImageError> {
+    if ratio_w == 0 || ratio_h == 0 {
+        return Err(ImageError::InvalidAspectRatio(ratio_w, ratio_h));
+    }
+
+    let (cols, rows) = (image.cols(), image.rows());
+
+    // the image is wider than the target if cols / rows > ratio_w / ratio_h
+    let is_wider = cols * ratio_h > rows * ratio_w;
+    let round_div = |num: usize, den: usize| (num + den / 2) / den;
+
+    let size = match (mode, is_wider) {
+        (AspectMode::Pad, true) => ImageSize {
+            width: cols,
+            height: round_div(cols * ratio_h, ratio_w).max(rows),
+        },
+        (AspectMode::Pad, false) => ImageSize {
+            width: round_div(rows * ratio_w, ratio_h).max(cols),
+            height: rows,
+        },
+        (AspectMode::Crop, true) => ImageSize {
+            width: round_div(rows * ratio_w, ratio_h).min(cols),
+            height: rows,
+        },
+        (AspectMode::Crop, false) => ImageSize {
+            width: cols,
+            height: round_div(cols * ratio_h, ratio_w).min(rows),
+        },
+    };
+
+    match mode {
+        AspectMode::Pad => {
+            let mut padded = filled_image(size, fill)?;
+            paste(
+                image,
+                &mut padded,
+                (size.width - cols) / 2,
+                (size.height - rows) / 2,
+            );
+            Ok(padded)
+        }
+        AspectMode::Crop => image.crop(
+            (cols - size.width) / 2,
+            (rows - size.height) / 2,
+            size.width,
+            size.height,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_pad_to_power_of_two() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 258,
+            height: 195,
+        };
+        let data = (0..size.width * size.height * 3)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<_>>();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        let padded = super::pad_to_power_of_two(&image, [7, 8, 9])?;
+        assert_eq!(padded.width(), 512);
+        assert_eq!(padded.height(), 256);
+
+        // the original image is in the top-left corner
+        for y in 0..size.height {
+            let src_row = &image.as_slice()[y * size.width * 3..(y + 1) * size.width * 3];
+            let dst_row = &padded.as_slice()[y * 512 * 3..y * 512 * 3 + size.width * 3];
+            assert_eq!(src_row, dst_row);
+        }
+
+        // the padded area is filled
+        assert_eq!(padded.get_pixel(258, 0, 0)?, &7);
+        assert_eq!(padded.get_pixel(511, 255, 2)?, &9);
+        assert_eq!(padded.get_pixel(0, 195, 1)?, &8);
+
+        // power-of-two images pass through
+        let pot = Image::<u8, 1>::from_size_val(
+            ImageSize {
+                width: 16,
+                height: 8,
+            },
+            3,
+        )?;
+        let padded = super::pad_to_power_of_two(&pot, [0])?;
+        assert_eq!(padded.size(), pot.size());
+        assert_eq!(padded.as_slice(), pot.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fit_aspect_ratio() -> Result<(), ImageError> {
+        use super::AspectMode;
+
+        // a 16:9 image with a distinct value per column
+        let size = ImageSize {
+            width: 32,
+            height: 18,
+        };
+        let data = (0..size.width * size.height)
+            .flat_map(|i| [(i % size.width) as u8; 3])
+            .collect::<Vec<_>>();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        let padded = super::fit_aspect_ratio(&image, 1, 1, AspectMode::Pad, [200, 0, 0])?;
+        assert_eq!(padded.width(), padded.height());
+        assert_eq!(padded.width(), 32);
+
+        // the image is centered vertically between the borders
+        assert_eq!(padded.get_pixel(5, 0, 0)?, &200);
+        assert_eq!(padded.get_pixel(5, 6, 0)?, &200);
+        assert_eq!(padded.get_pixel(5, 7, 0)?, &5);
+        assert_eq!(padded.get_pixel(5, 24, 0)?, &5);
+        assert_eq!(padded.get_pixel(5, 25, 0)?, &200);
+
+        let cropped = super::fit_aspect_ratio(&image, 1, 1, AspectMode::Crop, [0, 0, 0])?;
+        assert_eq!(cropped.width(), cropped.height());
+        assert_eq!(cropped.width(), 18);
+
+        // the center columns are kept
+        assert_eq!(cropped.get_pixel(0, 0, 0)?, &7);
+        assert_eq!(cropped.get_pixel(17, 17, 0)?, &24);
+
+        // an image already at the target ratio is unchanged
+        let same = super::fit_aspect_ratio(&image, 16, 9, AspectMode::Crop, [0, 0, 0])?;
+        assert_eq!(same.as_slice(), image.as_slice());
+
+        assert!(super::fit_aspect_ratio(&image, 0, 1, AspectMode::Pad, [0, 0, 0]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+use kornia_image::{Image, ImageError, ImageSize};
+
+// the 5-tap binomial kernel approximating a gaussian, as in the Burt-Adelson pyramid
+const PYRAMID_KERNEL: [f32; 5] = [1.0 / 16.0, 4.0 / 16.0, 6.0 / 16.0, 4.0 / 16.0, 1.0 / 16.0];
+
+// a strided line of an image buffer as (start, stride, length)
+type Line = (usize, usize, usize);
+
+// resamples a source line into a destination line
+type LineFn = fn(&[f32], Line, &mut [f32], Line);
+
+// read the `i`-th element of a strided line, replicating the border elements
+fn line_at(data: &[f32], start: usize, stride: usize, len: usize, i: isize) -> f32 {
+    // an empty line has no border to replicate
+    if len == 0 {
+        return 0.0;
+    }
+    let i = i.clamp(0, len as isize - 1) as usize;
+    data[start + i * stride]
+}
+
+// blur with the pyramid kernel along one axis and keep every other element
+fn downsample_line(
+    src: &[f32],
+    (src_start, src_stride, src_len): Line,
+    dst: &mut [f32],
+    (dst_start, dst_stride, dst_len): Line,
+) {
+    for j in 0..dst_len {
+        let center = 2 * j as isize;
+        dst[dst_start + j * dst_stride] = PYRAMID_KERNEL
+            .iter()
+            .enumerate()
+            .map(|(k, w)| w * line_at(src, src_start, src_stride, src_len, center + k as isize - 2))
+            .sum();
+    }
+}
+
+// upsample by two along one axis interpolating with the pyramid kernel
+fn upsample_line(
+    src: &[f32],
+    (src_start, src_stride, src_len): Line,
+    dst: &mut [f32],
+    (dst_start, dst_stride, dst_len): Line,
+) {
+    let at = |i: isize| line_at(src, src_start, src_stride, src_len, i);
+    for j in 0..dst_len {
+        let i = (j / 2) as isize;
+        dst[dst_start + j * dst_stride] = if j % 2 == 0 {
+            (at(i - 1) + 6.0 * at(i) + at(i + 1)) / 8.0
+        } else {
+            (at(i) + at(i + 1)) / 2.0
+        };
+    }
+}
+
+// resample a single channel image applying `f` first along the rows and then along the columns
+fn resample_separable(
+    src: &Image<f32, 1>,
+    dst_size: ImageSize,
+    f: LineFn,
+) -> Result<Image<f32, 1>, ImageError> {
+    let (src_cols, src_rows) = (src.cols(), src.rows());
+    let (dst_cols, dst_rows) = (dst_size.width, dst_size.height);
+
+    // resample the rows into a (src_rows, dst_cols) buffer
+    let mut tmp = vec![0.0; src_rows * dst_cols];
+    for r in 0..src_rows {
+        f(
+            src.as_slice(),
+            (r * src_cols, 1, src_cols),
+            &mut tmp,
+            (r * dst_cols, 1, dst_cols),
+        );
+    }
+
+    // resample the columns into the output image
+    let mut dst = Image::from_size_val(dst_size, 0.0)?;
+    for c in 0..dst_cols {
+        f(
+            &tmp,
+            (c, dst_cols, src_rows),
+            dst.as_slice_mut(),
+            (c, dst_cols, dst_rows),
+        );
+    }
+
+    Ok(dst)
+}
+
+// blur and downsample an image by two
+fn pyr_down(src: &Image<f32, 1>) -> Result<Image<f32, 1>, ImageError> {
+    let dst_size = ImageSize {
+        width: src.cols().div_ceil(2),
+        height: src.rows().div_ceil(2),
+    };
+    resample_separable(src, dst_size, downsample_line)
+}
+
+// upsample an image by two and crop it to the given size
+fn pyr_up(src: &Image<f32, 1>, dst_size: ImageSize) -> Result<Image<f32, 1>, ImageError> {
+    resample_separable(src, dst_size, upsample_line)
+}
+
+/// Compute the Laplacian pyramid of a grayscale image.
+///
+/// Each level is the difference between a level of the Gaussian pyramid and the
+/// upsampled next level, storing the details lost by the downsampling. The last
+/// level is the low-pass residual, i.e. the smallest level of the Gaussian pyramid.
+///
+/// The pyramid stops early if the image cannot be downsampled further.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image with shape (H, W, 1).
+/// * `levels` - The number of levels of the pyramid, including the residual.
+///
+/// # Returns
+///
+/// The levels of the pyramid from the finest to the coarsest. If `levels` is 0,
+/// an empty pyramid is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::pyramid::{laplacian_pyramid, reconstruct_laplacian};
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 16, height: 8 }, 42).unwrap();
+///
+/// let pyramid = laplacian_pyramid(&image, 3).unwrap();
+/// assert_eq!(pyramid.len(), 3);
+/// assert_eq!(pyramid[2].size(), ImageSize { width: 4, height: 2 });
+///
+/// let reconstructed = reconstruct_laplacian(&pyramid).unwrap();
+/// assert_eq!(reconstructed.as_slice(), image.as_slice());
+/// ```
+pub fn laplacian_pyramid(
+    image: &Image<u8, 1>,
+    levels: usize,
+) -> Result<Vec<Image<f32, 1>>, ImageError> {
+    let mut pyramid = Vec::with_capacity(levels);
+    if levels == 0 {
+        return Ok(pyramid);
+    }
+
+    let mut current = image.cast::<f32>()?;
+
+    for _ in 1..levels {
+        if current.cols() <= 1 && current.rows() <= 1 {
+            break;
+        }
+
+        let down = pyr_down(&current)?;
+        let up = pyr_up(&down, current.size())?;
+
+        // the details are the difference with the upsampled coarser level
+        current
+            .as_slice_mut()
+            .iter_mut()
+            .zip(up.as_slice().iter())
+            .for_each(|(c, &u)| *c -= u);
+
+        pyramid.push(std::mem::replace(&mut current, down));
+    }
+
+    pyramid.push(current);
+
+    Ok(pyramid)
+}
+
+/// Reconstruct a grayscale image from its Laplacian pyramid.
+///
+/// The residual is upsampled and the details of each level are added back from
+/// the coarsest to the finest level. The result is rounded and clamped to [0, 255].
+///
+/// # Arguments
+///
+/// * `pyramid` - The levels of the pyramid as returned by [`laplacian_pyramid`].
+///
+/// # Returns
+///
+/// The reconstructed image with the size of the first level.
+///
+/// # Errors
+///
+/// Returns an error if the pyramid is empty.
+pub fn reconstruct_laplacian(pyramid: &[Image<f32, 1>]) -> Result<Image<u8, 1>, ImageError> {
+    let (residual, details) = pyramid
+        .split_last()
+        .ok_or(ImageError::ImageDataNotInitialized)?;
+
+    let mut current = residual.clone();
+
+    for level in details.iter().rev() {
+        let mut up = pyr_up(&current, level.size())?;
+        up.as_slice_mut()
+            .iter_mut()
+            .zip(level.as_slice().iter())
+            .for_each(|(u, &l)| *u += l);
+        current = up;
+    }
+
+    let data = current
+        .as_slice()
+        .iter()
+        .map(|&v| v.round().clamp(0.0, 255.0) as u8)
+        .collect();
+
+    Image::new(current.size(), data)
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_line_at() {
+        let data = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(super::line_at(&data, 1, 2, 2, -1), 2.0);
+        assert_eq!(super::line_at(&data, 1, 2, 2, 5), 4.0);
+        assert_eq!(super::line_at(&data, 0, 1, 0, 0), 0.0);
+    }
+
+    #[test]
+    fn test_laplacian_pyramid() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 37,
+            height: 23,
+        };
+
+        // a diagonal gradient with a bright square
+        let data = (0..size.width * size.height)
+            .map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                let inside = (10..20).contains(&x) && (5..15).contains(&y);
+                if inside {
+                    250
+                } else {
+                    (x * 3 + y * 2) as u8
+                }
+            })
+            .collect();
+        let image = Image::<u8, 1>::new(size, data)?;
+
+        let pyramid = super::laplacian_pyramid(&image, 4)?;
+        assert_eq!(pyramid.len(), 4);
+
+        let sizes = pyramid.iter().map(|l| l.size()).collect::<Vec<_>>();
+        assert_eq!(
+            sizes,
+            vec![
+                ImageSize {
+                    width: 37,
+                    height: 23
+                },
+                ImageSize {
+                    width: 19,
+                    height: 12
+                },
+                ImageSize {
+                    width: 10,
+                    height: 6
+                },
+                ImageSize {
+                    width: 5,
+                    height: 3
+                },
+            ]
+        );
+
+        let reconstructed = super::reconstruct_laplacian(&pyramid)?;
+        assert_eq!(reconstructed.size(), size);
+
+        let max_error = reconstructed
+            .as_slice()
+            .iter()
+            .zip(image.as_slice().iter())
+            .map(|(&a, &b)| a.abs_diff(b))
+            .max();
+        assert!(max_error <= Some(1), "max error: {max_error:?}");
+
+        assert!(super::reconstruct_laplacian(&[]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use kornia_image::Image;
+
+use crate::gradient::sobel_at;
+
+// average `data` over a (2 * radius + 1) square window clamped to the image bounds
+fn box_mean(data: &[f32], cols: usize, rows: usize, radius: usize) -> Vec<f32> {
+    // integral image with a leading row and column of zeros
+    let stride = cols + 1;
+    let mut integral = vec![0.0f64; stride * (rows + 1)];
+    for y in 0..rows {
+        let mut row_sum = 0.0;
+        for x in 0..cols {
+            row_sum += data[y * cols + x] as f64;
+            integral[(y + 1) * stride + x + 1] = integral[y * stride + x + 1] + row_sum;
+        }
+    }
+
+    let mut mean = vec![0.0f32; cols * rows];
+    for y in 0..rows {
+        let (y0, y1) = (y.saturating_sub(radius), (y + radius + 1).min(rows));
+        for x in 0..cols {
+            let (x0, x1) = (x.saturating_sub(radius), (x + radius + 1).min(cols));
+            let sum = integral[y1 * stride + x1]
+                - integral[y0 * stride + x1]
+                - integral[y1 * stride + x0]
+                + integral[y0 * stride + x0];
+            mean[y * cols + x] = (sum / ((x1 - x0) * (y1 - y0)) as f64) as f32;
+        }
+    }
+
+    mean
+}
+
+/// Estimate the bounding box of the primary subject of a grayscale image.
+///
+/// The saliency of each pixel is its gradient energy, i.e. the Sobel gradient
+/// magnitude averaged over a window of about 1/16 of the smallest image side,
+/// so that textured and detailed regions stand out from flat backgrounds. The
+/// subject is the bounding box of the pixels whose saliency is above
+/// `mean + 0.25 * (max - mean)`.
+///
+/// # Arguments
+///
+/// * `image` - The input grayscale image with shape (H, W, 1).
+///
+/// # Returns
+///
+/// The subject box as `(x, y, width, height)`. If the image has no salient
+/// region, e.g. it is flat, the box covers the whole image.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::saliency::estimate_subject_box;
+///
+/// let image = Image::<u8, 1>::from_size_val(ImageSize { width: 8, height: 4 }, 100).unwrap();
+///
+/// assert_eq!(estimate_subject_box(&image), (0, 0, 8, 4));
+/// ```
+pub fn estimate_subject_box(image: &Image<u8, 1>) -> (usize, usize, usize, usize) {
+    let (cols, rows) = (image.cols(), image.rows());
+    let full = (0, 0, cols, rows);
+    if cols == 0 || rows == 0 {
+        return full;
+    }
+
+    let data = image.as_slice();
+    let energy = (0..rows)
+        .flat_map(|y| (0..cols).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let (gx, gy) = sobel_at(data, cols, rows, x, y);
+            (gx * gx + gy * gy).sqrt()
+        })
+        .collect::<Vec<_>>();
+
+    let saliency = box_mean(&energy, cols, rows, (cols.min(rows) / 32).max(1));
+
+    let max = saliency.iter().copied().fold(0.0f32, f32::max);
+    let mean = saliency.iter().sum::<f32>() / saliency.len() as f32;
+    if max <= mean {
+        return full;
+    }
+    let threshold = mean + 0.25 * (max - mean);
+
+    let (mut x0, mut y0, mut x1, mut y1) = (cols, rows, 0, 0);
+    for (i, _) in saliency.iter().enumerate().filter(|(_, &s)| s > threshold) {
+        let (x, y) = (i % cols, i / cols);
+        x0 = x0.min(x);
+        y0 = y0.min(y);
+        x1 = x1.max(x + 1);
+        y1 = y1.max(y + 1);
+    }
+
+    if x0 >= x1 || y0 >= y1 {
+        return full;
+    }
+
+    (x0, y0, x1 - x0, y1 - y0)
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_estimate_subject_box() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 96,
+            height: 64,
+        };
+
+        // a checkerboard object on a flat background
+        let (ox, oy, ow, oh) = (50, 12, 30, 24);
+        let data = (0..size.width * size.height)
+            .map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                let inside = (ox..ox + ow).contains(&x) && (oy..oy + oh).contains(&y);
+                match (inside, (x / 3 + y / 3) % 2) {
+                    (true, 0) => 230,
+                    (true, _) => 20,
+                    (false, _) => 120,
+                }
+            })
+            .collect();
+        let image = Image::<u8, 1>::new(size, data)?;
+
+        let (x, y, w, h) = super::estimate_subject_box(&image);
+
+        // the box contains the object with a small margin
+        let margin = 4;
+        assert!(x <= ox && x + margin >= ox, "x: {x}");
+        assert!(y <= oy && y + margin >= oy, "y: {y}");
+        assert!(x + w >= ox + ow && x + w <= ox + ow + margin, "w: {w}");
+        assert!(y + h >= oy + oh && y + h <= oy + oh + margin, "h: {h}");
+
+        let flat = Image::<u8, 1>::from_size_val(size, 7)?;
+        assert_eq!(super::estimate_subject_box(&flat), (0, 0, 96, 64));
+
+        Ok(())
+    }
+}
@@ -367,6 +367,75 @@ where
 
 // TODO: outsu, triangle
 
+/// Segment the pixels of an image close to a target color.
+///
+/// A pixel is marked as foreground (255) if its Euclidean distance to the target
+/// color in RGB space is less than or equal to `tolerance`, otherwise it is set
+/// to background (0).
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image with shape (H, W, 3).
+/// * `dst` - The output mask with shape (H, W, 1).
+/// * `target` - The target color in RGB.
+/// * `tolerance` - The maximum Euclidean distance to the target color.
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::threshold::segment_by_color_threshold;
+///
+/// let image = Image::<u8, 3>::new(
+///    ImageSize {
+///       width: 2,
+///       height: 1,
+///    },
+///    vec![200, 10, 10, 10, 10, 200],
+/// )
+/// .unwrap();
+///
+/// let mut mask = Image::<u8, 1>::from_size_val(image.size(), 0).unwrap();
+///
+/// segment_by_color_threshold(&image, &mut mask, [210, 0, 0], 20).unwrap();
+///
+/// assert_eq!(mask.get_pixel(0, 0, 0).unwrap(), &255);
+/// assert_eq!(mask.get_pixel(1, 0, 0).unwrap(), &0);
+/// ```
+pub fn segment_by_color_threshold(
+    src: &Image<u8, 3>,
+    dst: &mut Image<u8, 1>,
+    target: [u8; 3],
+    tolerance: u8,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    // compare squared distances to avoid the square root per pixel
+    let max_dist_sq = tolerance as u32 * tolerance as u32;
+
+    // parallelize the operation by rows
+    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+        let dist_sq = src_pixel
+            .iter()
+            .zip(target.iter())
+            .map(|(&p, &t)| {
+                let diff = p.abs_diff(t) as u32;
+                diff * diff
+            })
+            .sum::<u32>();
+        dst_pixel[0] = if dist_sq <= max_dist_sq { 255 } else { 0 };
+    });
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
@@ -520,4 +589,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_segment_by_color_threshold() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 5,
+        };
+
+        // gray background with a slightly noisy red 2x3 object at (x=2, y=1)
+        let mut image = Image::<u8, 3>::from_size_val(size, 120)?;
+        for y in 1..4 {
+            for x in 2..4 {
+                let noise = (x + y) as u8;
+                image.set_pixel(x, y, 0, 220 - noise)?;
+                image.set_pixel(x, y, 1, 30 + noise)?;
+                image.set_pixel(x, y, 2, 25)?;
+            }
+        }
+
+        let mut mask = Image::<u8, 1>::from_size_val(size, 0)?;
+        super::segment_by_color_threshold(&image, &mut mask, [220, 30, 30], 15)?;
+
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let inside = (1..4).contains(&y) && (2..4).contains(&x);
+                let expected = if inside { 255 } else { 0 };
+                assert_eq!(mask.get_pixel(x, y, 0)?, &expected);
+            }
+        }
+
+        Ok(())
+    }
 }
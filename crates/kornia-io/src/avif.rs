@@ -0,0 +1,445 @@
+use std::path::Path;
+
+use kornia_image::{Image, ImageSize};
+use re_rav1d::{
+    pixel::{MatrixCoefficients, YUVRange},
+    Decoder, Error as Dav1dError, Picture, PixelLayout, PlanarImageComponent, Settings,
+};
+
+use crate::error::IoError;
+
+// the box types of the HEIF/AVIF container (ISO/IEC 23008-12) used to locate the primary item
+const BOX_FTYP: &[u8; 4] = b"ftyp";
+const BOX_META: &[u8; 4] = b"meta";
+const BOX_PITM: &[u8; 4] = b"pitm";
+const BOX_IINF: &[u8; 4] = b"iinf";
+const BOX_INFE: &[u8; 4] = b"infe";
+const BOX_ILOC: &[u8; 4] = b"iloc";
+const BOX_IDAT: &[u8; 4] = b"idat";
+
+// the item type of an AV1 coded image
+const ITEM_TYPE_AV01: &[u8; 4] = b"av01";
+
+// the brands identifying an AVIF file
+const AVIF_BRANDS: [&[u8; 4]; 2] = [b"avif", b"avis"];
+
+/// Read an AVIF image with three channels (rgb8).
+///
+/// The primary image of the file is decoded with a pure-Rust AV1 decoder and
+/// converted from YUV to RGB. Sources with 10 or 12 bits per component are
+/// scaled down to 8 bits, monochrome sources are replicated to the three
+/// channels and the alpha plane, if any, is ignored.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the AVIF file.
+///
+/// # Returns
+///
+/// A RGB image with three channels (rgb8).
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, is not an AVIF file or the
+/// AV1 payload cannot be decoded.
+pub fn read_image_avif_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
+    let file_path = file_path.as_ref().to_owned();
+
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    if file_path
+        .extension()
+        .map_or(true, |ext| !ext.eq_ignore_ascii_case("avif"))
+    {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+
+    let data = std::fs::read(&file_path)?;
+    let payload = primary_item_payload(&data)?;
+    let picture = decode_av1(payload)?;
+
+    picture_to_rgb8(&picture)
+}
+
+// a box of the container as (type, payload)
+type IsoBox<'a> = (&'a [u8; 4], &'a [u8]);
+
+// read a big-endian unsigned integer of `size` bytes at `offset`
+fn read_uint(data: &[u8], offset: usize, size: usize) -> Result<u64, IoError> {
+    let bytes = data
+        .get(offset..offset + size)
+        .ok_or_else(|| IoError::CorruptImageData("truncated AVIF box".to_string()))?;
+    Ok(bytes.iter().fold(0, |acc, &b| (acc << 8) | b as u64))
+}
+
+// split a buffer into its sequence of boxes
+fn parse_boxes(mut data: &[u8]) -> Result<Vec<IsoBox<'_>>, IoError> {
+    let mut boxes = Vec::new();
+    while data.len() >= 8 {
+        let size = read_uint(data, 0, 4)?;
+        let box_type: &[u8; 4] = data[4..8].try_into().expect("slice of length 4");
+
+        let (header_len, box_len) = match size {
+            0 => (8, data.len() as u64),
+            1 => (16, read_uint(data, 8, 8)?),
+            _ => (8, size),
+        };
+
+        let box_len = usize::try_from(box_len)
+            .ok()
+            .filter(|&len| len >= header_len && len <= data.len())
+            .ok_or_else(|| IoError::CorruptImageData("invalid AVIF box size".to_string()))?;
+
+        boxes.push((box_type, &data[header_len..box_len]));
+        data = &data[box_len..];
+    }
+    Ok(boxes)
+}
+
+// find the first box of the given type
+fn find_box<'a>(boxes: &[IsoBox<'a>], box_type: &[u8; 4]) -> Option<&'a [u8]> {
+    boxes
+        .iter()
+        .find(|(t, _)| *t == box_type)
+        .map(|(_, payload)| *payload)
+}
+
+// locate and concatenate the coded data of the primary item
+fn primary_item_payload(data: &[u8]) -> Result<Vec<u8>, IoError> {
+    let corrupt = |msg: &str| IoError::CorruptImageData(msg.to_string());
+
+    let boxes = parse_boxes(data)?;
+
+    let ftyp = find_box(&boxes, BOX_FTYP).ok_or_else(|| corrupt("missing ftyp box"))?;
+    let is_avif = ftyp.chunks_exact(4).enumerate().any(|(i, brand)| {
+        // skip the minor version following the major brand
+        i != 1 && AVIF_BRANDS.iter().any(|b| brand == *b)
+    });
+    if !is_avif {
+        return Err(corrupt("not an AVIF file"));
+    }
+
+    // the meta box is a full box with a version and flags before its children
+    let meta = find_box(&boxes, BOX_META).ok_or_else(|| corrupt("missing meta box"))?;
+    let meta_boxes = parse_boxes(meta.get(4..).ok_or_else(|| corrupt("truncated meta box"))?)?;
+
+    let pitm = find_box(&meta_boxes, BOX_PITM).ok_or_else(|| corrupt("missing pitm box"))?;
+    let primary_id = match pitm.first() {
+        Some(0) => read_uint(pitm, 4, 2)?,
+        _ => read_uint(pitm, 4, 4)?,
+    };
+
+    if item_type(&meta_boxes, primary_id)? != Some(ITEM_TYPE_AV01) {
+        return Err(corrupt("the primary item is not an AV1 image"));
+    }
+
+    let iloc = find_box(&meta_boxes, BOX_ILOC).ok_or_else(|| corrupt("missing iloc box"))?;
+    let idat = find_box(&meta_boxes, BOX_IDAT).unwrap_or_default();
+
+    item_data(iloc, primary_id, data, idat)
+}
+
+// the type of an item from the item information box
+fn item_type<'a>(meta_boxes: &[IsoBox<'a>], item_id: u64) -> Result<Option<&'a [u8; 4]>, IoError> {
+    let Some(iinf) = find_box(meta_boxes, BOX_IINF) else {
+        return Ok(None);
+    };
+
+    let entries_offset = if iinf.first() == Some(&0) { 6 } else { 8 };
+    let entries = parse_boxes(iinf.get(entries_offset..).unwrap_or_default())?;
+
+    for (box_type, infe) in entries {
+        if box_type != BOX_INFE {
+            continue;
+        }
+
+        // only the versions 2 and 3 of the item info entry carry an item type
+        let (id, type_offset) = match infe.first() {
+            Some(2) => (read_uint(infe, 4, 2)?, 8),
+            Some(3) => (read_uint(infe, 4, 4)?, 10),
+            _ => continue,
+        };
+
+        if id == item_id {
+            let item_type = infe
+                .get(type_offset..type_offset + 4)
+                .and_then(|t| t.try_into().ok());
+            return Ok(item_type);
+        }
+    }
+
+    Ok(None)
+}
+
+// extract the data of an item from the item location box
+fn item_data(iloc: &[u8], item_id: u64, file: &[u8], idat: &[u8]) -> Result<Vec<u8>, IoError> {
+    let corrupt = |msg: &str| IoError::CorruptImageData(msg.to_string());
+
+    let version = *iloc.first().ok_or_else(|| corrupt("truncated iloc box"))?;
+    let sizes = read_uint(iloc, 4, 2)? as usize;
+    let offset_size = sizes >> 12;
+    let length_size = (sizes >> 8) & 0xf;
+    let base_offset_size = (sizes >> 4) & 0xf;
+    let index_size = if version > 0 { sizes & 0xf } else { 0 };
+
+    let id_size = if version < 2 { 2 } else { 4 };
+    let mut pos = 6;
+    let item_count = read_uint(iloc, pos, id_size)?;
+    pos += id_size;
+
+    for _ in 0..item_count {
+        let id = read_uint(iloc, pos, id_size)?;
+        pos += id_size;
+
+        let construction_method = if version > 0 {
+            pos += 2;
+            read_uint(iloc, pos - 2, 2)? & 0xf
+        } else {
+            0
+        };
+
+        // skip the data reference index
+        pos += 2;
+        let base_offset = read_uint(iloc, pos, base_offset_size)?;
+        pos += base_offset_size;
+        let extent_count = read_uint(iloc, pos, 2)?;
+        pos += 2;
+
+        let source = match construction_method {
+            0 => file,
+            1 => idat,
+            _ => return Err(corrupt("unsupported iloc construction method")),
+        };
+
+        let mut payload = Vec::new();
+        for _ in 0..extent_count {
+            pos += index_size;
+            let offset = base_offset
+                .checked_add(read_uint(iloc, pos, offset_size)?)
+                .ok_or_else(|| corrupt("AVIF item extent out of bounds"))?;
+            pos += offset_size;
+            let length = read_uint(iloc, pos, length_size)?;
+            pos += length_size;
+
+            if id != item_id {
+                continue;
+            }
+
+            // a zero length extent spans until the end of the source
+            let start = offset as usize;
+            let end = match length {
+                0 => source.len(),
+                _ => start.saturating_add(length as usize),
+            };
+            let extent = source
+                .get(start..end)
+                .ok_or_else(|| corrupt("AVIF item extent out of bounds"))?;
+            payload.extend_from_slice(extent);
+        }
+
+        if id == item_id {
+            return Ok(payload);
+        }
+    }
+
+    Err(corrupt("missing location of the primary item"))
+}
+
+// decode a single AV1 frame
+fn decode_av1(payload: Vec<u8>) -> Result<Picture, IoError> {
+    let decode_error = |e: Dav1dError| IoError::AvifDecodeError(e.to_string());
+
+    // decode on a single thread without frame delay, the image is a single frame
+    let mut settings = Settings::new();
+    settings.set_n_threads(1);
+    settings.set_max_frame_delay(1);
+
+    let mut decoder = Decoder::with_settings(&settings).map_err(decode_error)?;
+
+    let mut result = decoder.send_data(payload, None, None, None);
+    loop {
+        match result {
+            Ok(()) => break,
+            Err(Dav1dError::Again) => {
+                if let Ok(picture) = decoder.get_picture() {
+                    return Ok(picture);
+                }
+                result = decoder.send_pending_data();
+            }
+            Err(e) => return Err(decode_error(e)),
+        }
+    }
+
+    decoder.get_picture().map_err(decode_error)
+}
+
+// read the sample at (x, y) of a plane scaled down to 8 bits
+fn sample_u8(plane: &[u8], stride: usize, x: usize, y: usize, bits: usize) -> u8 {
+    if bits == 8 {
+        return plane[y * stride + x];
+    }
+
+    // high bit depth samples are stored as little-endian u16
+    let i = y * stride + 2 * x;
+    let v = u16::from_le_bytes([plane[i], plane[i + 1]]) as u32;
+    let shift = bits - 8;
+    ((v + (1 << (shift - 1))) >> shift).min(255) as u8
+}
+
+// convert a decoded YUV picture to rgb8
+fn picture_to_rgb8(picture: &Picture) -> Result<Image<u8, 3>, IoError> {
+    let width = picture.width() as usize;
+    let height = picture.height() as usize;
+    let bits = picture.bits_per_component().map_or(8, |b| b.0);
+
+    let layout = picture.pixel_layout();
+    let (shift_x, shift_y) = match layout {
+        PixelLayout::I420 => (1, 1),
+        PixelLayout::I422 => (1, 0),
+        PixelLayout::I400 | PixelLayout::I444 => (0, 0),
+    };
+
+    // the luma and chroma weights, BT.601 unless the stream signals otherwise
+    let (kr, kb) = match picture.matrix_coefficients() {
+        MatrixCoefficients::BT709 => (0.2126, 0.0722),
+        MatrixCoefficients::BT2020NonConstantLuminance
+        | MatrixCoefficients::BT2020ConstantLuminance => (0.2627, 0.0593),
+        _ => (0.299, 0.114),
+    };
+    let kg = 1.0 - kr - kb;
+
+    let (y_offset, y_scale, c_scale) = match picture.color_range() {
+        YUVRange::Full => (0.0, 1.0, 1.0),
+        YUVRange::Limited => (16.0, 255.0 / 219.0, 255.0 / 224.0),
+    };
+
+    let y_plane = picture.plane(PlanarImageComponent::Y);
+    let y_stride = picture.stride(PlanarImageComponent::Y) as usize;
+
+    let mut data = Vec::with_capacity(width * height * 3);
+
+    if layout == PixelLayout::I400 {
+        for y in 0..height {
+            for x in 0..width {
+                let luma = sample_u8(&y_plane, y_stride, x, y, bits) as f32;
+                let v = ((luma - y_offset) * y_scale).round().clamp(0.0, 255.0) as u8;
+                data.extend_from_slice(&[v, v, v]);
+            }
+        }
+    } else {
+        let u_plane = picture.plane(PlanarImageComponent::U);
+        let v_plane = picture.plane(PlanarImageComponent::V);
+        let c_stride = picture.stride(PlanarImageComponent::U) as usize;
+
+        for y in 0..height {
+            for x in 0..width {
+                let (cx, cy) = (x >> shift_x, y >> shift_y);
+                let luma = (sample_u8(&y_plane, y_stride, x, y, bits) as f32 - y_offset) * y_scale;
+                let cb = (sample_u8(&u_plane, c_stride, cx, cy, bits) as f32 - 128.0) * c_scale;
+                let cr = (sample_u8(&v_plane, c_stride, cx, cy, bits) as f32 - 128.0) * c_scale;
+
+                let r = luma + 2.0 * (1.0 - kr) * cr;
+                let b = luma + 2.0 * (1.0 - kb) * cb;
+                let g = (luma - kr * r - kb * b) / kg;
+
+                data.extend([r, g, b].iter().map(|c| c.round().clamp(0.0, 255.0) as u8));
+            }
+        }
+    }
+
+    Ok(Image::new(ImageSize { width, height }, data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use image::{codecs::avif::AvifEncoder, ExtendedColorType, ImageEncoder};
+    use std::fs::File;
+    use tempfile::tempdir;
+
+    #[test]
+    fn read_avif() -> Result<(), IoError> {
+        let (width, height) = (33u32, 17u32);
+
+        // a horizontal red ramp over a constant blue background
+        let data = (0..width * height)
+            .flat_map(|i| [((i % width) * 7) as u8, 64, 200])
+            .collect::<Vec<_>>();
+
+        let tmp_dir = tempdir()?;
+        let file_path = tmp_dir.path().join("sample.avif");
+
+        AvifEncoder::new_with_speed_quality(File::create(&file_path)?, 10, 90).write_image(
+            &data,
+            width,
+            height,
+            ExtendedColorType::Rgb8,
+        )?;
+
+        let image = super::read_image_avif_rgb8(&file_path)?;
+        assert_eq!(image.cols(), width as usize);
+        assert_eq!(image.rows(), height as usize);
+        assert_eq!(image.num_channels(), 3);
+
+        // the lossy encoding keeps the colors close to the source
+        let max_error = image
+            .as_slice()
+            .iter()
+            .zip(data.iter())
+            .map(|(&a, &b)| a.abs_diff(b))
+            .max();
+        assert!(max_error < Some(48), "max error: {max_error:?}");
+
+        assert!(super::read_image_avif_rgb8(tmp_dir.path().join("missing.avif")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_avif_10bit() -> Result<(), IoError> {
+        let (width, height) = (33usize, 17usize);
+
+        // the fixture is a 10-bit encoding of a vertical green ramp over a constant red background
+        let pixels = (0..width * height)
+            .map(|i| [180u8, ((i / width) * 15) as u8, 40])
+            .collect::<Vec<_>>();
+
+        let file_path = "../../tests/data/ramp_10bit.avif";
+        let image = super::read_image_avif_rgb8(file_path)?;
+        assert_eq!(image.cols(), width);
+        assert_eq!(image.rows(), height);
+
+        // the 10-bit samples are scaled down to 8 bits
+        let max_error = image
+            .as_slice()
+            .chunks_exact(3)
+            .zip(pixels.iter())
+            .flat_map(|(a, b)| a.iter().zip(b).map(|(&x, &y)| x.abs_diff(y)))
+            .max();
+        assert!(max_error < Some(48), "max error: {max_error:?}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn item_data_offset_overflow() {
+        // a version 0 iloc box whose base offset plus extent offset overflows u64
+        let iloc = [
+            0, 0, 0, 0, // version and flags
+            0x84, 0x80, // offset, length and base offset sizes
+            0, 1, // item count
+            0, 1, // item id
+            0, 0, // data reference index
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // base offset
+            0, 1, // extent count
+            0, 0, 0, 0, 0, 0, 0, 1, // extent offset
+            0, 0, 0, 1, // extent length
+        ];
+        assert!(matches!(
+            super::item_data(&iloc, 1, &[], &[]),
+            Err(IoError::CorruptImageData(_))
+        ));
+    }
+}
@@ -0,0 +1,319 @@
+use std::f32::consts::PI;
+
+use kornia_image::{Image, ImageSize};
+
+use crate::error::IoError;
+
+// the BlurHash specification: https://github.com/woltapp/blurhash/blob/master/Algorithm.md
+const BASE83_CHARS: &[u8; 83] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+// the valid range of the number of components along each axis
+const MIN_COMPONENTS: u32 = 1;
+const MAX_COMPONENTS: u32 = 9;
+
+// append `value` to `hash` as `length` base 83 digits
+fn encode_base83(value: u32, length: u32, hash: &mut String) {
+    for i in 1..=length {
+        let digit = (value / 83u32.pow(length - i)) % 83;
+        hash.push(BASE83_CHARS[digit as usize] as char);
+    }
+}
+
+// parse a string of base 83 digits
+fn decode_base83(digits: &str) -> Result<u32, IoError> {
+    digits.bytes().try_fold(0u32, |value, c| {
+        let digit = BASE83_CHARS
+            .iter()
+            .position(|&b| b == c)
+            .ok_or_else(|| IoError::BlurHashError(format!("invalid character {:?}", c as char)))?;
+        Ok(value * 83 + digit as u32)
+    })
+}
+
+fn srgb_to_linear(value: u8) -> f32 {
+    let v = value as f32 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let srgb = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (srgb * 255.0).round() as u8
+}
+
+fn sign_pow(value: f32, exp: f32) -> f32 {
+    value.abs().powf(exp).copysign(value)
+}
+
+/// Encode an image into a BlurHash string.
+///
+/// BlurHash is a compact representation of a placeholder for an image, made
+/// of the first `components_x` x `components_y` terms of the cosine transform
+/// of the image in linear RGB.
+///
+/// # Arguments
+///
+/// * `image` - The RGB image with shape (H, W, 3).
+/// * `components_x` - The number of horizontal components in the range [1, 9].
+/// * `components_y` - The number of vertical components in the range [1, 9].
+///
+/// # Returns
+///
+/// The BlurHash string with `4 + 2 * components_x * components_y` characters.
+///
+/// # Errors
+///
+/// Returns an error if the number of components is out of range or the image is empty.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::blurhash::blurhash_encode;
+///
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 8, height: 6 }, 128).unwrap();
+///
+/// let hash = blurhash_encode(&image, 4, 3).unwrap();
+/// assert_eq!(hash.len(), 4 + 2 * 4 * 3);
+/// ```
+pub fn blurhash_encode(
+    image: &Image<u8, 3>,
+    components_x: u32,
+    components_y: u32,
+) -> Result<String, IoError> {
+    let valid = MIN_COMPONENTS..=MAX_COMPONENTS;
+    if !valid.contains(&components_x) || !valid.contains(&components_y) {
+        return Err(IoError::BlurHashError(format!(
+            "invalid number of components {components_x}x{components_y}"
+        )));
+    }
+
+    let (width, height) = (image.cols(), image.rows());
+    if width == 0 || height == 0 {
+        return Err(IoError::BlurHashError("empty image".to_string()));
+    }
+
+    let linear = image
+        .as_slice()
+        .iter()
+        .map(|&v| srgb_to_linear(v))
+        .collect::<Vec<_>>();
+
+    // the cosine basis along each axis
+    let basis = |len: usize, n: u32| {
+        (0..n)
+            .map(|i| {
+                (0..len)
+                    .map(|p| (PI * i as f32 * p as f32 / len as f32).cos())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+    let basis_x = basis(width, components_x);
+    let basis_y = basis(height, components_y);
+
+    let mut factors = Vec::with_capacity((components_x * components_y) as usize);
+    for (j, by) in basis_y.iter().enumerate() {
+        for (i, bx) in basis_x.iter().enumerate() {
+            let normalization = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+            let mut factor = [0.0f32; 3];
+            for (y, row) in linear.chunks_exact(width * 3).enumerate() {
+                for (x, px) in row.chunks_exact(3).enumerate() {
+                    let b = bx[x] * by[y];
+                    factor.iter_mut().zip(px).for_each(|(f, &p)| *f += b * p);
+                }
+            }
+            let scale = normalization / (width * height) as f32;
+            factors.push(factor.map(|f| f * scale));
+        }
+    }
+
+    let (dc, ac) = factors.split_first().expect("at least one component");
+
+    let mut hash = String::with_capacity(4 + 2 * factors.len());
+    encode_base83((components_x - 1) + (components_y - 1) * 9, 1, &mut hash);
+
+    let maximum_value = if ac.is_empty() {
+        encode_base83(0, 1, &mut hash);
+        1.0
+    } else {
+        let actual_max = ac.iter().flatten().fold(0.0f32, |m, v| m.max(v.abs()));
+        let quantised_max = (actual_max * 166.0 - 0.5).floor().clamp(0.0, 82.0) as u32;
+        encode_base83(quantised_max, 1, &mut hash);
+        (quantised_max + 1) as f32 / 166.0
+    };
+
+    let [r, g, b] = dc.map(|c| linear_to_srgb(c) as u32);
+    encode_base83((r << 16) + (g << 8) + b, 4, &mut hash);
+
+    for factor in ac {
+        let [r, g, b] = factor.map(|c| {
+            (sign_pow(c / maximum_value, 0.5) * 9.0 + 9.5)
+                .floor()
+                .clamp(0.0, 18.0) as u32
+        });
+        encode_base83(r * 19 * 19 + g * 19 + b, 2, &mut hash);
+    }
+
+    Ok(hash)
+}
+
+/// Decode a BlurHash string into a placeholder image.
+///
+/// # Arguments
+///
+/// * `hash` - The BlurHash string.
+/// * `size` - The size of the decoded image.
+///
+/// # Returns
+///
+/// The placeholder RGB image with shape (H, W, 3).
+///
+/// # Errors
+///
+/// Returns an error if the hash is malformed, declares more than 9 components
+/// along an axis or its length does not match the number of components it declares.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::ImageSize;
+/// use kornia_io::blurhash::blurhash_decode;
+///
+/// let size = ImageSize { width: 32, height: 24 };
+/// let image = blurhash_decode("LEHV6nWB2yk8pyo0adR*.7kCMdnj", size).unwrap();
+///
+/// assert_eq!(image.size(), size);
+/// ```
+pub fn blurhash_decode(hash: &str, size: ImageSize) -> Result<Image<u8, 3>, IoError> {
+    if hash.len() < 6 || !hash.is_ascii() {
+        return Err(IoError::BlurHashError(format!("invalid hash {hash:?}")));
+    }
+
+    // the size flag packs the number of components of both axes, each at most 9
+    let size_flag = decode_base83(&hash[..1])?;
+    if size_flag >= MAX_COMPONENTS * MAX_COMPONENTS {
+        return Err(IoError::BlurHashError(format!(
+            "invalid size flag {:?}",
+            &hash[..1]
+        )));
+    }
+    let components_x = (size_flag % MAX_COMPONENTS + 1) as usize;
+    let components_y = (size_flag / MAX_COMPONENTS + 1) as usize;
+
+    if hash.len() != 4 + 2 * components_x * components_y {
+        return Err(IoError::BlurHashError(format!(
+            "invalid hash length {} for {components_x}x{components_y} components",
+            hash.len()
+        )));
+    }
+
+    let maximum_value = (decode_base83(&hash[1..2])? + 1) as f32 / 166.0;
+
+    let dc = decode_base83(&hash[2..6])?;
+    let mut colors = vec![[
+        srgb_to_linear((dc >> 16) as u8),
+        srgb_to_linear((dc >> 8) as u8),
+        srgb_to_linear(dc as u8),
+    ]];
+
+    for i in 1..components_x * components_y {
+        let value = decode_base83(&hash[4 + 2 * i..6 + 2 * i])?;
+        let quant = [value / (19 * 19), (value / 19) % 19, value % 19];
+        colors.push(quant.map(|q| sign_pow((q as f32 - 9.0) / 9.0, 2.0) * maximum_value));
+    }
+
+    let (width, height) = (size.width, size.height);
+    let mut data = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let mut pixel = [0.0f32; 3];
+            for j in 0..components_y {
+                let basis_y = (PI * y as f32 * j as f32 / height as f32).cos();
+                for i in 0..components_x {
+                    let basis = (PI * x as f32 * i as f32 / width as f32).cos() * basis_y;
+                    let color = &colors[i + j * components_x];
+                    pixel
+                        .iter_mut()
+                        .zip(color)
+                        .for_each(|(p, &c)| *p += c * basis);
+                }
+            }
+            data.extend(pixel.map(linear_to_srgb));
+        }
+    }
+
+    Ok(Image::new(size, data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use kornia_image::{Image, ImageSize};
+
+    // the mean of each channel of an image
+    fn mean_color(image: &Image<u8, 3>) -> [f32; 3] {
+        let mut sum = [0.0f32; 3];
+        for px in image.as_slice().chunks_exact(3) {
+            sum.iter_mut().zip(px).for_each(|(s, &p)| *s += p as f32);
+        }
+        sum.map(|s| s / (image.cols() * image.rows()) as f32)
+    }
+
+    #[test]
+    fn blurhash_roundtrip() -> Result<(), IoError> {
+        let size = ImageSize {
+            width: 40,
+            height: 30,
+        };
+
+        // a smooth gradient from dark red to light blue
+        let data = (0..size.width * size.height)
+            .flat_map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                [200 - (x * 4) as u8, 60 + y as u8, 40 + (x * 5) as u8]
+            })
+            .collect();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        let hash = super::blurhash_encode(&image, 4, 3)?;
+        assert_eq!(hash.len(), 4 + 2 * 4 * 3);
+
+        let decoded = super::blurhash_decode(&hash, size)?;
+        assert_eq!(decoded.size(), size);
+
+        let (mean, mean_back) = (mean_color(&image), mean_color(&decoded));
+        for (a, b) in mean.iter().zip(mean_back.iter()) {
+            assert!((a - b).abs() < 10.0, "mean {mean:?} decoded {mean_back:?}");
+        }
+
+        // a single component encodes only the average color
+        let hash = super::blurhash_encode(&image, 1, 1)?;
+        assert_eq!(hash.len(), 6);
+        let flat = super::blurhash_decode(&hash, size)?;
+        assert!(flat
+            .as_slice()
+            .chunks_exact(3)
+            .all(|px| px == &flat.as_slice()[..3]));
+
+        assert!(super::blurhash_encode(&image, 0, 3).is_err());
+        assert!(super::blurhash_encode(&image, 4, 10).is_err());
+        assert!(super::blurhash_decode("LEHV6nWB2yk8", size).is_err());
+        assert!(super::blurhash_decode("LEHV6nWB2yk8pyo0adR*.7kCMdn\"", size).is_err());
+
+        // a size flag of 81 declares 1x10 components
+        let hash = format!("}}{}", "0".repeat(23));
+        assert!(super::blurhash_decode(&hash, size).is_err());
+
+        Ok(())
+    }
+}
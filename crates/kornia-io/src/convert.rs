@@ -0,0 +1,102 @@
+use kornia_image::{Image, ImageError, ImageSize};
+
+use crate::error::IoError;
+
+/// Convert a NV12 frame to a RGB image.
+///
+/// NV12 is a YUV 4:2:0 format with a full resolution luma (Y) plane followed by
+/// a half resolution plane of interleaved chroma samples (U, V), as delivered
+/// by many cameras and hardware video decoders. The conversion uses the BT.601
+/// coefficients with limited range, i.e. luma in [16, 235] and chroma in
+/// [16, 240], and each chroma sample is shared by a 2x2 block of pixels.
+///
+/// # Arguments
+///
+/// * `y` - The luma plane with `width * height` bytes.
+/// * `uv` - The interleaved chroma plane with `2 * ceil(width / 2) * ceil(height / 2)` bytes.
+/// * `size` - The size of the frame in pixels.
+///
+/// # Returns
+///
+/// The RGB image with shape (H, W, 3).
+///
+/// # Errors
+///
+/// Returns an error if the length of a plane does not match the frame size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::ImageSize;
+/// use kornia_io::convert::rgb8_from_nv12;
+///
+/// let size = ImageSize { width: 2, height: 2 };
+///
+/// // a mid gray frame
+/// let image = rgb8_from_nv12(&[126; 4], &[128, 128], size).unwrap();
+///
+/// assert_eq!(image.as_slice(), &[128; 12]);
+/// ```
+pub fn rgb8_from_nv12(y: &[u8], uv: &[u8], size: ImageSize) -> Result<Image<u8, 3>, IoError> {
+    let (width, height) = (size.width, size.height);
+    let chroma_width = width.div_ceil(2);
+
+    if y.len() != width * height {
+        return Err(ImageError::InvalidChannelShape(y.len(), width * height).into());
+    }
+
+    let uv_len = 2 * chroma_width * height.div_ceil(2);
+    if uv.len() != uv_len {
+        return Err(ImageError::InvalidChannelShape(uv.len(), uv_len).into());
+    }
+
+    let mut data = Vec::with_capacity(width * height * 3);
+
+    for (row, y_row) in y.chunks_exact(width.max(1)).enumerate() {
+        let uv_row = &uv[(row / 2) * 2 * chroma_width..];
+        for (col, &luma) in y_row.iter().enumerate() {
+            let c = 1.164 * (luma as f32 - 16.0);
+            let d = uv_row[(col / 2) * 2] as f32 - 128.0;
+            let e = uv_row[(col / 2) * 2 + 1] as f32 - 128.0;
+
+            let rgb = [c + 1.596 * e, c - 0.392 * d - 0.813 * e, c + 2.017 * d];
+            data.extend(rgb.map(|v| v.round().clamp(0.0, 255.0) as u8));
+        }
+    }
+
+    Ok(Image::new(size, data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn rgb8_from_nv12() -> Result<(), IoError> {
+        // an odd size to exercise the rounding of the chroma plane
+        let size = ImageSize {
+            width: 5,
+            height: 3,
+        };
+
+        // rgb (200, 100, 50) in BT.601 limited range
+        let (y, u, v) = (123u8, 91u8, 175u8);
+        let y_plane = vec![y; size.width * size.height];
+        let uv_plane = [u, v].repeat(3 * 2);
+
+        let image = super::rgb8_from_nv12(&y_plane, &uv_plane, size)?;
+        assert_eq!(image.size(), size);
+
+        for px in image.as_slice().chunks_exact(3) {
+            for (&value, expected) in px.iter().zip([200u8, 100, 50]) {
+                assert!(value.abs_diff(expected) <= 3, "pixel: {px:?}");
+            }
+        }
+
+        assert!(super::rgb8_from_nv12(&y_plane[1..], &uv_plane, size).is_err());
+        assert!(super::rgb8_from_nv12(&y_plane, &uv_plane[2..], size).is_err());
+
+        Ok(())
+    }
+}
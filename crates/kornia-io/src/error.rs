@@ -26,6 +26,53 @@ pub enum IoError {
     #[error("Failed to decode the image")]
     ImageDecodeError(#[from] image::ImageError),
 
+    /// Error to decode the OpenEXR image.
+    #[cfg(feature = "exr")]
+    #[error("Failed to decode the EXR image")]
+    ExrDecodeError(#[from] exr::error::Error),
+
+    /// Error to decode the GIF image.
+    #[cfg(feature = "gif")]
+    #[error("Failed to decode the GIF image")]
+    GifDecodeError(#[from] gif::DecodingError),
+
+    /// Error to decode the HEIF image.
+    #[cfg(feature = "heif")]
+    #[error("Failed to decode the HEIF image")]
+    HeifDecodeError(#[from] libheif_rs::HeifError),
+
+    /// Error to encode or decode the TIFF image.
+    #[cfg(feature = "tiff")]
+    #[error("Failed to encode or decode the TIFF image")]
+    TiffError(#[from] tiff::TiffError),
+
+    /// Error when a blocking task of the async API fails to complete.
+    #[cfg(feature = "tokio")]
+    #[error("Failed to run the blocking task")]
+    BlockingTaskError(#[from] tokio::task::JoinError),
+
+    /// Error to encode or decode a BlurHash.
+    #[error("Failed to encode or decode the BlurHash: {0}")]
+    BlurHashError(String),
+
+    /// Error when the image data is truncated or corrupted.
+    #[error("Corrupted image data: {0}")]
+    CorruptImageData(String),
+
+    /// Error when the encoded image does not reach the minimum fidelity.
+    #[error("Encoded image PSNR {0:.2} dB is below the minimum {1:.2} dB")]
+    EncodeFidelityError(f64, f64),
+
+    /// Error to encode or decode the QOI image.
+    #[cfg(feature = "qoi")]
+    #[error("Failed to encode or decode the QOI image: {0}")]
+    QoiError(String),
+
+    /// Error to decode the AVIF image.
+    #[cfg(feature = "avif")]
+    #[error("Failed to decode the AVIF image: {0}")]
+    AvifDecodeError(String),
+
     /// Error to decode the PNG image.
     #[error("Failed to decode the image")]
     PngDecodeError(String),
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use exr::prelude::{read_first_rgba_layer_from_file, RgbaChannels, Vec2};
+use kornia_image::{Image, ImageSize};
+
+use crate::error::IoError;
+
+/// Read an OpenEXR image with three float channels (rgb32f).
+///
+/// The first layer with red, green and blue channels is read at its largest
+/// resolution level. Half, float and integer samples are converted to `f32`
+/// without any tone mapping, so HDR values above 1.0 are preserved. The alpha
+/// channel of RGBA layers is dropped.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the EXR file.
+///
+/// # Returns
+///
+/// A RGB image with three float channels (rgb32f).
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, does not have an `exr`
+/// extension or has no RGB layer.
+pub fn read_image_exr_rgb_f32(file_path: impl AsRef<Path>) -> Result<Image<f32, 3>, IoError> {
+    let file_path = file_path.as_ref();
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    if file_path
+        .extension()
+        .map_or(true, |ext| !ext.eq_ignore_ascii_case("exr"))
+    {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+
+    let image = read_first_rgba_layer_from_file(
+        file_path,
+        |resolution: Vec2<usize>, _: &RgbaChannels| {
+            (
+                resolution,
+                vec![0.0f32; resolution.width() * resolution.height() * 3],
+            )
+        },
+        |(resolution, pixels): &mut (Vec2<usize>, Vec<f32>),
+         position: Vec2<usize>,
+         (r, g, b, _a): (f32, f32, f32, f32)| {
+            let offset = (position.y() * resolution.width() + position.x()) * 3;
+            pixels[offset..offset + 3].copy_from_slice(&[r, g, b]);
+        },
+    )?;
+
+    let (resolution, pixels) = image.layer_data.channel_data.pixels;
+
+    Ok(Image::new(
+        ImageSize {
+            width: resolution.width(),
+            height: resolution.height(),
+        },
+        pixels,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use exr::prelude::{f16, write_rgb_file, write_rgba_file};
+
+    #[test]
+    fn read_exr() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp_dir = tempfile::tempdir()?;
+        let (width, height) = (16, 8);
+
+        // a half float gradient with HDR values above 1.0
+        let file_path = tmp_dir.path().join("rgb.exr");
+        write_rgb_file(&file_path, width, height, |x, y| {
+            (
+                f16::from_f32(x as f32 * 0.25),
+                f16::from_f32(y as f32 * 0.5),
+                f16::from_f32(0.125),
+            )
+        })?;
+
+        let image = super::read_image_exr_rgb_f32(&file_path)?;
+        assert_eq!(image.cols(), width);
+        assert_eq!(image.rows(), height);
+        assert_eq!(image.num_channels(), 3);
+
+        let pixel = (0..3)
+            .map(|ch| image.get_pixel(10, 5, ch).copied())
+            .collect::<Result<Vec<_>, _>>()?;
+        for (value, expected) in pixel.iter().zip([2.5, 2.5, 0.125]) {
+            assert!((value - expected).abs() < 1e-3, "{pixel:?}");
+        }
+
+        // the alpha channel of a float RGBA layer is dropped
+        let file_path = tmp_dir.path().join("rgba.exr");
+        write_rgba_file(&file_path, width, height, |x, y| {
+            (x as f32 / 16.0, y as f32 / 8.0, 0.75f32, 0.5f32)
+        })?;
+
+        let image = super::read_image_exr_rgb_f32(&file_path)?;
+        assert_eq!(image.num_channels(), 3);
+        assert!((image.get_pixel(4, 2, 0)? - 0.25).abs() < 1e-6);
+        assert!((image.get_pixel(4, 2, 1)? - 0.25).abs() < 1e-6);
+        assert!((image.get_pixel(4, 2, 2)? - 0.75).abs() < 1e-6);
+
+        let result = super::read_image_exr_rgb_f32(tmp_dir.path().join("missing.exr"));
+        assert!(matches!(result, Err(IoError::FileDoesNotExist(_))));
+
+        Ok(())
+    }
+}
@@ -1,12 +1,41 @@
 use std::path::Path;
+#[cfg(feature = "turbojpeg")]
+use std::{
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+    time::SystemTime,
+};
 
 use kornia_image::{Image, ImageSize};
 
 use crate::error::IoError;
+use crate::jpeg_markers::read_jpeg_segments;
 
 #[cfg(feature = "turbojpeg")]
 use super::jpegturbo::{JpegTurboDecoder, JpegTurboEncoder};
 
+// whether the file has a JPEG extension, ignoring the case
+#[cfg(feature = "turbojpeg")]
+fn has_jpeg_extension(file_path: &Path) -> bool {
+    file_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("jpg") || ext.eq_ignore_ascii_case("jpeg"))
+}
+
+// verify the file exists and has a JPEG extension before reading it
+#[cfg(feature = "turbojpeg")]
+fn check_jpeg_path(file_path: &Path) -> Result<(), IoError> {
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    if !has_jpeg_extension(file_path) {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "turbojpeg")]
 /// Reads a JPEG image in `RGB8` format from the given file path.
 ///
@@ -33,17 +62,9 @@ use super::jpegturbo::{JpegTurboDecoder, JpegTurboEncoder};
 /// assert_eq!(image.num_channels(), 3);
 /// ```
 pub fn read_image_jpegturbo_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
-    let file_path = file_path.as_ref().to_owned();
+    let file_path = file_path.as_ref();
     // verify the file exists and is a JPEG
-    if !file_path.exists() {
-        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
-    }
-
-    if file_path.extension().map_or(true, |ext| {
-        !ext.eq_ignore_ascii_case("jpg") && !ext.eq_ignore_ascii_case("jpeg")
-    }) {
-        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
-    }
+    check_jpeg_path(file_path)?;
 
     // open the file and map it to memory
     let jpeg_data = std::fs::read(file_path)?;
@@ -57,6 +78,123 @@ pub fn read_image_jpegturbo_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8
     Ok(image)
 }
 
+/// Reads a JPEG image in `RGB8` format from the given file path asynchronously.
+///
+/// The file is read with `tokio::fs` and the CPU-bound decoding runs on the
+/// blocking thread pool of the tokio runtime, so the async executor is not
+/// stalled while the image is loaded.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the JPEG image.
+///
+/// # Returns
+///
+/// An image containing the JPEG image data.
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, is not a JPEG or cannot be decoded.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_io::functional as F;
+///
+/// # async fn run() -> Result<(), kornia_io::IoError> {
+/// let image = F::read_image_jpegturbo_rgb8_async("../../tests/data/dog.jpeg").await?;
+///
+/// assert_eq!(image.cols(), 258);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(all(feature = "turbojpeg", feature = "tokio"))]
+pub async fn read_image_jpegturbo_rgb8_async(
+    file_path: impl AsRef<Path>,
+) -> Result<Image<u8, 3>, IoError> {
+    let file_path = file_path.as_ref().to_owned();
+
+    if !has_jpeg_extension(&file_path) {
+        return Err(IoError::InvalidFileExtension(file_path));
+    }
+
+    let jpeg_data = match tokio::fs::read(&file_path).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(IoError::FileDoesNotExist(file_path));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    // decode on the blocking thread pool to keep the executor responsive
+    tokio::task::spawn_blocking(move || {
+        let mut decoder = JpegTurboDecoder::new()?;
+        Ok(decoder.decode_rgb8(&jpeg_data)?)
+    })
+    .await?
+}
+
+/// Reads a thumbnail of a JPEG image in `RGB8` format from the given file path.
+///
+/// The image is downscaled while decoding with the native scaling of
+/// libjpeg-turbo, which skips most of the decoding work instead of decoding the
+/// full image and resizing it. The largest scaling factor supported by the
+/// library, e.g. 3/8 or 1/4, whose output fits within `max_dim` is used, and
+/// images smaller than `max_dim` are not upscaled. Images whose smallest scale
+/// still exceeds `max_dim` are decoded at that scale.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the JPEG image.
+/// * `max_dim` - The maximum width and height of the thumbnail in pixels.
+///
+/// # Returns
+///
+/// The thumbnail image with the aspect ratio of the image.
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::functional as F;
+///
+/// let thumbnail = F::read_jpeg_thumbnail_rgb8("../../tests/data/dog.jpeg", 128).unwrap();
+///
+/// // the 258x195 image is decoded at 3/8 scale
+/// assert_eq!(thumbnail.cols(), 97);
+/// assert_eq!(thumbnail.rows(), 74);
+/// ```
+#[cfg(feature = "turbojpeg")]
+pub fn read_jpeg_thumbnail_rgb8(
+    file_path: impl AsRef<Path>,
+    max_dim: u32,
+) -> Result<Image<u8, 3>, IoError> {
+    let file_path = file_path.as_ref();
+    // verify the file exists and is a JPEG
+    check_jpeg_path(file_path)?;
+
+    let jpeg_data = std::fs::read(file_path)?;
+
+    let mut decoder = JpegTurboDecoder::new()?;
+    let size = decoder.read_header(&jpeg_data)?;
+
+    // the downscaling factors sorted from the largest to the smallest output
+    let mut factors = turbojpeg::Decompressor::supported_scaling_factors()
+        .into_iter()
+        .filter(|factor| factor.num() <= factor.denom())
+        .collect::<Vec<_>>();
+    factors.sort_by_key(|factor| std::cmp::Reverse(factor.scale(size.width.max(size.height))));
+
+    let max_dim = max_dim as usize;
+    let scaling_factor = factors
+        .iter()
+        .find(|factor| factor.scale(size.width.max(size.height)) <= max_dim)
+        .or(factors.last())
+        .copied()
+        .unwrap_or(turbojpeg::ScalingFactor::ONE_EIGHTH);
+
+    Ok(decoder.decode_rgb8_scaled(&jpeg_data, scaling_factor)?)
+}
+
 #[cfg(feature = "turbojpeg")]
 /// Writes the given JPEG data to the given file path.
 ///
@@ -76,34 +214,941 @@ pub fn write_image_jpegturbo_rgb8(
     // write the data directly to a file
     std::fs::write(file_path, jpeg_data)?;
 
-    Ok(())
+    Ok(())
+}
+
+#[cfg(feature = "turbojpeg")]
+/// Verifies that a JPEG encode and decode round-trip preserves the image fidelity.
+///
+/// The image is encoded with the given quality, decoded back and compared
+/// against the original using the peak signal-to-noise ratio (PSNR).
+///
+/// # Arguments
+///
+/// * `image` - The image to encode.
+/// * `quality` - The JPEG quality in the range [1, 100].
+/// * `min_psnr` - The minimum accepted PSNR in dB.
+///
+/// # Errors
+///
+/// Returns [`IoError::EncodeFidelityError`] if the PSNR is below `min_psnr`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::functional as F;
+///
+/// let image = F::read_image_jpegturbo_rgb8("../../tests/data/dog.jpeg").unwrap();
+///
+/// F::assert_encode_fidelity(&image, 95, 35.0).unwrap();
+/// ```
+pub fn assert_encode_fidelity(
+    image: &Image<u8, 3>,
+    quality: i32,
+    min_psnr: f64,
+) -> Result<(), IoError> {
+    let mut encoder = JpegTurboEncoder::new()?;
+    encoder.set_quality(quality)?;
+    let jpeg_data = encoder.encode_rgb8(image)?;
+
+    let decoded = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+
+    let psnr = crate::metrics::psnr_u8(image, &decoded)?;
+    if psnr < min_psnr {
+        return Err(IoError::EncodeFidelityError(psnr, min_psnr));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "turbojpeg")]
+/// A JPEG reader caching the decoded images by file path.
+///
+/// A cached image is returned as long as the modification time of its file does
+/// not change. The cache holds at most `capacity` images and evicts the least
+/// recently used image when full.
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::functional::CachedJpegReader;
+///
+/// let mut reader = CachedJpegReader::new(4).unwrap();
+///
+/// let image = reader.read_rgb8("../../tests/data/dog.jpeg").unwrap();
+/// let cached = reader.read_rgb8("../../tests/data/dog.jpeg").unwrap();
+///
+/// assert_eq!(image.as_slice(), cached.as_slice());
+/// assert_eq!(reader.num_decodes(), 1);
+/// ```
+pub struct CachedJpegReader {
+    decoder: JpegTurboDecoder,
+    capacity: usize,
+    cache: HashMap<PathBuf, (SystemTime, Image<u8, 3>)>,
+    // the cached paths from the least to the most recently used
+    recency: VecDeque<PathBuf>,
+    num_decodes: usize,
+}
+
+#[cfg(feature = "turbojpeg")]
+impl CachedJpegReader {
+    /// Creates a new `CachedJpegReader`.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of cached images. A capacity of 0 disables the cache.
+    pub fn new(capacity: usize) -> Result<Self, IoError> {
+        Ok(Self {
+            decoder: JpegTurboDecoder::new()?,
+            capacity,
+            cache: HashMap::with_capacity(capacity),
+            recency: VecDeque::with_capacity(capacity),
+            num_decodes: 0,
+        })
+    }
+
+    /// Reads a JPEG image in `RGB8` format from the given file path.
+    ///
+    /// The image is decoded only if it is not cached or if the file was modified
+    /// since it was cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the JPEG image.
+    ///
+    /// # Returns
+    ///
+    /// A copy of the decoded image.
+    pub fn read_rgb8(&mut self, file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
+        let file_path = file_path.as_ref().to_path_buf();
+
+        // verify the file exists
+        if !file_path.exists() {
+            return Err(IoError::FileDoesNotExist(file_path));
+        }
+
+        let modified = std::fs::metadata(&file_path)?.modified()?;
+
+        // the cached image is not used if the file was modified
+        let cached = self.cache.get(&file_path);
+        if let Some((_, image)) = cached.filter(|(mtime, _)| *mtime == modified) {
+            let image = image.clone();
+            self.touch(&file_path);
+            return Ok(image);
+        }
+
+        let jpeg_data = std::fs::read(&file_path)?;
+        let image = self.decoder.decode_rgb8(&jpeg_data)?;
+        self.num_decodes += 1;
+
+        if self.capacity == 0 {
+            return Ok(image);
+        }
+
+        // evict the least recently used image to make room for a new entry
+        if !self.cache.contains_key(&file_path) && self.cache.len() >= self.capacity {
+            if let Some(evicted) = self.recency.pop_front() {
+                self.cache.remove(&evicted);
+            }
+        }
+
+        self.cache
+            .insert(file_path.clone(), (modified, image.clone()));
+        self.touch(&file_path);
+
+        Ok(image)
+    }
+
+    /// Returns the number of cached images.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns true if no image is cached.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Returns the number of images decoded since the reader was created.
+    pub fn num_decodes(&self) -> usize {
+        self.num_decodes
+    }
+
+    // mark the given path as the most recently used
+    fn touch(&mut self, file_path: &Path) {
+        self.recency.retain(|path| path != file_path);
+        self.recency.push_back(file_path.to_path_buf());
+    }
+}
+
+/// Reads a RGB8 image from the given file path.
+///
+/// The method tries to read from any image format supported by the image crate.
+/// The format is detected from the magic bytes of the file. With the
+/// `turbojpeg` feature, JPEG files are decoded with the faster turbojpeg
+/// decoder, falling back to the image crate if turbojpeg fails.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the image.
+///
+/// # Returns
+///
+/// A tensor image containing the image data in RGB8 format with shape (H, W, 3).
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::Image;
+/// use kornia_io::functional as F;
+///
+/// let image: Image<u8, 3> = F::read_image_any_rgb8("../../tests/data/dog.jpeg").unwrap();
+///
+/// assert_eq!(image.cols(), 258);
+/// assert_eq!(image.rows(), 195);
+/// assert_eq!(image.num_channels(), 3);
+/// ```
+pub fn read_image_any_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
+    let file_path = file_path.as_ref().to_owned();
+
+    // verify the file exists
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    // open the file and map it to memory
+    let jpeg_data = std::fs::read(file_path)?;
+
+    decode_image_any_rgb8(&jpeg_data)
+}
+
+/// Reads a RGB8 image from the given file path memory-mapping the file.
+///
+/// Same as [`read_image_any_rgb8`], but the file is memory-mapped and decoded
+/// from the mapped pages instead of being copied to the heap first, which
+/// avoids a large allocation for big files. If the file cannot be mapped, e.g.
+/// on a file system without mmap support, it is read into memory instead.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the image.
+///
+/// # Returns
+///
+/// A tensor image containing the image data in RGB8 format with shape (H, W, 3).
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::Image;
+/// use kornia_io::functional as F;
+///
+/// let image: Image<u8, 3> = F::read_image_any_rgb8_mmap("../../tests/data/dog.jpeg").unwrap();
+///
+/// assert_eq!(image.cols(), 258);
+/// assert_eq!(image.rows(), 195);
+/// ```
+#[cfg(feature = "mmap")]
+pub fn read_image_any_rgb8_mmap(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
+    let file_path = file_path.as_ref().to_owned();
+
+    // verify the file exists
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    let file = std::fs::File::open(&file_path)?;
+
+    // SAFETY: the mapping is only read while decoding; the file must not be
+    // truncated by another process in the meantime
+    match unsafe { memmap2::Mmap::map(&file) } {
+        Ok(mmap) => decode_image_any_rgb8(&mmap),
+        Err(err) => {
+            log::debug!("failed to map {}: {err}", file_path.display());
+            decode_image_any_rgb8(&std::fs::read(&file_path)?)
+        }
+    }
+}
+
+// decode an image of any format supported by the image crate as rgb8
+fn decode_image_any_rgb8(data: &[u8]) -> Result<Image<u8, 3>, IoError> {
+    #[cfg(feature = "turbojpeg")]
+    if is_jpeg(data) {
+        match JpegTurboDecoder::new().and_then(|mut decoder| decoder.decode_rgb8(data)) {
+            Ok(image) => return Ok(image),
+            Err(err) => log::debug!("turbojpeg failed, decoding with the image crate: {err}"),
+        }
+    }
+
+    // decode the data directly from memory
+    let img = image::ImageReader::new(std::io::Cursor::new(data))
+        .with_guessed_format()?
+        .decode()?;
+
+    // TODO: handle more image formats
+    // return the image data
+    let image = Image::new(
+        ImageSize {
+            width: img.width() as usize,
+            height: img.height() as usize,
+        },
+        img.to_rgb8().to_vec(),
+    )?;
+
+    Ok(image)
+}
+
+// a JPEG stream starts with the start of image (SOI) marker followed by another marker
+#[cfg(feature = "turbojpeg")]
+fn is_jpeg(data: &[u8]) -> bool {
+    data.starts_with(&[0xFF, 0xD8, 0xFF])
+}
+
+/// Convert a RGB image (rgb8) to grayscale (gray8) using the Rec. 601 luma weights.
+///
+/// Each output pixel is computed as:
+///
+/// Y = 0.299 * R + 0.587 * G + 0.114 * B
+///
+/// and rounded to the nearest integer.
+///
+/// # Arguments
+///
+/// * `image` - The RGB image with shape (H, W, 3).
+///
+/// # Returns
+///
+/// The grayscale image with shape (H, W, 1).
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::functional as F;
+///
+/// let image = Image::<u8, 3>::new(ImageSize { width: 1, height: 1 }, vec![0, 255, 0]).unwrap();
+///
+/// let gray = F::rgb8_to_gray8(&image);
+/// assert_eq!(gray.as_slice(), &[150]);
+/// ```
+pub fn rgb8_to_gray8(image: &Image<u8, 3>) -> Image<u8, 1> {
+    let data = image
+        .as_slice()
+        .chunks_exact(3)
+        .map(|px| {
+            let y = 0.299 * px[0] as f32 + 0.587 * px[1] as f32 + 0.114 * px[2] as f32;
+            y.round().min(255.0) as u8
+        })
+        .collect();
+
+    // the data has exactly one value per pixel of the source image
+    Image::new(image.size(), data).expect("gray data matches the image size")
+}
+
+/// Reads an image from the given file path as grayscale (gray8).
+///
+/// The image is decoded as RGB with [`read_image_any_rgb8`] and converted with
+/// [`rgb8_to_gray8`], using the Rec. 601 luma weights (0.299, 0.587, 0.114).
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the image.
+///
+/// # Returns
+///
+/// A tensor image containing the image data in grayscale format with shape (H, W, 1).
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::Image;
+/// use kornia_io::functional as F;
+///
+/// let image: Image<u8, 1> = F::read_image_any_gray8_luminance("../../tests/data/dog.jpeg").unwrap();
+///
+/// assert_eq!(image.cols(), 258);
+/// assert_eq!(image.rows(), 195);
+/// assert_eq!(image.num_channels(), 1);
+/// ```
+pub fn read_image_any_gray8_luminance(
+    file_path: impl AsRef<Path>,
+) -> Result<Image<u8, 1>, IoError> {
+    let image = read_image_any_rgb8(file_path)?;
+    Ok(rgb8_to_gray8(&image))
+}
+
+/// An image decoded with its native channel layout.
+pub enum DynamicImageOut {
+    /// A grayscale image with shape (H, W, 1).
+    Gray8(Image<u8, 1>),
+    /// A RGB image with shape (H, W, 3).
+    Rgb8(Image<u8, 3>),
+    /// A RGBA image with shape (H, W, 4).
+    Rgba8(Image<u8, 4>),
+}
+
+/// Reads an image from the given file path preserving its channel layout.
+///
+/// The method tries to read from any image format supported by the image crate.
+/// Grayscale sources are returned as [`DynamicImageOut::Gray8`], sources with an
+/// alpha channel as [`DynamicImageOut::Rgba8`] and any other source as
+/// [`DynamicImageOut::Rgb8`]. Sources with more than 8 bits per channel are
+/// converted to 8 bits.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the image.
+///
+/// # Returns
+///
+/// The decoded image with the variant matching the channels of the source.
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::functional::{self as F, DynamicImageOut};
+///
+/// let image = F::read_image_any("../../tests/data/dog.jpeg").unwrap();
+///
+/// assert!(matches!(image, DynamicImageOut::Rgb8(_)));
+/// ```
+pub fn read_image_any(file_path: impl AsRef<Path>) -> Result<DynamicImageOut, IoError> {
+    let file_path = file_path.as_ref().to_owned();
+
+    // verify the file exists
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    let data = std::fs::read(file_path)?;
+
+    // decode the data directly from memory
+    let img = image::ImageReader::new(std::io::Cursor::new(&data))
+        .with_guessed_format()?
+        .decode()?;
+
+    let size = ImageSize {
+        width: img.width() as usize,
+        height: img.height() as usize,
+    };
+
+    // keep the channel layout of the source, grayscale with alpha is expanded to rgba
+    let color = img.color();
+    let image = if color.has_alpha() {
+        DynamicImageOut::Rgba8(Image::new(size, img.to_rgba8().into_raw())?)
+    } else if color.has_color() {
+        DynamicImageOut::Rgb8(Image::new(size, img.to_rgb8().into_raw())?)
+    } else {
+        DynamicImageOut::Gray8(Image::new(size, img.to_luma8().into_raw())?)
+    };
+
+    Ok(image)
+}
+
+/// Writes and reads back a RGB8 image with every format supported by the image crate.
+///
+/// The image is written to a file named `roundtrip.<extension>` in the given
+/// directory for each format, decoded back and compared against the original
+/// using the peak signal-to-noise ratio (PSNR).
+///
+/// # Arguments
+///
+/// * `image` - The image to round-trip.
+/// * `dir` - The directory to write the files to.
+///
+/// # Returns
+///
+/// The PSNR in dB of each format. Lossless formats have an infinite PSNR.
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::functional as F;
+///
+/// let image = F::read_image_any_rgb8("../../tests/data/dog.jpeg").unwrap();
+/// let tmp_dir = tempfile::tempdir().unwrap();
+///
+/// let results = F::roundtrip_all_formats(&image, tmp_dir.path()).unwrap();
+///
+/// assert!(results.contains(&(image::ImageFormat::Png, f64::INFINITY)));
+/// ```
+pub fn roundtrip_all_formats(
+    image: &Image<u8, 3>,
+    dir: &Path,
+) -> Result<Vec<(image::ImageFormat, f64)>, IoError> {
+    use image::ImageFormat;
+
+    let formats = [
+        ImageFormat::Png,
+        ImageFormat::Jpeg,
+        ImageFormat::Bmp,
+        ImageFormat::Tiff,
+        ImageFormat::WebP,
+        ImageFormat::Qoi,
+        ImageFormat::Tga,
+        ImageFormat::Pnm,
+    ];
+
+    let mut results = Vec::with_capacity(formats.len());
+
+    for format in formats {
+        let file_path = dir.join(format!("roundtrip.{}", format.extensions_str()[0]));
+
+        image::save_buffer_with_format(
+            &file_path,
+            image.as_slice(),
+            image.width() as u32,
+            image.height() as u32,
+            image::ExtendedColorType::Rgb8,
+            format,
+        )?;
+
+        // some formats as tga cannot be guessed from the content
+        let data = std::fs::read(&file_path)?;
+        let img = image::ImageReader::with_format(std::io::Cursor::new(&data), format).decode()?;
+        let decoded = Image::new(image.size(), img.to_rgb8().into_raw())?;
+
+        results.push((format, crate::metrics::psnr_u8(image, &decoded)?));
+    }
+
+    Ok(results)
+}
+
+/// Converts every image of a directory to another format.
+///
+/// Each file of `src_dir` is decoded as RGB with [`read_image_any_rgb8`] and
+/// written to `dst_dir` with the same file stem and the target extension. The
+/// files that cannot be read as an image are skipped. The subdirectories are
+/// not visited and `dst_dir` is created if it does not exist.
+///
+/// # Arguments
+///
+/// * `src_dir` - The directory with the images to convert.
+/// * `dst_dir` - The directory to write the converted images to.
+/// * `target_ext` - The extension of the target format, e.g. `png` or `jpg`.
+/// * `quality` - The JPEG quality in the range [1, 100], ignored by the other formats.
+///
+/// # Returns
+///
+/// The number of converted images.
+///
+/// # Errors
+///
+/// Returns an error if `src_dir` does not exist, the target extension is not a
+/// format that can be written or a converted image cannot be written.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_io::functional as F;
+///
+/// let num_converted = F::convert_directory("images", "images_png", "png", 90).unwrap();
+/// ```
+pub fn convert_directory(
+    src_dir: impl AsRef<Path>,
+    dst_dir: impl AsRef<Path>,
+    target_ext: &str,
+    quality: i32,
+) -> Result<usize, IoError> {
+    let (src_dir, dst_dir) = (src_dir.as_ref(), dst_dir.as_ref());
+
+    let file_paths = list_dir_files(src_dir)?;
+
+    let format = image::ImageFormat::from_extension(target_ext)
+        .filter(|format| format.writing_enabled())
+        .ok_or_else(|| IoError::InvalidFileExtension(target_ext.into()))?;
+
+    std::fs::create_dir_all(dst_dir)?;
+
+    let mut num_converted = 0;
+
+    for file_path in file_paths {
+        let image = match read_image_any_rgb8(&file_path) {
+            Ok(image) => image,
+            Err(err) => {
+                log::debug!("skipping {}: {err}", file_path.display());
+                continue;
+            }
+        };
+
+        let Some(file_stem) = file_path.file_stem() else {
+            continue;
+        };
+        // append the extension to keep the dotted parts of the stem, e.g. `img.v1`
+        let dst_path = dst_dir.join(format!("{}.{target_ext}", file_stem.to_string_lossy()));
+
+        let (width, height) = (image.width() as u32, image.height() as u32);
+        if format == image::ImageFormat::Jpeg {
+            let writer = std::io::BufWriter::new(std::fs::File::create(&dst_path)?);
+            image::codecs::jpeg::JpegEncoder::new_with_quality(writer, quality.clamp(1, 100) as u8)
+                .encode(
+                    image.as_slice(),
+                    width,
+                    height,
+                    image::ExtendedColorType::Rgb8,
+                )?;
+        } else {
+            image::save_buffer_with_format(
+                &dst_path,
+                image.as_slice(),
+                width,
+                height,
+                image::ExtendedColorType::Rgb8,
+                format,
+            )?;
+        }
+
+        num_converted += 1;
+    }
+
+    Ok(num_converted)
+}
+
+// list the regular files of a directory sorted by path, to visit them in a deterministic order
+fn list_dir_files(dir: &Path) -> Result<Vec<std::path::PathBuf>, IoError> {
+    if !dir.exists() {
+        return Err(IoError::FileDoesNotExist(dir.to_path_buf()));
+    }
+
+    let mut file_paths = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    file_paths.retain(|path| path.is_file());
+    file_paths.sort();
+
+    Ok(file_paths)
+}
+
+/// The path of an image file and the result of decoding it.
+pub type ImageDirEntry = (std::path::PathBuf, Result<Image<u8, 3>, IoError>);
+
+/// Reads all the images of a directory with the given extensions.
+///
+/// Each file of `dir` whose extension matches one of `extensions`, ignoring
+/// the case, is decoded as RGB with [`read_image_any_rgb8`]. The failure to
+/// decode a file is returned with its path instead of aborting the whole
+/// batch. The subdirectories are not visited and a directory that does not
+/// exist or cannot be listed gives no images.
+///
+/// # Arguments
+///
+/// * `dir` - The directory with the images.
+/// * `extensions` - The extensions of the files to read without the dot, e.g. `["jpg", "png"]`.
+///
+/// # Returns
+///
+/// The path of each matching file with its decoded image, sorted by path.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_io::functional as F;
+///
+/// for (path, image) in F::read_images_from_dir("dataset", &["jpg", "png"]) {
+///     println!("{}: {}", path.display(), image.is_ok());
+/// }
+/// ```
+pub fn read_images_from_dir(dir: impl AsRef<Path>, extensions: &[&str]) -> Vec<ImageDirEntry> {
+    let dir = dir.as_ref();
+
+    let file_paths = match list_dir_files(dir) {
+        Ok(file_paths) => file_paths,
+        Err(err) => {
+            log::debug!("cannot list {}: {err}", dir.display());
+            return Vec::new();
+        }
+    };
+
+    file_paths
+        .into_iter()
+        .filter(|path| {
+            path.extension().is_some_and(|ext| {
+                extensions
+                    .iter()
+                    .any(|wanted| ext.eq_ignore_ascii_case(wanted))
+            })
+        })
+        .map(|file_path| {
+            let image = read_image_any_rgb8(&file_path);
+            (file_path, image)
+        })
+        .collect()
+}
+
+/// Counts the number of frames or pages of an image file without decoding the pixels.
+///
+/// The format is detected from the magic bytes of the file:
+///
+/// * GIF: the number of image descriptors.
+/// * PNG: the number of frames of an animated PNG (APNG), 1 otherwise.
+/// * TIFF: the number of image file directories (pages).
+/// * JPEG: the number of images of a multi-picture object (MPO), 1 otherwise.
+///
+/// Any other image format supported by the image crate counts as a single frame.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the image.
+///
+/// # Returns
+///
+/// The number of frames of the image.
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::functional as F;
+///
+/// let num_frames = F::count_frames("../../tests/data/dog.jpeg").unwrap();
+///
+/// assert_eq!(num_frames, 1);
+/// ```
+pub fn count_frames(file_path: impl AsRef<Path>) -> Result<usize, IoError> {
+    let file_path = file_path.as_ref().to_owned();
+
+    // verify the file exists
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    let data = std::fs::read(file_path)?;
+
+    if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        count_gif_frames(&data)
+    } else if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        count_png_frames(&data)
+    } else if data.starts_with(b"II") || data.starts_with(b"MM") {
+        count_tiff_pages(&data)
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        count_jpeg_images(&data)
+    } else {
+        // fail on unknown formats
+        image::guess_format(&data)?;
+        Ok(1)
+    }
+}
+
+// counts the image descriptors of a GIF stream
+fn count_gif_frames(data: &[u8]) -> Result<usize, IoError> {
+    let truncated = || IoError::CorruptImageData("truncated GIF data".to_string());
+    let byte_at = |pos: usize| data.get(pos).copied().ok_or_else(truncated);
+
+    // skip a sequence of data sub-blocks ending with an empty block
+    let skip_sub_blocks = |mut pos: usize| -> Result<usize, IoError> {
+        loop {
+            let len = byte_at(pos)? as usize;
+            pos += 1 + len;
+            if len == 0 {
+                return Ok(pos);
+            }
+        }
+    };
+
+    // skip the header and the logical screen descriptor with its global color table
+    let flags = byte_at(10)?;
+    let mut pos = 13;
+    if flags & 0x80 != 0 {
+        pos += 3 << ((flags & 0x07) + 1);
+    }
+
+    let mut num_frames = 0;
+    loop {
+        match byte_at(pos)? {
+            // image descriptor, the local color table and the image data
+            0x2C => {
+                num_frames += 1;
+                let flags = byte_at(pos + 9)?;
+                pos += 10;
+                if flags & 0x80 != 0 {
+                    pos += 3 << ((flags & 0x07) + 1);
+                }
+                pos = skip_sub_blocks(pos + 1)?;
+            }
+            // extension block
+            0x21 => pos = skip_sub_blocks(pos + 2)?,
+            // trailer
+            0x3B => break,
+            block => {
+                return Err(IoError::CorruptImageData(format!(
+                    "unknown GIF block 0x{block:02X}"
+                )))
+            }
+        }
+    }
+
+    Ok(num_frames)
+}
+
+// reads the number of frames from the animation control chunk of a PNG stream
+fn count_png_frames(data: &[u8]) -> Result<usize, IoError> {
+    let mut pos = 8;
+    while let Some(header) = data.get(pos..pos + 8) {
+        let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        match &header[4..8] {
+            b"acTL" => {
+                let num_frames = data
+                    .get(pos + 8..pos + 12)
+                    .ok_or_else(|| IoError::CorruptImageData("truncated acTL chunk".to_string()))?;
+                return Ok(u32::from_be_bytes([
+                    num_frames[0],
+                    num_frames[1],
+                    num_frames[2],
+                    num_frames[3],
+                ]) as usize);
+            }
+            // the animation control must precede the image data
+            b"IDAT" | b"IEND" => return Ok(1),
+            _ => pos += 12 + len,
+        }
+    }
+
+    Err(IoError::CorruptImageData("truncated PNG data".to_string()))
+}
+
+// counts the image file directories of a TIFF stream
+fn count_tiff_pages(data: &[u8]) -> Result<usize, IoError> {
+    let truncated = || IoError::CorruptImageData("truncated TIFF data".to_string());
+    let bytes = |pos: usize, n: usize| {
+        pos.checked_add(n)
+            .and_then(|end| data.get(pos..end))
+            .ok_or_else(truncated)
+    };
+
+    let big_endian = match bytes(0, 2)? {
+        b"II" => false,
+        b"MM" => true,
+        _ => {
+            return Err(IoError::CorruptImageData(
+                "invalid TIFF byte order".to_string(),
+            ))
+        }
+    };
+    let read_uint = |pos: usize, n: usize| -> Result<usize, IoError> {
+        let value = bytes(pos, n)?
+            .iter()
+            .enumerate()
+            .fold(0u64, |acc, (i, &b)| {
+                let shift = if big_endian { 8 * (n - 1 - i) } else { 8 * i };
+                acc | (b as u64) << shift
+            });
+        usize::try_from(value).map_err(|_| truncated())
+    };
+
+    // classic TIFF uses 32-bit offsets and BigTIFF 64-bit offsets
+    let (mut offset, count_size, entry_size, offset_size) = match read_uint(2, 2)? {
+        42 => (read_uint(4, 4)?, 2, 12, 4),
+        43 => (read_uint(8, 8)?, 8, 20, 8),
+        version => {
+            return Err(IoError::CorruptImageData(format!(
+                "invalid TIFF version {version}"
+            )))
+        }
+    };
+
+    let mut num_pages = 0;
+    while offset != 0 {
+        num_pages += 1;
+        // guard against cycles in the chain of directories
+        if num_pages > data.len() / (count_size + offset_size) {
+            return Err(IoError::CorruptImageData(
+                "cyclic TIFF directories".to_string(),
+            ));
+        }
+        let num_entries = read_uint(offset, count_size)?;
+        // the offset of the next directory follows the entries
+        let next = num_entries
+            .checked_mul(entry_size)
+            .and_then(|len| len.checked_add(offset + count_size))
+            .ok_or_else(|| {
+                IoError::CorruptImageData(format!("invalid TIFF directory size {num_entries}"))
+            })?;
+        offset = read_uint(next, offset_size)?;
+    }
+
+    Ok(num_pages)
+}
+
+// reads the number of images from the multi-picture format index of a JPEG stream
+fn count_jpeg_images(data: &[u8]) -> Result<usize, IoError> {
+    let segments = read_jpeg_segments(data).map_err(IoError::CorruptImageData)?;
+
+    let Some(mpf) = segments.iter().find(|segment| segment.is_mpf()) else {
+        return Ok(1);
+    };
+
+    // the MP index is a TIFF structure after the MPF identifier
+    let tiff = &mpf.payload()[4..];
+    let truncated = || IoError::CorruptImageData("truncated MPF data".to_string());
+    let big_endian = tiff.starts_with(b"MM");
+    let read_uint = |pos: usize, n: usize| -> Result<usize, IoError> {
+        let bytes = tiff.get(pos..pos + n).ok_or_else(truncated)?;
+        let value = bytes.iter().enumerate().fold(0usize, |acc, (i, &b)| {
+            let shift = if big_endian { 8 * (n - 1 - i) } else { 8 * i };
+            acc | (b as usize) << shift
+        });
+        Ok(value)
+    };
+
+    // look up the NumberOfImages tag in the MP index directory
+    let ifd = read_uint(4, 4)?;
+    for i in 0..read_uint(ifd, 2)? {
+        let entry = ifd + 2 + i * 12;
+        if read_uint(entry, 2)? == 0xB001 {
+            return read_uint(entry + 8, 4);
+        }
+    }
+
+    Ok(1)
+}
+
+/// The metadata of an image file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageInfo {
+    /// The width of the image in pixels.
+    pub width: usize,
+    /// The height of the image in pixels.
+    pub height: usize,
+    /// The encoding format of the file.
+    pub format: image::ImageFormat,
+    /// The number of channels stored in the file, e.g. 1 for palette images.
+    pub channels: usize,
+    /// The number of bits per channel sample.
+    pub bit_depth: u8,
 }
 
-/// Reads a RGB8 image from the given file path.
+/// Reads the size, format and sample layout of an image file without decoding the pixels.
 ///
-/// The method tries to read from any image format supported by the image crate.
+/// The format is detected from the magic bytes of the file. JPEG and PNG
+/// files are probed by parsing the frame header (SOF) and the `IHDR` chunk
+/// respectively, and the other formats supported by the image crate by
+/// reading their header only.
 ///
 /// # Arguments
 ///
-/// * `file_path` - The path to the image.
+/// * `file_path` - The path to the image file.
 ///
 /// # Returns
 ///
-/// A tensor image containing the image data in RGB8 format with shape (H, W, 3).
+/// The metadata of the image.
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, has an unknown format or a
+/// malformed header.
 ///
 /// # Example
 ///
 /// ```
-/// use kornia_image::Image;
 /// use kornia_io::functional as F;
 ///
-/// let image: Image<u8, 3> = F::read_image_any_rgb8("../../tests/data/dog.jpeg").unwrap();
+/// let info = F::probe_image("../../tests/data/dog.jpeg").unwrap();
 ///
-/// assert_eq!(image.cols(), 258);
-/// assert_eq!(image.rows(), 195);
-/// assert_eq!(image.num_channels(), 3);
+/// assert_eq!((info.width, info.height), (258, 195));
+/// assert_eq!(info.format, image::ImageFormat::Jpeg);
+/// assert_eq!(info.channels, 3);
+/// assert_eq!(info.bit_depth, 8);
 /// ```
-pub fn read_image_any_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
+pub fn probe_image(file_path: impl AsRef<Path>) -> Result<ImageInfo, IoError> {
     let file_path = file_path.as_ref().to_owned();
 
     // verify the file exists
@@ -111,34 +1156,195 @@ pub fn read_image_any_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>,
         return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
     }
 
-    // open the file and map it to memory
-    let jpeg_data = std::fs::read(file_path)?;
+    let data = std::fs::read(file_path)?;
 
-    // decode the data directly from memory
-    let img = image::ImageReader::new(std::io::Cursor::new(&jpeg_data))
-        .with_guessed_format()?
-        .decode()?;
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        probe_png(&data)
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        probe_jpeg(&data)
+    } else {
+        use image::ImageDecoder;
 
-    // TODO: handle more image formats
-    // return the image data
-    let image = Image::new(
-        ImageSize {
-            width: img.width() as usize,
-            height: img.height() as usize,
-        },
-        img.to_rgb8().to_vec(),
-    )?;
+        let reader = image::ImageReader::new(std::io::Cursor::new(&data)).with_guessed_format()?;
+        let format = reader
+            .format()
+            .ok_or_else(|| IoError::CorruptImageData("unknown image format".to_string()))?;
+        let decoder = reader.into_decoder()?;
+        let (width, height) = decoder.dimensions();
+        let color_type = decoder.color_type();
+        let channels = color_type.channel_count();
 
-    Ok(image)
+        Ok(ImageInfo {
+            width: width as usize,
+            height: height as usize,
+            format,
+            channels: channels as usize,
+            bit_depth: (color_type.bits_per_pixel() / channels as u16) as u8,
+        })
+    }
+}
+
+// reads the metadata of a PNG stream from its IHDR chunk
+fn probe_png(data: &[u8]) -> Result<ImageInfo, IoError> {
+    let ihdr = data
+        .get(8..33)
+        .filter(|chunk| &chunk[4..8] == b"IHDR")
+        .ok_or_else(|| IoError::CorruptImageData("missing PNG IHDR chunk".to_string()))?;
+
+    let read_u32 =
+        |pos: usize| u32::from_be_bytes([ihdr[pos], ihdr[pos + 1], ihdr[pos + 2], ihdr[pos + 3]]);
+
+    let channels = match ihdr[17] {
+        0 | 3 => 1,
+        2 => 3,
+        4 => 2,
+        6 => 4,
+        color_type => {
+            return Err(IoError::CorruptImageData(format!(
+                "invalid PNG color type {color_type}"
+            )))
+        }
+    };
+
+    Ok(ImageInfo {
+        width: read_u32(8) as usize,
+        height: read_u32(12) as usize,
+        format: image::ImageFormat::Png,
+        channels,
+        bit_depth: ihdr[16],
+    })
+}
+
+// reads the metadata of a JPEG stream from its frame header (SOF)
+fn probe_jpeg(data: &[u8]) -> Result<ImageInfo, IoError> {
+    let segments = read_jpeg_segments(data).map_err(IoError::CorruptImageData)?;
+
+    // the start of frame markers, excluding DHT, JPG and DAC
+    let sof = segments
+        .iter()
+        .find(|segment| {
+            matches!(segment.marker, 0xC0..=0xCF) && !matches!(segment.marker, 0xC4 | 0xC8 | 0xCC)
+        })
+        .map(|segment| segment.payload())
+        .filter(|payload| payload.len() >= 6)
+        .ok_or_else(|| IoError::CorruptImageData("missing JPEG frame header".to_string()))?;
+
+    Ok(ImageInfo {
+        width: u16::from_be_bytes([sof[3], sof[4]]) as usize,
+        height: u16::from_be_bytes([sof[1], sof[2]]) as usize,
+        format: image::ImageFormat::Jpeg,
+        channels: sof[5] as usize,
+        bit_depth: sof[0],
+    })
 }
 
 pub use crate::png::{
+    read_image_png_rgba8,
     write_image_png_gray8,
     write_image_png_rgb8,
     write_image_png_rgba8, 
     write_image_png_gray16
 };
 
+/// Check whether an RGBA image has any pixel that is not fully opaque.
+///
+/// Many RGBA images have an alpha channel where every pixel is opaque (255),
+/// in which case the alpha channel can be dropped safely and the image can be
+/// handled as RGB.
+///
+/// # Arguments
+///
+/// * `image` - The RGBA image with shape (H, W, 4).
+///
+/// # Returns
+///
+/// `true` if any pixel has an alpha value below 255, `false` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::functional as F;
+///
+/// let image = Image::<u8, 4>::from_size_val(ImageSize { width: 2, height: 2 }, 255).unwrap();
+///
+/// assert!(!F::has_real_transparency(&image));
+/// ```
+pub fn has_real_transparency(image: &Image<u8, 4>) -> bool {
+    image.as_slice().chunks_exact(4).any(|px| px[3] < 255)
+}
+
+/// Extracts the embedded thumbnail of a JPEG stream from its EXIF data.
+///
+/// Cameras and phones usually store a small JPEG preview in the second image
+/// directory (IFD1) of the EXIF block, which is much faster to decode than
+/// the full image, e.g. for gallery views.
+///
+/// # Arguments
+///
+/// * `jpeg_data` - The JPEG encoded image.
+///
+/// # Returns
+///
+/// The JPEG encoded thumbnail, or `None` if the stream has no EXIF thumbnail
+/// or its EXIF data is malformed.
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::functional as F;
+///
+/// let jpeg_data = std::fs::read("../../tests/data/dog.jpeg").unwrap();
+///
+/// assert!(F::extract_exif_thumbnail(&jpeg_data).is_none());
+/// ```
+pub fn extract_exif_thumbnail(jpeg_data: &[u8]) -> Option<Vec<u8>> {
+    let segments = read_jpeg_segments(jpeg_data).ok()?;
+    let exif = segments.iter().find(|segment| segment.is_exif())?;
+
+    // the EXIF data is a TIFF structure after the Exif identifier
+    let tiff = &exif.payload()[6..];
+    let big_endian = match tiff.get(..2)? {
+        b"II" => false,
+        b"MM" => true,
+        _ => return None,
+    };
+    let read_uint = |pos: usize, n: usize| -> Option<usize> {
+        let bytes = tiff.get(pos..pos.checked_add(n)?)?;
+        let value = bytes.iter().enumerate().fold(0usize, |acc, (i, &b)| {
+            let shift = if big_endian { 8 * (n - 1 - i) } else { 8 * i };
+            acc | (b as usize) << shift
+        });
+        Some(value)
+    };
+
+    // the thumbnail directory (IFD1) follows the main image directory (IFD0)
+    let ifd0 = read_uint(4, 4)?;
+    let ifd1 = read_uint(ifd0 + 2 + read_uint(ifd0, 2)? * 12, 4)?;
+    if ifd1 == 0 {
+        return None;
+    }
+
+    // look up the JPEGInterchangeFormat and JPEGInterchangeFormatLength tags
+    let (mut offset, mut length) = (None, None);
+    for i in 0..read_uint(ifd1, 2)? {
+        let entry = ifd1 + 2 + i * 12;
+        match read_uint(entry, 2)? {
+            0x0201 => offset = Some(read_uint(entry + 8, 4)?),
+            0x0202 => length = Some(read_uint(entry + 8, 4)?),
+            _ => {}
+        }
+    }
+
+    let (offset, length) = (offset?, length?);
+    let thumbnail = tiff.get(offset..offset.checked_add(length)?)?;
+    if !thumbnail.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+
+    Some(thumbnail.to_vec())
+}
+
 /// Reads a grayscale (gray8) image from a JPEG file using TurboJPEG.
 ///
 /// # Arguments
@@ -225,6 +1431,80 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn read_any_routing() -> Result<(), IoError> {
+        // the png is decoded with the image crate
+        let image = read_image_any_rgb8("../../tests/data/dog.png")?;
+        let expected = image::open("../../tests/data/dog.png")?.to_rgb8();
+        assert_eq!(image.cols(), expected.width() as usize);
+        assert_eq!(image.rows(), expected.height() as usize);
+        assert_eq!(image.as_slice(), expected.as_raw().as_slice());
+
+        #[cfg(feature = "turbojpeg")]
+        {
+            let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+            assert!(super::is_jpeg(&jpeg_data));
+            assert!(!super::is_jpeg(&std::fs::read("../../tests/data/dog.png")?));
+
+            // the jpeg is decoded with turbojpeg
+            let image = read_image_any_rgb8("../../tests/data/dog.jpeg")?;
+            let expected = read_image_jpegturbo_rgb8("../../tests/data/dog.jpeg")?;
+            assert_eq!(image.size(), expected.size());
+            assert_eq!(image.as_slice(), expected.as_slice());
+
+            // a corrupt jpeg falls back to the image crate, which fails too
+            let tmp_dir = tempfile::tempdir()?;
+            let file_path = tmp_dir.path().join("corrupt.jpeg");
+            std::fs::write(&file_path, &jpeg_data[..64])?;
+            assert!(read_image_any_rgb8(&file_path).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn read_any_mmap() -> Result<(), IoError> {
+        let image = super::read_image_any_rgb8_mmap("../../tests/data/dog.jpeg")?;
+        let image_read = read_image_any_rgb8("../../tests/data/dog.jpeg")?;
+        assert_eq!(image.size(), image_read.size());
+        assert_eq!(image.as_slice(), image_read.as_slice());
+
+        let result = super::read_image_any_rgb8_mmap("../../tests/data/missing.jpeg");
+        assert!(matches!(result, Err(IoError::FileDoesNotExist(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rgb8_to_gray8() -> Result<(), IoError> {
+        use kornia_image::{Image, ImageSize};
+
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let red = Image::<u8, 3>::new(size, [255, 0, 0].repeat(size.width * size.height))?;
+
+        // 0.299 * 255 = 76.245
+        let gray = super::rgb8_to_gray8(&red);
+        assert_eq!(gray.size(), size);
+        assert!(gray.as_slice().iter().all(|&v| v == 76));
+
+        let white = Image::<u8, 3>::from_size_val(size, 255)?;
+        assert!(super::rgb8_to_gray8(&white)
+            .as_slice()
+            .iter()
+            .all(|&v| v == 255));
+
+        let image = super::read_image_any_gray8_luminance("../../tests/data/dog.jpeg")?;
+        assert_eq!(image.cols(), 258);
+        assert_eq!(image.rows(), 195);
+        assert_eq!(image.num_channels(), 1);
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "turbojpeg")]
     fn read_jpeg() -> Result<(), IoError> {
@@ -234,6 +1514,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "turbojpeg")]
+    fn read_jpeg_thumbnail() -> Result<(), IoError> {
+        let image = read_image_jpegturbo_rgb8("../../tests/data/dog.jpeg")?;
+
+        let thumbnail = super::read_jpeg_thumbnail_rgb8("../../tests/data/dog.jpeg", 128)?;
+        assert!(thumbnail.cols().max(thumbnail.rows()) <= 128);
+        assert!(thumbnail.cols().max(thumbnail.rows()) > 64);
+
+        // the aspect ratio is preserved up to the rounding of the scaled size
+        let ratio = image.cols() as f32 / image.rows() as f32;
+        let thumbnail_ratio = thumbnail.cols() as f32 / thumbnail.rows() as f32;
+        assert!((ratio - thumbnail_ratio).abs() < 0.05);
+
+        // a small image is not upscaled
+        let full = super::read_jpeg_thumbnail_rgb8("../../tests/data/dog.jpeg", 1024)?;
+        assert_eq!(full.size(), image.size());
+        assert_eq!(full.as_slice(), image.as_slice());
+
+        // the smallest scale is used when no scale fits
+        let tiny = super::read_jpeg_thumbnail_rgb8("../../tests/data/dog.jpeg", 8)?;
+        assert_eq!((tiny.cols(), tiny.rows()), (33, 25));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "turbojpeg", feature = "tokio"))]
+    async fn read_jpeg_async() -> Result<(), IoError> {
+        let image = super::read_image_jpegturbo_rgb8_async("../../tests/data/dog.jpeg").await?;
+        assert_eq!(image.cols(), 258);
+        assert_eq!(image.rows(), 195);
+        assert_eq!(image.num_channels(), 3);
+
+        let result = super::read_image_jpegturbo_rgb8_async("../../tests/data/missing.jpeg").await;
+        assert!(matches!(result, Err(IoError::FileDoesNotExist(_))));
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "turbojpeg")]
     fn read_write_jpeg() -> Result<(), IoError> {
@@ -254,15 +1574,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "turbojpeg")]
+    fn encode_fidelity() -> Result<(), IoError> {
+        let image = read_image_jpegturbo_rgb8("../../tests/data/dog.jpeg")?;
+        super::assert_encode_fidelity(&image, 95, 35.0)?;
+
+        let result = super::assert_encode_fidelity(&image, 10, 60.0);
+        assert!(matches!(result, Err(IoError::EncodeFidelityError(_, _))));
+        Ok(())
+    }
+
     #[test]
     fn write_read_png_gray8() -> Result<(), IoError> {
         use kornia_image::{Image, ImageSize};
-        use std::path::PathBuf;
         use tempfile::tempdir;
-        // Convert to grayscale using the proper function
-        let mut image_gray = Image::<u8, 1>::from_size_val(image_rgb.size(), 0)?;
-        gray_from_rgb_u8(&image_rgb, &mut image_gray)?;
-        
+
         // Create a temporary directory for our test file
         let temp_dir = tempdir()?;
         let file_path = temp_dir.path().join("test_gray8.png");
@@ -289,19 +1616,403 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn read_png_rgba8() -> Result<(), IoError> {
+        use kornia_image::{Image, ImageSize};
+
+        let tmp_dir = tempfile::tempdir()?;
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+
+        // rgba source: the alpha channel is preserved
+        let file_path = tmp_dir.path().join("rgba.png");
+        let image = Image::<u8, 4>::new(size, vec![255, 0, 0, 255, 0, 255, 0, 0])?;
+        crate::png::write_image_png_rgba8(&file_path, &image)?;
+
+        let image_back = super::read_image_png_rgba8(&file_path)?;
+        assert_eq!(image_back.as_slice(), image.as_slice());
+        assert_eq!(image_back.get([0, 1, 3]), Some(&0));
+
+        // rgb source: the alpha channel is opaque
+        let file_path = tmp_dir.path().join("rgb.png");
+        let image = Image::<u8, 3>::new(size, vec![255, 0, 0, 0, 255, 0])?;
+        crate::png::write_image_png_rgb8(&file_path, &image)?;
+
+        let image_back = super::read_image_png_rgba8(&file_path)?;
+        assert_eq!(image_back.as_slice(), &[255, 0, 0, 255, 0, 255, 0, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn has_real_transparency() -> Result<(), IoError> {
+        use kornia_image::{Image, ImageSize};
+
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+
+        let mut image = Image::<u8, 4>::new(size, [10, 20, 30, 255].repeat(6))?;
+        assert!(!super::has_real_transparency(&image));
+
+        // a single pixel with partial transparency
+        image.as_slice_mut()[4 * 4 + 3] = 254;
+        assert!(super::has_real_transparency(&image));
+
+        Ok(())
+    }
+
+    #[test]
+    fn extract_exif_thumbnail() -> Result<(), Box<dyn std::error::Error>> {
+        use image::codecs::jpeg::JpegEncoder;
+
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        assert!(super::extract_exif_thumbnail(&jpeg_data).is_none());
+
+        // encode a 32x24 thumbnail of the image
+        let image = image::load_from_memory(&jpeg_data)?;
+        let mut thumbnail = Vec::new();
+        JpegEncoder::new(&mut thumbnail).encode_image(&image.thumbnail_exact(32, 24))?;
+
+        // a little endian TIFF structure with an empty IFD0 and an IFD1 with
+        // the offset and the length of the thumbnail
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend(8u32.to_le_bytes());
+        tiff.extend(0u16.to_le_bytes());
+        tiff.extend(14u32.to_le_bytes());
+        tiff.extend(2u16.to_le_bytes());
+        for (tag, value) in [(0x0201u16, 44u32), (0x0202, thumbnail.len() as u32)] {
+            tiff.extend(tag.to_le_bytes());
+            tiff.extend(4u16.to_le_bytes());
+            tiff.extend(1u32.to_le_bytes());
+            tiff.extend(value.to_le_bytes());
+        }
+        tiff.extend(0u32.to_le_bytes());
+        tiff.extend(&thumbnail);
+
+        // insert the EXIF block as an APP1 segment after the SOI marker
+        let mut app1 = vec![0xFF, 0xE1];
+        app1.extend((2 + 6 + tiff.len() as u16).to_be_bytes());
+        app1.extend(b"Exif\0\0");
+        app1.extend(tiff);
+
+        let mut with_exif = jpeg_data[..2].to_vec();
+        with_exif.extend(app1);
+        with_exif.extend(&jpeg_data[2..]);
+
+        let extracted = super::extract_exif_thumbnail(&with_exif).ok_or("missing thumbnail")?;
+        assert_eq!(extracted, thumbnail);
+
+        let decoded = image::load_from_memory(&extracted)?;
+        assert_eq!((decoded.width(), decoded.height()), (32, 24));
+        assert!(decoded.width() < image.width() && decoded.height() < image.height());
+
+        // a stream truncated within the thumbnail is ignored
+        let truncated = &with_exif[..2 + 4 + 6 + 44 + thumbnail.len() / 2];
+        assert!(super::extract_exif_thumbnail(truncated).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_any_native_channels() -> Result<(), IoError> {
+        use super::DynamicImageOut;
+        use kornia_image::{Image, ImageSize};
+
+        let tmp_dir = tempfile::tempdir()?;
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+
+        let file_path = tmp_dir.path().join("gray.png");
+        crate::png::write_image_png_gray8(&file_path, &Image::<u8, 1>::new(size, vec![10, 20])?)?;
+        match super::read_image_any(&file_path)? {
+            DynamicImageOut::Gray8(image) => assert_eq!(image.as_slice(), &[10, 20]),
+            _ => panic!("expected a grayscale image"),
+        }
+
+        let file_path = tmp_dir.path().join("rgb.png");
+        let rgb = Image::<u8, 3>::new(size, vec![1, 2, 3, 4, 5, 6])?;
+        crate::png::write_image_png_rgb8(&file_path, &rgb)?;
+        match super::read_image_any(&file_path)? {
+            DynamicImageOut::Rgb8(image) => assert_eq!(image.as_slice(), rgb.as_slice()),
+            _ => panic!("expected a rgb image"),
+        }
+
+        let file_path = tmp_dir.path().join("rgba.png");
+        let rgba = Image::<u8, 4>::new(size, vec![1, 2, 3, 4, 5, 6, 7, 8])?;
+        crate::png::write_image_png_rgba8(&file_path, &rgba)?;
+        match super::read_image_any(&file_path)? {
+            DynamicImageOut::Rgba8(image) => assert_eq!(image.as_slice(), rgba.as_slice()),
+            _ => panic!("expected a rgba image"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_all_formats() -> Result<(), IoError> {
+        use image::ImageFormat;
+
+        let tmp_dir = tempfile::tempdir()?;
+        let image = read_image_any_rgb8("../../tests/data/dog.jpeg")?;
+
+        let results = super::roundtrip_all_formats(&image, tmp_dir.path())?;
+        assert!(results.len() >= 2);
+
+        for (format, psnr) in results {
+            match format {
+                ImageFormat::Jpeg => assert!(psnr > 30.0, "psnr: {psnr}"),
+                _ => assert_eq!(psnr, f64::INFINITY, "format: {format:?}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_directory() -> Result<(), IoError> {
+        let src_dir = tempfile::tempdir()?;
+        let dst_dir = tempfile::tempdir()?;
+
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        for name in ["a.jpg", "b.jpeg", "c.jpg"] {
+            std::fs::write(src_dir.path().join(name), &jpeg_data)?;
+        }
+        std::fs::write(src_dir.path().join("notes.txt"), b"not an image")?;
+
+        // the dotted stems must not collide on the same output file
+        for name in ["img.v1.jpg", "img.v2.jpg"] {
+            std::fs::write(src_dir.path().join(name), &jpeg_data)?;
+        }
+
+        let dst_path = dst_dir.path().join("converted");
+        let num_converted = super::convert_directory(src_dir.path(), &dst_path, "png", 90)?;
+        assert_eq!(num_converted, 5);
+
+        for name in ["a.png", "b.png", "c.png", "img.v1.png", "img.v2.png"] {
+            let image = crate::png::read_image_png_rgb8(dst_path.join(name))?;
+            assert_eq!(image.cols(), 258);
+            assert_eq!(image.rows(), 195);
+        }
+        assert!(!dst_path.join("notes.png").exists());
+
+        let result = super::convert_directory(src_dir.path(), &dst_path, "xyz", 90);
+        assert!(matches!(result, Err(IoError::InvalidFileExtension(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_images_from_dir() -> Result<(), IoError> {
+        let dir = tempfile::tempdir()?;
+
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        std::fs::write(dir.path().join("b.jpg"), &jpeg_data)?;
+        std::fs::write(dir.path().join("a.JPEG"), &jpeg_data)?;
+        std::fs::copy("../../tests/data/dog.png", dir.path().join("c.png"))?;
+        std::fs::write(dir.path().join("broken.jpg"), b"not an image")?;
+        std::fs::write(dir.path().join("notes.txt"), b"not an image")?;
+        std::fs::create_dir(dir.path().join("nested.jpg"))?;
+
+        let entries = super::read_images_from_dir(dir.path(), &["jpg", "jpeg"]);
+        let names = entries
+            .iter()
+            .map(|(path, _)| path.file_name().and_then(|name| name.to_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(names, [Some("a.JPEG"), Some("b.jpg"), Some("broken.jpg")]);
+
+        for (_, image) in &entries[..2] {
+            assert!(matches!(image, Ok(image) if image.cols() == 258 && image.rows() == 195));
+        }
+        assert!(entries[2].1.is_err());
+
+        let entries = super::read_images_from_dir(dir.path(), &["png"]);
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].1.is_ok());
+
+        assert!(super::read_images_from_dir(dir.path().join("missing"), &["jpg"]).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn probe_image() -> Result<(), IoError> {
+        use super::ImageInfo;
+        use image::ImageFormat;
+        use kornia_image::{Image, ImageSize};
+
+        let info = super::probe_image("../../tests/data/dog.jpeg")?;
+        assert_eq!(
+            info,
+            ImageInfo {
+                width: 258,
+                height: 195,
+                format: ImageFormat::Jpeg,
+                channels: 3,
+                bit_depth: 8,
+            }
+        );
+
+        let info = super::probe_image("../../tests/data/dog.png")?;
+        assert_eq!((info.width, info.height), (258, 195));
+        assert_eq!(info.format, ImageFormat::Png);
+        assert_eq!((info.channels, info.bit_depth), (1, 8));
+
+        let tmp_dir = tempfile::tempdir()?;
+        let size = ImageSize {
+            width: 5,
+            height: 3,
+        };
+
+        let file_path = tmp_dir.path().join("depth.png");
+        crate::png::write_image_png_gray16(&file_path, &Image::<u16, 1>::from_size_val(size, 9)?)?;
+        let info = super::probe_image(&file_path)?;
+        assert_eq!((info.width, info.height), (5, 3));
+        assert_eq!((info.channels, info.bit_depth), (1, 16));
+
+        // other formats are probed from their header by the image crate
+        let file_path = tmp_dir.path().join("image.bmp");
+        image::RgbaImage::new(5, 3).save(&file_path)?;
+        let info = super::probe_image(&file_path)?;
+        assert_eq!((info.width, info.height), (5, 3));
+        assert_eq!(info.format, ImageFormat::Bmp);
+        assert_eq!((info.channels, info.bit_depth), (4, 8));
+
+        let file_path = tmp_dir.path().join("garbage.bin");
+        std::fs::write(&file_path, b"not an image")?;
+        assert!(super::probe_image(&file_path).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn count_frames() -> Result<(), IoError> {
+        use kornia_image::{Image, ImageSize};
+
+        let tmp_dir = tempfile::tempdir()?;
+
+        // a 1x1 gif with two frames, each with a graphic control extension
+        let frame = [
+            0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00, 0x00, // graphic control extension
+            0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x80, // image descriptor
+            0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, // local color table
+            0x02, 0x02, 0x44, 0x01, 0x00, // image data
+        ];
+        let mut gif = b"GIF89a\x01\x00\x01\x00\x00\x00\x00".to_vec();
+        gif.extend_from_slice(&frame);
+        gif.extend_from_slice(&frame);
+        gif.push(0x3B);
+
+        let file_path = tmp_dir.path().join("animated.gif");
+        std::fs::write(&file_path, gif)?;
+        assert_eq!(super::count_frames(&file_path)?, 2);
+
+        // an animated png with three frames
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let frames = (0..3)
+            .map(|i| Ok((Image::<u8, 4>::from_size_val(size, i * 50)?, 10)))
+            .collect::<Result<Vec<_>, IoError>>()?;
+        let file_path = tmp_dir.path().join("animated.png");
+        crate::png::write_apng_rgba8(&file_path, &frames, 0)?;
+        assert_eq!(super::count_frames(&file_path)?, 3);
+
+        // a tiff with two empty pages
+        let tiff = [
+            b'I', b'I', 42, 0, 8, 0, 0, 0, // header
+            0, 0, 14, 0, 0, 0, // first directory
+            0, 0, 0, 0, 0, 0, // second directory
+        ];
+        let file_path = tmp_dir.path().join("pages.tiff");
+        std::fs::write(&file_path, tiff)?;
+        assert_eq!(super::count_frames(&file_path)?, 2);
+
+        // a bigtiff with a directory offset out of the file
+        let mut tiff = vec![b'I', b'I', 43, 0, 8, 0, 0, 0];
+        tiff.extend_from_slice(&(u64::MAX - 4).to_le_bytes());
+        std::fs::write(&file_path, tiff)?;
+        assert!(matches!(
+            super::count_frames(&file_path),
+            Err(IoError::CorruptImageData(_))
+        ));
+
+        // a jpeg with a multi-picture format index of three images
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE2, 0x00, 0x20];
+        jpeg.extend_from_slice(b"MPF\0");
+        jpeg.extend_from_slice(&[
+            b'I', b'I', 42, 0, 8, 0, 0, 0, // header
+            1, 0, // number of entries
+            0x01, 0xB0, 4, 0, 1, 0, 0, 0, 3, 0, 0, 0, // NumberOfImages
+            0, 0, 0, 0, // next directory
+        ]);
+        jpeg.extend_from_slice(&[0xFF, 0xD9]);
+        let file_path = tmp_dir.path().join("multi.mpo");
+        std::fs::write(&file_path, jpeg)?;
+        assert_eq!(super::count_frames(&file_path)?, 3);
+
+        // single image formats
+        assert_eq!(super::count_frames("../../tests/data/dog.jpeg")?, 1);
+        assert_eq!(super::count_frames("../../tests/data/dog.png")?, 1);
+
+        assert!(super::count_frames("../../tests/data/missing.gif").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "turbojpeg")]
+    fn cached_jpeg_reader() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("dog.jpeg");
+        let other_path = tmp_dir.path().join("other.jpeg");
+        std::fs::copy("../../tests/data/dog.jpeg", &file_path)?;
+        std::fs::copy("../../tests/data/dog.jpeg", &other_path)?;
+
+        let mut reader = super::CachedJpegReader::new(1)?;
+        assert!(reader.is_empty());
+
+        // the second read is served from the cache
+        let image = reader.read_rgb8(&file_path)?;
+        let cached = reader.read_rgb8(&file_path)?;
+        assert_eq!(cached.as_slice(), image.as_slice());
+        assert_eq!(reader.num_decodes(), 1);
+        assert_eq!(reader.len(), 1);
+
+        // a modified file is decoded again
+        let mtime = std::fs::metadata(&file_path)?.modified()?;
+        std::fs::File::options()
+            .write(true)
+            .open(&file_path)?
+            .set_modified(mtime + std::time::Duration::from_secs(1))?;
+        reader.read_rgb8(&file_path)?;
+        assert_eq!(reader.num_decodes(), 2);
+
+        // the least recently used image is evicted
+        reader.read_rgb8(&other_path)?;
+        reader.read_rgb8(&file_path)?;
+        assert_eq!(reader.num_decodes(), 4);
+        assert_eq!(reader.len(), 1);
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "turbojpeg")]
     fn read_write_jpeg_gray() -> Result<(), IoError> {
-        use kornia_image::{Image, ImageSize};
-        use std::path::PathBuf;
         use tempfile::tempdir;
         
         // First, read an RGB image
         let image_rgb = super::read_image_jpegturbo_rgb8("../../tests/data/dog.jpeg")?;
         
         // Convert to grayscale using the proper function
-        let mut image_gray = Image::<u8, 1>::from_size_val(image_rgb.size(), 0)?;
-        gray_from_rgb_u8(&image_rgb, &mut image_gray)?;
+        let image_gray = super::rgb8_to_gray8(&image_rgb);
         
         // Create a temporary directory for our test file
         let temp_dir = tempdir()?;
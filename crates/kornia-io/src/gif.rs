@@ -0,0 +1,181 @@
+use std::{fs::File, path::Path};
+
+use gif::{ColorOutput, DecodeOptions, DisposalMethod};
+use kornia_image::{Image, ImageSize};
+
+use crate::error::IoError;
+
+/// Read all the frames of an (animated) GIF image as RGB8 images.
+///
+/// Each frame is composited onto the logical screen of the GIF honoring the
+/// disposal method of the previous frame, so every returned image is the full
+/// canvas as it would be displayed. Areas not covered by any frame are black.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the GIF file.
+///
+/// # Returns
+///
+/// A vector with each composited frame and its delay in centiseconds.
+pub fn read_gif_frames_rgb8(
+    file_path: impl AsRef<Path>,
+) -> Result<Vec<(Image<u8, 3>, u16)>, IoError> {
+    // verify the file exists
+    let file_path = file_path.as_ref();
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    // verify the file extension
+    if file_path
+        .extension()
+        .map_or(true, |ext| !ext.eq_ignore_ascii_case("gif"))
+    {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+
+    let mut options = DecodeOptions::new();
+    options.set_color_output(ColorOutput::RGBA);
+
+    let file = File::open(file_path)?;
+    let mut decoder = options.read_info(file)?;
+
+    let size = ImageSize {
+        width: decoder.width() as usize,
+        height: decoder.height() as usize,
+    };
+
+    // the canvas holds the composited RGBA pixels of the logical screen
+    let mut canvas = vec![0u8; size.width * size.height * 4];
+    let mut frames = Vec::new();
+
+    while let Some(frame) = decoder.read_next_frame()? {
+        let left = frame.left as usize;
+        let top = frame.top as usize;
+        let frame_width = frame.width as usize;
+        let frame_height = frame.height as usize;
+
+        // keep a copy of the canvas to restore it after this frame if requested
+        let previous = (frame.dispose == DisposalMethod::Previous).then(|| canvas.clone());
+
+        // draw the frame on top of the canvas skipping the transparent pixels,
+        // a frame without columns has nothing to draw
+        if frame_width > 0 {
+            for (y, row) in frame.buffer.chunks_exact(frame_width * 4).enumerate() {
+                let cy = top + y;
+                if cy >= size.height {
+                    break;
+                }
+                for (x, px) in row.chunks_exact(4).enumerate() {
+                    let cx = left + x;
+                    if cx >= size.width {
+                        break;
+                    }
+                    if px[3] != 0 {
+                        let offset = (cy * size.width + cx) * 4;
+                        canvas[offset..offset + 4].copy_from_slice(px);
+                    }
+                }
+            }
+        }
+
+        // snapshot the canvas as rgb
+        let rgb = canvas
+            .chunks_exact(4)
+            .flat_map(|px| [px[0], px[1], px[2]])
+            .collect::<Vec<_>>();
+        frames.push((Image::new(size, rgb)?, frame.delay));
+
+        // dispose the frame before drawing the next one
+        match frame.dispose {
+            DisposalMethod::Background => {
+                for cy in top..(top + frame_height).min(size.height) {
+                    let start = (cy * size.width + left.min(size.width)) * 4;
+                    let end = (cy * size.width + (left + frame_width).min(size.width)) * 4;
+                    canvas[start..end].fill(0);
+                }
+            }
+            DisposalMethod::Previous => {
+                if let Some(previous) = previous {
+                    canvas = previous;
+                }
+            }
+            DisposalMethod::Any | DisposalMethod::Keep => {}
+        }
+    }
+
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use crate::gif::read_gif_frames_rgb8;
+
+    // write a 4x4 animated gif with three frames using the global palette:
+    // 0 = red, 1 = green, 2 = blue
+    fn write_test_gif(file_path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+        let palette = [255, 0, 0, 0, 255, 0, 0, 0, 255];
+        let file = std::fs::File::create(file_path)?;
+        let mut encoder = gif::Encoder::new(file, 4, 4, &palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        let mut frame = gif::Frame::from_indexed_pixels(4, 4, vec![0; 16], None);
+        frame.delay = 10;
+        encoder.write_frame(&frame)?;
+
+        // green 2x2 patch at the top-left, cleared to background afterwards
+        let mut frame = gif::Frame::from_indexed_pixels(2, 2, vec![1; 4], None);
+        frame.delay = 20;
+        frame.dispose = gif::DisposalMethod::Background;
+        encoder.write_frame(&frame)?;
+
+        // blue 2x2 patch at the bottom-right
+        let mut frame = gif::Frame::from_indexed_pixels(2, 2, vec![2; 4], None);
+        frame.delay = 30;
+        frame.left = 2;
+        frame.top = 2;
+        encoder.write_frame(&frame)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_gif_frames() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("anim.gif");
+        write_test_gif(&file_path)?;
+
+        let frames = read_gif_frames_rgb8(&file_path)?;
+        assert_eq!(frames.len(), 3);
+
+        let delays = frames.iter().map(|(_, delay)| *delay).collect::<Vec<_>>();
+        assert_eq!(delays, vec![10, 20, 30]);
+
+        for (image, _) in frames.iter() {
+            assert_eq!(image.cols(), 4);
+            assert_eq!(image.rows(), 4);
+            assert_eq!(image.num_channels(), 3);
+        }
+
+        // second frame: green patch over the red background
+        let (frame, _) = &frames[1];
+        assert_eq!(frame.get([0, 0, 1]), Some(&255));
+        assert_eq!(frame.get([3, 3, 0]), Some(&255));
+
+        // third frame: green patch disposed to background, blue patch drawn
+        let (frame, _) = &frames[2];
+        assert_eq!(frame.get([0, 0, 1]), Some(&0));
+        assert_eq!(frame.get([0, 3, 0]), Some(&255));
+        assert_eq!(frame.get([3, 3, 2]), Some(&255));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_gif_invalid_extension() {
+        let result = read_gif_frames_rgb8("../../tests/data/dog.jpeg");
+        assert!(matches!(result, Err(IoError::InvalidFileExtension(_))));
+    }
+}
@@ -0,0 +1,110 @@
+use std::path::Path;
+
+use kornia_image::{Image, ImageSize};
+use libheif_rs::{ColorSpace, HeifContext, LibHeif, RgbChroma};
+
+use crate::error::IoError;
+
+/// Read a HEIF/HEIC image with three channels (rgb8).
+///
+/// The primary image of the container is decoded with libheif, e.g. the main
+/// photo of an iPhone HEIC file. The rotation and mirroring stored in the
+/// container are applied while decoding, so the image is returned in its
+/// display orientation. Sources with more than 8 bits per channel are
+/// converted to 8 bits and the alpha channel, if any, is ignored.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the HEIF/HEIC file.
+///
+/// # Returns
+///
+/// A RGB image with three channels (rgb8).
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, does not have a `heic` or
+/// `heif` extension or cannot be decoded.
+pub fn read_image_heif_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
+    let file_path = file_path.as_ref().to_owned();
+
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    if file_path.extension().map_or(true, |ext| {
+        !ext.eq_ignore_ascii_case("heic") && !ext.eq_ignore_ascii_case("heif")
+    }) {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+
+    let data = std::fs::read(&file_path)?;
+
+    let context = HeifContext::read_from_bytes(&data)?;
+    let handle = context.primary_image_handle()?;
+
+    // the default decoding options apply the transformations of the container
+    let image = LibHeif::new().decode(&handle, ColorSpace::Rgb(RgbChroma::Rgb), None)?;
+
+    let planes = image.planes();
+    let plane = planes
+        .interleaved
+        .ok_or_else(|| IoError::CorruptImageData("missing interleaved RGB plane".to_string()))?;
+
+    let (width, height) = (plane.width as usize, plane.height as usize);
+
+    // copy the rows skipping the padding at the end of each row
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in plane.data.chunks(plane.stride).take(height) {
+        pixels.extend_from_slice(&row[..width * 3]);
+    }
+
+    Ok(Image::new(ImageSize { width, height }, pixels)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use libheif_rs::{
+        Channel, ColorSpace, CompressionFormat, EncoderQuality, HeifContext, Image, LibHeif,
+        RgbChroma,
+    };
+
+    #[test]
+    fn read_heif() -> Result<(), Box<dyn std::error::Error>> {
+        let (width, height) = (64u32, 48u32);
+
+        // encode a small HEIC sample with a color gradient
+        let mut image = Image::new(width, height, ColorSpace::Rgb(RgbChroma::Rgb))?;
+        image.create_plane(Channel::Interleaved, width, height, 8)?;
+
+        let planes = image.planes_mut();
+        let plane = planes.interleaved.ok_or("missing interleaved plane")?;
+        for (y, row) in plane.data.chunks_mut(plane.stride).enumerate() {
+            for (x, px) in row[..width as usize * 3].chunks_exact_mut(3).enumerate() {
+                px.copy_from_slice(&[(x * 4) as u8, (y * 5) as u8, 128]);
+            }
+        }
+
+        let lib_heif = LibHeif::new();
+        let mut encoder = lib_heif.encoder_for_format(CompressionFormat::Hevc)?;
+        encoder.set_quality(EncoderQuality::Lossy(90))?;
+
+        let mut context = HeifContext::new()?;
+        context.encode_image(&image, &mut encoder, None)?;
+
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("sample.heic");
+        context.write_to_file(file_path.to_str().ok_or("invalid path")?)?;
+
+        let image = super::read_image_heif_rgb8(&file_path)?;
+        assert_eq!(image.cols(), width as usize);
+        assert_eq!(image.rows(), height as usize);
+        assert_eq!(image.num_channels(), 3);
+
+        let result = super::read_image_heif_rgb8(tmp_dir.path().join("missing.heic"));
+        assert!(matches!(result, Err(IoError::FileDoesNotExist(_))));
+
+        Ok(())
+    }
+}
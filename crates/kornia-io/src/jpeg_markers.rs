@@ -0,0 +1,123 @@
+// a marker segment of the JPEG header
+pub(crate) struct JpegSegment<'a> {
+    // the marker code, e.g. 0xE1 for APP1
+    pub marker: u8,
+    // the offset of the segment in the JPEG stream
+    #[cfg_attr(not(feature = "turbojpeg"), allow(dead_code))]
+    pub offset: usize,
+    // the full segment bytes including the marker and the length field
+    pub data: &'a [u8],
+}
+
+impl<'a> JpegSegment<'a> {
+    // the segment payload after the length field
+    pub fn payload(&self) -> &'a [u8] {
+        &self.data[4..]
+    }
+
+    // whether the segment is an APP1 segment with EXIF data
+    pub fn is_exif(&self) -> bool {
+        self.marker == 0xE1 && self.payload().starts_with(b"Exif\0\0")
+    }
+
+    // whether the segment is an APP2 segment with the multi-picture format (MPF) index
+    pub fn is_mpf(&self) -> bool {
+        self.marker == 0xE2 && self.payload().starts_with(b"MPF\0")
+    }
+
+    // whether the segment is an APP2 segment with a chunk of an ICC profile
+    #[cfg(feature = "turbojpeg")]
+    pub fn is_icc(&self) -> bool {
+        self.marker == 0xE2 && self.payload().starts_with(b"ICC_PROFILE\0")
+    }
+}
+
+// parse the marker segments of the JPEG header up to the start of scan (SOS)
+pub(crate) fn read_jpeg_segments(jpeg_data: &[u8]) -> Result<Vec<JpegSegment<'_>>, String> {
+    if !jpeg_data.starts_with(&[0xFF, 0xD8]) {
+        return Err("missing SOI marker".to_string());
+    }
+
+    let mut segments = Vec::new();
+    let mut pos = 2;
+
+    loop {
+        if jpeg_data.get(pos) != Some(&0xFF) {
+            return Err(format!("expected a marker at offset {pos}"));
+        }
+
+        // skip the fill bytes preceding the marker code
+        let offset = pos;
+        while jpeg_data.get(pos) == Some(&0xFF) {
+            pos += 1;
+        }
+
+        let marker = *jpeg_data
+            .get(pos)
+            .ok_or_else(|| "truncated JPEG header".to_string())?;
+        pos += 1;
+
+        match marker {
+            // standalone markers without a length field
+            0x01 | 0xD0..=0xD8 => continue,
+            // end of image
+            0xD9 => break,
+            _ => {}
+        }
+
+        let length = match jpeg_data.get(pos..pos + 2) {
+            Some(bytes) => u16::from_be_bytes([bytes[0], bytes[1]]) as usize,
+            None => return Err("truncated JPEG header".to_string()),
+        };
+
+        let end = pos + length;
+        if length < 2 || end > jpeg_data.len() {
+            return Err(format!(
+                "invalid length {length} of the marker 0x{marker:02X} at offset {offset}"
+            ));
+        }
+
+        segments.push(JpegSegment {
+            marker,
+            offset: pos - 2,
+            data: &jpeg_data[pos - 2..end],
+        });
+        pos = end;
+
+        // the entropy coded data follows the start of scan
+        if marker == 0xDA {
+            break;
+        }
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_jpeg_segments;
+
+    #[test]
+    fn read_segments() -> Result<(), Box<dyn std::error::Error>> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let segments = read_jpeg_segments(&jpeg_data)?;
+
+        let markers = segments.iter().map(|s| s.marker).collect::<Vec<_>>();
+        assert_eq!(
+            markers,
+            vec![0xE0, 0xDB, 0xC0, 0xC4, 0xC4, 0xC4, 0xC4, 0xDA]
+        );
+
+        // the segments are contiguous after the SOI marker
+        assert_eq!(segments[0].offset, 2);
+        for pair in segments.windows(2) {
+            assert_eq!(pair[0].offset + pair[0].data.len(), pair[1].offset);
+        }
+        assert!(segments[0].payload().starts_with(b"JFIF\0"));
+
+        assert!(read_jpeg_segments(&jpeg_data[..30]).is_err());
+        assert!(read_jpeg_segments(b"not a jpeg").is_err());
+
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
+use std::borrow::Cow;
 use std::sync::{Arc, Mutex};
 use turbojpeg;
 
 use kornia_image::{Image, ImageError, ImageSize};
 
+use crate::jpeg_markers::read_jpeg_segments;
+
+pub use turbojpeg::PixelFormat;
+
 /// Error types for the JPEG module.
 #[derive(thiserror::Error, Debug)]
 pub enum JpegTurboError {
@@ -17,18 +22,106 @@ pub enum JpegTurboError {
     /// Error to create the image.
     #[error("Failed to create image")]
     ImageCreationError(#[from] ImageError),
+
+    /// Error when the JPEG data cannot be read from the source.
+    #[error("Failed to read the JPEG data")]
+    ReadError(#[from] std::io::Error),
+
+    /// Error when the JPEG data is truncated or corrupted.
+    #[error("Corrupted JPEG data: {0}")]
+    CorruptData(String),
+
+    /// Error when no quality reaches the target PSNR.
+    #[error("Target PSNR {0:.2} dB is not reachable, the best is {1:.2} dB")]
+    TargetPsnrNotReached(f64, f64),
+
+    /// Error when the quantization tables cannot be used by the encoder.
+    #[error("Unsupported quantization tables: {0}")]
+    UnsupportedQuantTables(String),
+
+    /// Error when the colorspace of the image cannot be converted to the requested format.
+    #[error("Unsupported JPEG colorspace {0:?}")]
+    UnsupportedColorspace(turbojpeg::Colorspace),
+
+    /// Error when the comment does not fit in a COM segment.
+    #[error("The comment of {0} bytes exceeds the maximum of 65533 bytes")]
+    CommentTooLong(usize),
+
+    /// Error when the lock of the turbojpeg handle is poisoned.
+    #[error("The turbojpeg handle lock is poisoned")]
+    PoisonedLock,
+}
+
+// maps the failures reported by libjpeg-turbo while parsing the data to a corrupt data error
+fn map_decode_error(err: turbojpeg::Error) -> JpegTurboError {
+    match err {
+        turbojpeg::Error::TurboJpegError(msg) => JpegTurboError::CorruptData(msg),
+        err => JpegTurboError::TurboJpegError(err),
+    }
+}
+
+// the standard luminance quantization table of the JPEG spec (Annex K), in zig-zag order
+const STD_LUMA_QUANT_TABLE: [u16; 64] = [
+    16, 11, 12, 14, 12, 10, 16, 14, 13, 14, 18, 17, 16, 19, 24, 40, 26, 24, 22, 22, 24, 49, 35, 37,
+    29, 40, 58, 51, 61, 60, 57, 51, 56, 55, 64, 72, 92, 78, 64, 68, 87, 69, 55, 56, 80, 109, 81,
+    87, 95, 98, 103, 104, 103, 62, 77, 113, 121, 112, 100, 120, 92, 101, 103, 99,
+];
+
+// the standard chrominance quantization table of the JPEG spec (Annex K), in zig-zag order
+const STD_CHROMA_QUANT_TABLE: [u16; 64] = [
+    17, 18, 18, 24, 21, 24, 47, 26, 26, 47, 99, 66, 56, 66, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99,
+];
+
+// scale a standard table to the given quality as libjpeg does for baseline JPEG
+fn scale_quant_table(table: &[u16; 64], quality: i32) -> [u16; 64] {
+    let scale = if quality < 50 {
+        5000 / quality
+    } else {
+        200 - 2 * quality
+    };
+    table.map(|v| ((v as i32 * scale + 50) / 100).clamp(1, 255) as u16)
+}
+
+/// Metadata parsed from the header of a JPEG image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JpegHeader {
+    /// The image size in pixels.
+    pub size: ImageSize,
+    /// The chrominance subsampling of the compressed image.
+    pub subsamp: turbojpeg::Subsamp,
+    /// The colorspace of the compressed image.
+    pub colorspace: turbojpeg::Colorspace,
+    /// Whether the image is a progressive JPEG.
+    pub is_progressive: bool,
 }
 
 /// A JPEG decoder using the turbojpeg library.
 pub struct JpegTurboDecoder {
     /// The turbojpeg decompressor.
     pub decompressor: Arc<Mutex<turbojpeg::Decompressor>>,
+    // whether to use the fast integer inverse DCT
+    fast_dct: bool,
+    // the raw decompressor set up for the fast DCT, created on the first fast decode
+    fast_dct_decompressor: Option<RawHandle>,
 }
 
 /// A JPEG encoder using the turbojpeg library.
 pub struct JpegTurboEncoder {
     /// The turbojpeg compressor.
     pub compressor: Arc<Mutex<turbojpeg::Compressor>>,
+    // the settings mirrored to the raw compressor used for restart markers
+    quality: i32,
+    optimize: bool,
+    // the number of MCU rows between restart markers, 0 to disable them
+    restart_rows: u32,
+    // the horizontal and vertical density in dots per inch written to the JFIF segment
+    density: Option<(u16, u16)>,
+    // whether to strip the application (APPn) segments from the encoded data
+    no_markers: bool,
+    // the text written to a comment (COM) segment
+    comment: Option<String>,
 }
 
 impl Default for JpegTurboDecoder {
@@ -64,6 +157,13 @@ impl JpegTurboEncoder {
         let compressor = turbojpeg::Compressor::new()?;
         Ok(Self {
             compressor: Arc::new(Mutex::new(compressor)),
+            // the default quality of the turbojpeg compressor
+            quality: 95,
+            optimize: false,
+            restart_rows: 0,
+            density: None,
+            no_markers: false,
+            comment: None,
         })
     }
 
@@ -77,24 +177,58 @@ impl JpegTurboEncoder {
     ///
     /// The encoded data as `Vec<u8>`.
     pub fn encode_rgb8(&mut self, image: &Image<u8, 3>) -> Result<Vec<u8>, JpegTurboError> {
-        // get the image data
-        let image_data = image.as_slice();
+        let image = pack_contiguous(image);
+        self.encode(image.as_slice(), image.size(), PixelFormat::RGB)
+    }
+
+    /// Encodes the given RGB8 image into a JPEG image reusing the output buffer.
+    ///
+    /// The buffer is cleared and filled with the encoded data. Its allocation is
+    /// reused across calls and only grows when the worst-case compressed size of
+    /// the image exceeds its capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to encode.
+    /// * `out` - The buffer to write the encoded data to.
+    pub fn encode_rgb8_into(
+        &mut self,
+        image: &Image<u8, 3>,
+        out: &mut Vec<u8>,
+    ) -> Result<(), JpegTurboError> {
+        let image = pack_contiguous(image);
 
         // create a turbojpeg image
         let buf = turbojpeg::Image {
-            pixels: image_data,
+            pixels: image.as_slice(),
             width: image.width(),
             pitch: 3 * image.width(),
             height: image.height(),
             format: turbojpeg::PixelFormat::RGB,
         };
 
-        // encode the image
-        Ok(self
+        if self.restart_rows > 0 {
+            let jpeg_data = self.compress(buf)?;
+            out.clear();
+            out.extend_from_slice(&jpeg_data);
+            return Ok(());
+        }
+
+        let mut compressor = self
             .compressor
             .lock()
-            .expect("Failed to lock the compressor")
-            .compress_to_vec(buf)?)
+            .map_err(|_| JpegTurboError::PoisonedLock)?;
+
+        // make room for the worst-case compressed size without shrinking the allocation
+        let max_len = compressor.buf_len(image.width(), image.height())?;
+        out.clear();
+        out.resize(max_len, 0);
+
+        // encode the image and keep only the written bytes
+        let len = compressor.compress_to_slice(buf, out)?;
+        out.truncate(len);
+
+        self.write_markers(out)
     }
 
     /// Encodes the given grayscale (Gray8) image into a JPEG image.
@@ -107,24 +241,231 @@ impl JpegTurboEncoder {
     ///
     /// The encoded data as `Vec<u8>`.
     pub fn encode_gray8(&mut self, image: &Image<u8, 1>) -> Result<Vec<u8>, JpegTurboError> {
-        // get the image data
-        let image_data = image.as_slice();
+        let image = pack_contiguous(image);
+        self.encode(image.as_slice(), image.size(), PixelFormat::GRAY)
+    }
+
+    /// Encodes the given grayscale image with alpha (GrayAlpha8) into a JPEG image.
+    ///
+    /// JPEG has no alpha channel, so only the luminance plane is encoded as a
+    /// grayscale JPEG and the alpha channel is discarded. The pixels are not
+    /// blended with any background.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The grayscale image with alpha to encode.
+    ///
+    /// # Returns
+    ///
+    /// The encoded data as `Vec<u8>`.
+    pub fn encode_gray_alpha8(&mut self, image: &Image<u8, 2>) -> Result<Vec<u8>, JpegTurboError> {
+        // extracting the channel copies the luminance into a packed image
+        let luminance = image.channel(0)?;
+        self.encode_gray8(&luminance)
+    }
+
+    /// Encodes a raw pixel buffer with the given pixel format into a JPEG image.
+    ///
+    /// The rows of the buffer are assumed to be tightly packed, i.e. the pitch is
+    /// the width times the number of bytes per pixel of the format.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - The pixel data in row-major order.
+    /// * `size` - The size of the image in pixels.
+    /// * `format` - The layout of the pixels, e.g. RGB, BGR, RGBA or GRAY.
+    ///
+    /// # Returns
+    ///
+    /// The encoded data as `Vec<u8>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is smaller than the image size requires.
+    pub fn encode(
+        &mut self,
+        pixels: &[u8],
+        size: ImageSize,
+        format: PixelFormat,
+    ) -> Result<Vec<u8>, JpegTurboError> {
+        let pitch = size.width * format.size();
+
+        if pixels.len() < pitch * size.height {
+            return Err(ImageError::InvalidChannelShape(pixels.len(), pitch * size.height).into());
+        }
 
         // create a turbojpeg image
         let buf = turbojpeg::Image {
-            pixels: image_data,
-            width: image.width(),
-            pitch: image.width(), // 1 byte per pixel for grayscale
-            height: image.height(),
-            format: turbojpeg::PixelFormat::GRAY,
+            pixels,
+            width: size.width,
+            pitch,
+            height: size.height,
+            format,
         };
 
         // encode the image
-        Ok(self
-            .compressor
-            .lock()
-            .expect("Failed to lock the compressor")
-            .compress_to_vec(buf)?)
+        self.compress(buf)
+    }
+
+    /// Encodes the given RGB8 image with the lowest quality reaching a target PSNR.
+    ///
+    /// The quality is binary searched in the range [1, 100]. Each candidate is
+    /// decoded back and compared against the original image using the peak
+    /// signal-to-noise ratio (PSNR). After the call, the encoder quality is set to
+    /// the returned quality, or kept as it was if the target is not reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to encode.
+    /// * `target_psnr` - The minimum accepted PSNR in dB.
+    ///
+    /// # Returns
+    ///
+    /// The encoded data and the quality used to encode it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JpegTurboError::TargetPsnrNotReached`] if not even the maximum
+    /// quality reaches the target.
+    pub fn encode_rgb8_target_psnr(
+        &mut self,
+        image: &Image<u8, 3>,
+        target_psnr: f64,
+    ) -> Result<(Vec<u8>, i32), JpegTurboError> {
+        let mut decoder = JpegTurboDecoder::new()?;
+        let initial_quality = self.quality;
+
+        let (mut lo, mut hi) = (1, 100);
+        let mut best = None;
+        let mut best_psnr = f64::NEG_INFINITY;
+
+        while lo <= hi {
+            let quality = (lo + hi) / 2;
+            self.set_quality(quality)?;
+            let jpeg_data = self.encode_rgb8(image)?;
+
+            let decoded = decoder.decode_rgb8(&jpeg_data)?;
+            let psnr = crate::metrics::psnr_u8(image, &decoded)?;
+            best_psnr = best_psnr.max(psnr);
+
+            if psnr >= target_psnr {
+                best = Some((jpeg_data, quality));
+                hi = quality - 1;
+            } else {
+                lo = quality + 1;
+            }
+        }
+
+        let Some((jpeg_data, quality)) = best else {
+            self.set_quality(initial_quality)?;
+            return Err(JpegTurboError::TargetPsnrNotReached(target_psnr, best_psnr));
+        };
+        self.set_quality(quality)?;
+
+        Ok((jpeg_data, quality))
+    }
+
+    /// Encodes the given RGB8 image with the highest quality fitting a byte budget.
+    ///
+    /// The quality is binary searched in the range [1, 100] with at most 7
+    /// encoding attempts. After the call, the encoder quality is set to the
+    /// quality of the returned data.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to encode.
+    /// * `max_bytes` - The maximum size of the encoded data in bytes.
+    ///
+    /// # Returns
+    ///
+    /// The encoded data. If not even the minimum quality fits the budget, the
+    /// data encoded with the minimum quality is returned.
+    pub fn encode_rgb8_target_size(
+        &mut self,
+        image: &Image<u8, 3>,
+        max_bytes: usize,
+    ) -> Result<Vec<u8>, JpegTurboError> {
+        // the best attempt fitting the budget and the lowest quality attempt as a fallback
+        let mut best = None;
+        let mut lowest = (Vec::new(), 1);
+
+        let (mut lo, mut hi) = (1, 100);
+        for _ in 0..7 {
+            if lo > hi {
+                break;
+            }
+
+            let quality = (lo + hi) / 2;
+            self.set_quality(quality)?;
+            let jpeg_data = self.encode_rgb8(image)?;
+
+            if jpeg_data.len() <= max_bytes {
+                best = Some((jpeg_data, quality));
+                lo = quality + 1;
+            } else {
+                lowest = (jpeg_data, quality);
+                hi = quality - 1;
+            }
+        }
+
+        let (jpeg_data, quality) = best.unwrap_or(lowest);
+        self.set_quality(quality)?;
+
+        Ok(jpeg_data)
+    }
+
+    /// Encodes the given RGB8 image with the given quantization tables.
+    ///
+    /// libjpeg-turbo does not accept arbitrary quantization tables: it derives
+    /// them by scaling the standard tables of the JPEG spec with a quality factor.
+    /// The tables are therefore only supported if they match the standard tables
+    /// scaled to some quality in [1, 100], e.g. tables read from an image encoded
+    /// by libjpeg or libjpeg-turbo. After the call, the encoder quality is set to
+    /// the matching quality.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to encode.
+    /// * `luma` - The quantization table of the luminance in zig-zag order.
+    /// * `chroma` - The quantization table of the chrominance in zig-zag order.
+    ///
+    /// # Returns
+    ///
+    /// The encoded data with the given quantization tables.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JpegTurboError::UnsupportedQuantTables`] if a value is out of the
+    /// baseline range [1, 255] or if no quality produces the given tables.
+    pub fn encode_rgb8_with_quant_tables(
+        &mut self,
+        image: &Image<u8, 3>,
+        luma: [u16; 64],
+        chroma: [u16; 64],
+    ) -> Result<Vec<u8>, JpegTurboError> {
+        if let Some(&value) = luma
+            .iter()
+            .chain(chroma.iter())
+            .find(|v| !(1..=255).contains(*v))
+        {
+            return Err(JpegTurboError::UnsupportedQuantTables(format!(
+                "value {value} is out of the range [1, 255]"
+            )));
+        }
+
+        let quality = (1..=100)
+            .find(|&quality| {
+                scale_quant_table(&STD_LUMA_QUANT_TABLE, quality) == luma
+                    && scale_quant_table(&STD_CHROMA_QUANT_TABLE, quality) == chroma
+            })
+            .ok_or_else(|| {
+                JpegTurboError::UnsupportedQuantTables(
+                    "the tables are not the standard tables scaled by a quality".to_string(),
+                )
+            })?;
+
+        self.set_quality(quality)?;
+        self.encode_rgb8(image)
     }
 
     /// Sets the quality of the encoder.
@@ -133,12 +474,293 @@ impl JpegTurboEncoder {
     ///
     /// * `quality` - The quality to set.
     pub fn set_quality(&mut self, quality: i32) -> Result<(), JpegTurboError> {
-        Ok(self
-            .compressor
+        self.compressor
             .lock()
-            .expect("Failed to lock the compressor")
-            .set_quality(quality)?)
+            .map_err(|_| JpegTurboError::PoisonedLock)?
+            .set_quality(quality)?;
+        self.quality = quality;
+        Ok(())
+    }
+
+    /// Enables or disables the computation of optimal Huffman tables.
+    ///
+    /// Optimized tables reduce the size of the encoded data at the cost of an
+    /// extra pass over the image. The setting persists across encodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to optimize the Huffman tables.
+    pub fn set_optimize(&mut self, enabled: bool) -> Result<(), JpegTurboError> {
+        self.compressor
+            .lock()
+            .map_err(|_| JpegTurboError::PoisonedLock)?
+            .set_optimize(enabled)?;
+        self.optimize = enabled;
+        Ok(())
+    }
+
+    /// Sets the interval of the restart markers in the encoded data.
+    ///
+    /// A restart marker (RSTn) is inserted every `mcu_rows` rows of MCUs and the
+    /// interval is written to a DRI segment. The entropy decoder resynchronizes
+    /// at each marker, so a corrupted byte only damages the pixels up to the next
+    /// marker instead of the rest of the image, at the cost of a slightly larger
+    /// output. This is useful for JPEGs sent over lossy links. The setting
+    /// persists across encodes.
+    ///
+    /// While restart markers are enabled, the images are encoded with the
+    /// quality and the Huffman optimization set on this encoder and without
+    /// chrominance subsampling (4:4:4), i.e. the settings changed directly on
+    /// [`JpegTurboEncoder::compressor`] are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `mcu_rows` - The number of MCU rows between restart markers, 0 to disable them.
+    pub fn set_restart_interval(&mut self, mcu_rows: u32) {
+        self.restart_rows = mcu_rows;
     }
+
+    /// Sets the pixel density written to the JFIF header of the encoded data.
+    ///
+    /// The density is stored in dots per inch, e.g. to print the image at a
+    /// given size. By default the JFIF header written by turbojpeg is kept,
+    /// which declares a 1:1 pixel aspect ratio without units. The setting
+    /// persists across encodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_dpi` - The horizontal density in dots per inch.
+    /// * `y_dpi` - The vertical density in dots per inch.
+    pub fn set_density(&mut self, x_dpi: u16, y_dpi: u16) {
+        self.density = Some((x_dpi, y_dpi));
+    }
+
+    /// Enables or disables the removal of the application markers from the encoded data.
+    ///
+    /// When enabled, the JFIF header and any other application (APPn) segment
+    /// are stripped, leaving only the segments needed to decode the image, e.g.
+    /// to embed the stream in a custom container. The decoders assume YCbCr
+    /// for 3-channel images without JFIF header, so the pixels decode the same.
+    /// The density set with [`JpegTurboEncoder::set_density`] is then ignored.
+    /// The setting persists across encodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to strip the application markers.
+    pub fn set_no_markers(&mut self, enabled: bool) {
+        self.no_markers = enabled;
+    }
+
+    /// Sets the comment written to a COM segment of the encoded data.
+    ///
+    /// The comment is stored after the application segments of the header,
+    /// e.g. to tag the image with its provenance, and can be read back with
+    /// [`read_comment`]. The setting persists across encodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment` - The comment text, at most 65533 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the comment does not fit in a COM segment.
+    pub fn set_comment(&mut self, comment: &str) -> Result<(), JpegTurboError> {
+        if comment.len() > MAX_SEGMENT_PAYLOAD {
+            return Err(JpegTurboError::CommentTooLong(comment.len()));
+        }
+        self.comment = Some(comment.to_string());
+        Ok(())
+    }
+
+    // compress the image into a new buffer honoring the restart interval and the density
+    fn compress(&mut self, buf: turbojpeg::Image<&[u8]>) -> Result<Vec<u8>, JpegTurboError> {
+        let mut jpeg_data = if self.restart_rows > 0 {
+            compress_with_restarts(buf, self.quality, self.optimize, self.restart_rows)?
+        } else {
+            self.compressor
+                .lock()
+                .map_err(|_| JpegTurboError::PoisonedLock)?
+                .compress_to_vec(buf)?
+        };
+
+        self.write_markers(&mut jpeg_data)?;
+
+        Ok(jpeg_data)
+    }
+
+    // update the application markers of the encoded data with the encoder settings
+    fn write_markers(&self, jpeg_data: &mut Vec<u8>) -> Result<(), JpegTurboError> {
+        if self.no_markers {
+            strip_app_segments(jpeg_data)?;
+        } else if let Some((x_dpi, y_dpi)) = self.density {
+            set_jfif_density(jpeg_data, x_dpi, y_dpi)?;
+        }
+
+        if let Some(comment) = &self.comment {
+            insert_comment(jpeg_data, comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+// borrow contiguous images and pack strided ones, e.g. sliced with row padding, before encoding
+fn pack_contiguous<const C: usize>(image: &Image<u8, C>) -> Cow<'_, Image<u8, C>> {
+    if image.is_contiguous() {
+        Cow::Borrowed(image)
+    } else {
+        Cow::Owned(image.to_contiguous())
+    }
+}
+
+// whether the JPEG data has an Adobe (APP14) segment, whose CMYK values are inverted
+fn has_adobe_marker(jpeg_data: &[u8]) -> bool {
+    read_jpeg_segments(jpeg_data).is_ok_and(|segments| {
+        segments
+            .iter()
+            .any(|segment| segment.marker == 0xEE && segment.payload().starts_with(b"Adobe"))
+    })
+}
+
+// convert CMYK pixels to RGB without color management, where the values are
+// the amount of ink or, for images written by Adobe applications, 255 minus it
+fn cmyk_to_rgb(cmyk: &[u8], inverted: bool) -> Vec<u8> {
+    let mul = |a: u8, b: u8| ((a as u32 * b as u32 + 127) / 255) as u8;
+
+    cmyk.chunks_exact(4)
+        .flat_map(|pixel| {
+            let [c, m, y, k]: [u8; 4] =
+                std::array::from_fn(|ch| if inverted { pixel[ch] } else { 255 - pixel[ch] });
+            [mul(c, k), mul(m, k), mul(y, k)]
+        })
+        .collect()
+}
+
+// the 64-bit FNV-1a hash of the given bytes
+fn fnv1a_64(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    data.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+// the maximum payload of a segment, whose length field counts itself
+const MAX_SEGMENT_PAYLOAD: usize = u16::MAX as usize - 2;
+
+// insert a comment (COM) segment after the application segments of the header
+fn insert_comment(jpeg_data: &mut Vec<u8>, comment: &str) -> Result<(), JpegTurboError> {
+    let offset = read_jpeg_segments(jpeg_data)
+        .map_err(JpegTurboError::CorruptData)?
+        .iter()
+        .find(|segment| !matches!(segment.marker, 0xE0..=0xEF))
+        .map(|segment| segment.offset)
+        .ok_or_else(|| JpegTurboError::CorruptData("missing SOS marker".to_string()))?;
+
+    let mut com = vec![0xFF, 0xFE];
+    com.extend_from_slice(&(comment.len() as u16 + 2).to_be_bytes());
+    com.extend_from_slice(comment.as_bytes());
+    jpeg_data.splice(offset..offset, com);
+
+    Ok(())
+}
+
+// remove the application (APPn) segments from the header of the JPEG data
+fn strip_app_segments(jpeg_data: &mut Vec<u8>) -> Result<(), JpegTurboError> {
+    let app_segments = read_jpeg_segments(jpeg_data)
+        .map_err(JpegTurboError::CorruptData)?
+        .iter()
+        .filter(|segment| matches!(segment.marker, 0xE0..=0xEF))
+        .map(|segment| segment.offset..segment.offset + segment.data.len())
+        .collect::<Vec<_>>();
+
+    // remove from the back to keep the offsets of the previous segments valid
+    for range in app_segments.into_iter().rev() {
+        jpeg_data.drain(range);
+    }
+
+    Ok(())
+}
+
+// find the offset of the marker following the entropy coded data of each scan
+fn find_scan_ends(jpeg_data: &[u8]) -> Result<Vec<usize>, String> {
+    let sos = read_jpeg_segments(jpeg_data)?
+        .pop()
+        .filter(|segment| segment.marker == 0xDA)
+        .ok_or_else(|| "missing SOS marker".to_string())?;
+
+    let mut scan_ends = Vec::new();
+    let mut pos = sos.offset + sos.data.len();
+
+    loop {
+        // skip the entropy coded data, where 0xFF is followed by a stuffed zero
+        // byte, a fill byte or a restart marker
+        while pos + 1 < jpeg_data.len()
+            && (jpeg_data[pos] != 0xFF || matches!(jpeg_data[pos + 1], 0x00 | 0xFF | 0xD0..=0xD7))
+        {
+            pos += 1;
+        }
+
+        if pos + 1 >= jpeg_data.len() {
+            return Err("truncated scan data".to_string());
+        }
+        scan_ends.push(pos);
+
+        // skip the tables between the scans up to the next SOS marker
+        loop {
+            match jpeg_data[pos + 1] {
+                0xD9 => return Ok(scan_ends),
+                marker => {
+                    let length = jpeg_data
+                        .get(pos + 2..pos + 4)
+                        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]) as usize)
+                        .ok_or_else(|| "truncated JPEG marker".to_string())?;
+                    pos += 2 + length;
+                    // the entropy coded data of the next scan follows the SOS header
+                    if marker == 0xDA {
+                        break;
+                    }
+                    if pos + 1 >= jpeg_data.len() || jpeg_data[pos] != 0xFF {
+                        return Err(format!("invalid marker 0x{marker:02X} between scans"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// write the density in dots per inch to the JFIF segment, inserting one after SOI if missing
+fn set_jfif_density(jpeg_data: &mut Vec<u8>, x_dpi: u16, y_dpi: u16) -> Result<(), JpegTurboError> {
+    let jfif_offset = read_jpeg_segments(jpeg_data)
+        .map_err(JpegTurboError::CorruptData)?
+        .iter()
+        .find(|segment| {
+            segment.marker == 0xE0
+                && segment.payload().starts_with(b"JFIF\0")
+                && segment.payload().len() >= 12
+        })
+        .map(|segment| segment.offset);
+
+    let Some(offset) = jfif_offset else {
+        // a JFIF 1.01 segment without thumbnail
+        let mut app0 = vec![0xFF, 0xE0, 0x00, 0x10];
+        app0.extend_from_slice(b"JFIF\0");
+        app0.extend_from_slice(&[1, 1, 1]);
+        app0.extend_from_slice(&x_dpi.to_be_bytes());
+        app0.extend_from_slice(&y_dpi.to_be_bytes());
+        app0.extend_from_slice(&[0, 0]);
+        jpeg_data.splice(2..2, app0);
+        return Ok(());
+    };
+
+    // the units, the horizontal and the vertical density follow the identifier and the version
+    let units = offset + 4 + 7;
+    jpeg_data[units] = 1;
+    jpeg_data[units + 1..units + 3].copy_from_slice(&x_dpi.to_be_bytes());
+    jpeg_data[units + 3..units + 5].copy_from_slice(&y_dpi.to_be_bytes());
+
+    Ok(())
 }
 
 /// Implementation of the ImageDecoder struct.
@@ -152,9 +774,24 @@ impl JpegTurboDecoder {
         let decompressor = turbojpeg::Decompressor::new()?;
         Ok(JpegTurboDecoder {
             decompressor: Arc::new(Mutex::new(decompressor)),
+            fast_dct: false,
+            fast_dct_decompressor: None,
         })
     }
 
+    /// Enables or disables the fast integer inverse DCT.
+    ///
+    /// The fast DCT of libjpeg-turbo trades a small loss of accuracy for a
+    /// faster decode, which is useful e.g. for real-time previews. The setting
+    /// applies to the subsequent full resolution decodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to use the fast integer DCT.
+    pub fn set_fast_dct(&mut self, enabled: bool) {
+        self.fast_dct = enabled;
+    }
+
     /// Reads the header of a JPEG image.
     ///
     /// # Arguments
@@ -169,16 +806,35 @@ impl JpegTurboDecoder {
     ///
     /// Panics if the header cannot be read.
     pub fn read_header(&mut self, jpeg_data: &[u8]) -> Result<ImageSize, JpegTurboError> {
+        Ok(self.read_header_full(jpeg_data)?.size)
+    }
+
+    /// Reads the full header of a JPEG image.
+    ///
+    /// # Arguments
+    ///
+    /// * `jpeg_data` - The JPEG data to read the header from.
+    ///
+    /// # Returns
+    ///
+    /// The header metadata including size, subsampling and colorspace.
+    pub fn read_header_full(&mut self, jpeg_data: &[u8]) -> Result<JpegHeader, JpegTurboError> {
         // read the JPEG header with image size
         let header = self
             .decompressor
             .lock()
-            .expect("Failed to lock the decompressor")
-            .read_header(jpeg_data)?;
+            .map_err(|_| JpegTurboError::PoisonedLock)?
+            .read_header(jpeg_data)
+            .map_err(map_decode_error)?;
 
-        Ok(ImageSize {
-            width: header.width,
-            height: header.height,
+        Ok(JpegHeader {
+            size: ImageSize {
+                width: header.width,
+                height: header.height,
+            },
+            subsamp: header.subsamp,
+            colorspace: header.colorspace,
+            is_progressive: header.is_progressive,
         })
     }
 
@@ -192,67 +848,697 @@ impl JpegTurboDecoder {
     ///
     /// The decoded data as Image<u8, 3>.
     pub fn decode_rgb8(&mut self, jpeg_data: &[u8]) -> Result<Image<u8, 3>, JpegTurboError> {
-        // get the image size to allocate th data storage
-        let image_size = self.read_header(jpeg_data)?;
-
-        // prepare a storage for the raw pixel data
-        let mut pixels = vec![0u8; image_size.height * image_size.width * 3];
-
-        // allocate image container
-        let buf = turbojpeg::Image {
-            pixels: pixels.as_mut_slice(),
-            width: image_size.width,
-            pitch: 3 * image_size.width, // we use no padding between rows
-            height: image_size.height,
-            format: turbojpeg::PixelFormat::RGB,
-        };
-
-        // decompress the JPEG data
-        self.decompressor
-            .lock()
-            .expect("Failed to lock the decompressor")
-            .decompress(jpeg_data, buf)?;
-
-        Ok(Image::new(image_size, pixels)?)
+        let (image, _) = self.decode_rgb8_with_info(jpeg_data)?;
+        Ok(image)
     }
 
-    /// Decodes the given JPEG data as grayscale (Gray8) image.
+    /// Decodes a JPEG image in RGB8 format reporting the partial image after each scan.
+    ///
+    /// A progressive JPEG stores the image in several scans, each one refining
+    /// the previous ones, e.g. to show a preview while the data downloads. The
+    /// callback is invoked with the image decoded from the scans received so far
+    /// after each scan, the last call receiving the complete image. For baseline
+    /// JPEGs the callback is invoked once with the complete image.
+    ///
+    /// Each partial image is a full decode of the data up to its scan, so the
+    /// total cost grows with the number of scans.
     ///
     /// # Arguments
     ///
     /// * `jpeg_data` - The JPEG data to decode.
+    /// * `on_scan` - The function called with the image after each scan.
     ///
     /// # Returns
     ///
-    /// The decoded data as Image<u8, 1>.
-    pub fn decode_gray8(&mut self, jpeg_data: &[u8]) -> Result<Image<u8, 1>, JpegTurboError> {
-        // get the image size to allocate th data storage
-        let image_size = self.read_header(jpeg_data)?;
+    /// The decoded image in RGB8 format.
+    pub fn decode_rgb8_progressive(
+        &mut self,
+        jpeg_data: &[u8],
+        mut on_scan: impl FnMut(&Image<u8, 3>),
+    ) -> Result<Image<u8, 3>, JpegTurboError> {
+        if self.read_header_full(jpeg_data)?.is_progressive {
+            let scan_ends = find_scan_ends(jpeg_data).map_err(JpegTurboError::CorruptData)?;
 
-        // prepare a storage for the raw pixel data
-        let mut pixels = vec![0u8; image_size.height * image_size.width * 1]; // 1 byte per pixel
-
-        // allocate image container
-        let buf = turbojpeg::Image {
-            pixels: pixels.as_mut_slice(),
-            width: image_size.width,
-            pitch: image_size.width, // 1 byte per pixel, no padding
-            height: image_size.height,
-            format: turbojpeg::PixelFormat::GRAY,
-        };
+            // terminate the data after each scan but the last one with an EOI marker
+            let mut partial = Vec::with_capacity(jpeg_data.len() + 2);
+            for &end in scan_ends.iter().take(scan_ends.len().saturating_sub(1)) {
+                partial.clear();
+                partial.extend_from_slice(&jpeg_data[..end]);
+                partial.extend_from_slice(&[0xFF, 0xD9]);
+                on_scan(&self.decode_rgb8(&partial)?);
+            }
+        }
 
-        // decompress the JPEG data
-        self.decompressor
-            .lock()
-            .expect("Failed to lock the decompressor")
-            .decompress(jpeg_data, buf)?;
+        let image = self.decode_rgb8(jpeg_data)?;
+        on_scan(&image);
 
-        Ok(Image::new(image_size, pixels)?)
+        Ok(image)
     }
-}
+
+    /// Decodes a JPEG image in RGB8 format and computes a hash of its pixels.
+    ///
+    /// The hash is the 64-bit FNV-1a hash of the decoded pixel data, so images
+    /// with the same pixels have the same hash regardless of the metadata or
+    /// comments stored in the file. It is not a cryptographic hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `jpeg_data` - The JPEG data to decode.
+    ///
+    /// # Returns
+    ///
+    /// The decoded image in RGB8 format and the hash of its pixels.
+    pub fn decode_and_hash(
+        &mut self,
+        jpeg_data: &[u8],
+    ) -> Result<(Image<u8, 3>, u64), JpegTurboError> {
+        let image = self.decode_rgb8(jpeg_data)?;
+        let hash = fnv1a_64(image.as_slice());
+        Ok((image, hash))
+    }
+
+    /// Decodes a low resolution preview of the given JPEG data as RGB8 image.
+    ///
+    /// The image is decoded at 1/8 scale, where each 8x8 block is reduced to a
+    /// single pixel. This approximates a decode of the DC coefficients only and
+    /// skips most of the inverse DCT work, making it the fastest way to get a
+    /// blurry preview of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `jpeg_data` - The JPEG data to decode.
+    ///
+    /// # Returns
+    ///
+    /// The decoded preview as Image<u8, 3> with the size of the image divided by
+    /// 8 and rounded up.
+    pub fn decode_dc_preview(&mut self, jpeg_data: &[u8]) -> Result<Image<u8, 3>, JpegTurboError> {
+        self.decode_rgb8_scaled(jpeg_data, turbojpeg::ScalingFactor::ONE_EIGHTH)
+    }
+
+    // decode the data as RGB8 with the native scaling of libjpeg-turbo
+    pub(crate) fn decode_rgb8_scaled(
+        &mut self,
+        jpeg_data: &[u8],
+        scaling_factor: turbojpeg::ScalingFactor,
+    ) -> Result<Image<u8, 3>, JpegTurboError> {
+        let mut decompressor = self
+            .decompressor
+            .lock()
+            .map_err(|_| JpegTurboError::PoisonedLock)?;
+
+        let header = decompressor
+            .read_header(jpeg_data)
+            .map_err(map_decode_error)?;
+
+        let image_size = ImageSize {
+            width: scaling_factor.scale(header.width),
+            height: scaling_factor.scale(header.height),
+        };
+
+        // prepare a storage for the raw pixel data
+        let mut pixels = vec![0u8; image_size.height * image_size.width * 3];
+
+        let buf = turbojpeg::Image {
+            pixels: pixels.as_mut_slice(),
+            width: image_size.width,
+            pitch: 3 * image_size.width, // we use no padding between rows
+            height: image_size.height,
+            format: turbojpeg::PixelFormat::RGB,
+        };
+
+        decompressor.set_scaling_factor(scaling_factor)?;
+        let result = decompressor
+            .decompress(jpeg_data, buf)
+            .map_err(map_decode_error);
+
+        // restore the full scale for the next decodes
+        decompressor.set_scaling_factor(turbojpeg::ScalingFactor::ONE)?;
+        result?;
+
+        Ok(Image::new(image_size, pixels)?)
+    }
+
+    /// Decodes a JPEG image as RGB8 from a reader.
+    ///
+    /// The reader is consumed until the end of the stream and the data is decoded
+    /// as with [`JpegTurboDecoder::decode_rgb8`].
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the JPEG data, e.g. a file or a socket.
+    ///
+    /// # Returns
+    ///
+    /// The decoded data as Image<u8, 3>.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JpegTurboError::ReadError`] if reading from the source fails.
+    pub fn decode_rgb8_from_reader<R: std::io::Read>(
+        &mut self,
+        reader: &mut R,
+    ) -> Result<Image<u8, 3>, JpegTurboError> {
+        let mut jpeg_data = Vec::new();
+        reader.read_to_end(&mut jpeg_data)?;
+        self.decode_rgb8(&jpeg_data)
+    }
+
+    /// Decodes the given JPEG data as RGB8 image and returns its header.
+    ///
+    /// The header is parsed once and used both to allocate the image and as
+    /// the returned metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `jpeg_data` - The JPEG data to decode.
+    ///
+    /// # Returns
+    ///
+    /// The decoded data as Image<u8, 3> and the parsed header.
+    pub fn decode_rgb8_with_info(
+        &mut self,
+        jpeg_data: &[u8],
+    ) -> Result<(Image<u8, 3>, JpegHeader), JpegTurboError> {
+        // get the image size to allocate th data storage
+        let header = self.read_header_full(jpeg_data)?;
+        let image_size = header.size;
+
+        // libjpeg-turbo decodes CMYK and YCCK images only to CMYK pixels
+        if matches!(
+            header.colorspace,
+            turbojpeg::Colorspace::CMYK | turbojpeg::Colorspace::YCCK
+        ) {
+            let mut cmyk = vec![0u8; image_size.height * image_size.width * 4];
+
+            let buf = turbojpeg::Image {
+                pixels: cmyk.as_mut_slice(),
+                width: image_size.width,
+                pitch: 4 * image_size.width, // we use no padding between rows
+                height: image_size.height,
+                format: turbojpeg::PixelFormat::CMYK,
+            };
+
+            self.decompress(jpeg_data, buf)?;
+
+            let pixels = cmyk_to_rgb(&cmyk, has_adobe_marker(jpeg_data));
+            return Ok((Image::new(image_size, pixels)?, header));
+        }
+
+        // prepare a storage for the raw pixel data
+        let mut pixels = vec![0u8; image_size.height * image_size.width * 3];
+
+        // allocate image container
+        let buf = turbojpeg::Image {
+            pixels: pixels.as_mut_slice(),
+            width: image_size.width,
+            pitch: 3 * image_size.width, // we use no padding between rows
+            height: image_size.height,
+            format: turbojpeg::PixelFormat::RGB,
+        };
+
+        // decompress the JPEG data
+        self.decompress(jpeg_data, buf)?;
+
+        Ok((Image::new(image_size, pixels)?, header))
+    }
+
+    // decompress the JPEG data into the buffer honoring the DCT setting
+    fn decompress(
+        &mut self,
+        jpeg_data: &[u8],
+        buf: turbojpeg::Image<&mut [u8]>,
+    ) -> Result<(), JpegTurboError> {
+        if self.fast_dct {
+            let handle = match &mut self.fast_dct_decompressor {
+                Some(handle) => handle,
+                None => {
+                    let handle = RawHandle::new(turbojpeg::raw::TJINIT_TJINIT_DECOMPRESS)?;
+                    handle.set(turbojpeg::raw::TJPARAM_TJPARAM_FASTDCT, 1)?;
+                    self.fast_dct_decompressor.insert(handle)
+                }
+            };
+            return decompress_fast_dct(handle, jpeg_data, buf);
+        }
+
+        self.decompressor
+            .lock()
+            .map_err(|_| JpegTurboError::PoisonedLock)?
+            .decompress(jpeg_data, buf)
+            .map_err(map_decode_error)
+    }
+
+    /// Decodes the given JPEG data as grayscale (Gray8) image.
+    ///
+    /// # Arguments
+    ///
+    /// * `jpeg_data` - The JPEG data to decode.
+    ///
+    /// # Returns
+    ///
+    /// The decoded data as Image<u8, 1>.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JpegTurboError::UnsupportedColorspace`] for CMYK and YCCK
+    /// images, which can be decoded with [`JpegTurboDecoder::decode_rgb8`].
+    pub fn decode_gray8(&mut self, jpeg_data: &[u8]) -> Result<Image<u8, 1>, JpegTurboError> {
+        // get the image size to allocate th data storage
+        let header = self.read_header_full(jpeg_data)?;
+        let image_size = header.size;
+
+        // libjpeg-turbo cannot convert CMYK and YCCK images to grayscale
+        if matches!(
+            header.colorspace,
+            turbojpeg::Colorspace::CMYK | turbojpeg::Colorspace::YCCK
+        ) {
+            return Err(JpegTurboError::UnsupportedColorspace(header.colorspace));
+        }
+
+        // prepare a storage for the raw pixel data
+        let mut pixels = vec![0u8; image_size.height * image_size.width]; // 1 byte per pixel
+
+        // allocate image container
+        let buf = turbojpeg::Image {
+            pixels: pixels.as_mut_slice(),
+            width: image_size.width,
+            pitch: image_size.width, // 1 byte per pixel, no padding
+            height: image_size.height,
+            format: turbojpeg::PixelFormat::GRAY,
+        };
+
+        // decompress the JPEG data
+        self.decompress(jpeg_data, buf)?;
+
+        Ok(Image::new(image_size, pixels)?)
+    }
+}
+
+// a raw turbojpeg handle, destroyed on drop
+struct RawHandle(turbojpeg::raw::tjhandle);
+
+// SAFETY: a turbojpeg handle is not tied to the thread that created it and is
+// only used through exclusive references
+unsafe impl Send for RawHandle {}
+unsafe impl Sync for RawHandle {}
+
+impl RawHandle {
+    fn new(init: turbojpeg::raw::TJINIT) -> Result<Self, JpegTurboError> {
+        // SAFETY: tj3Init returns either a valid handle or null
+        let handle = unsafe { turbojpeg::raw::tj3Init(init as std::ffi::c_int) };
+        if handle.is_null() {
+            return Err(JpegTurboError::CorruptData(
+                "failed to create the turbojpeg handle".to_string(),
+            ));
+        }
+        Ok(Self(handle))
+    }
+
+    fn set(
+        &self,
+        param: turbojpeg::raw::TJPARAM,
+        value: std::ffi::c_int,
+    ) -> Result<(), JpegTurboError> {
+        // SAFETY: the handle is valid
+        if unsafe { turbojpeg::raw::tj3Set(self.0, param as std::ffi::c_int, value) } != 0 {
+            return Err(self.last_error());
+        }
+        Ok(())
+    }
+
+    fn last_error(&self) -> JpegTurboError {
+        // SAFETY: the handle is valid and the error string is a nul-terminated C string
+        let msg = unsafe { std::ffi::CStr::from_ptr(turbojpeg::raw::tj3GetErrorStr(self.0)) };
+        JpegTurboError::CorruptData(msg.to_string_lossy().into_owned())
+    }
+}
+
+impl Drop for RawHandle {
+    fn drop(&mut self) {
+        // SAFETY: the handle was created by tj3Init and is destroyed only once
+        unsafe { turbojpeg::raw::tj3Destroy(self.0) };
+    }
+}
+
+// check that the pixel buffer holds the image and convert its width, height and
+// pitch to the C integers of the raw API
+fn raw_image_dims<T: AsRef<[u8]>>(
+    buf: &turbojpeg::Image<T>,
+) -> Result<(std::ffi::c_int, std::ffi::c_int, std::ffi::c_int), JpegTurboError> {
+    let to_c_int = |v: usize, name: &'static str| {
+        std::ffi::c_int::try_from(v).map_err(|_| turbojpeg::Error::IntegerOverflow(name))
+    };
+    let dims = (
+        to_c_int(buf.width, "width")?,
+        to_c_int(buf.height, "height")?,
+        to_c_int(buf.pitch, "pitch")?,
+    );
+
+    let len = buf.pixels.as_ref().len();
+    if buf.pitch < buf.width * buf.format.size() || len < buf.pitch * buf.height {
+        return Err(ImageError::InvalidChannelShape(len, buf.pitch * buf.height).into());
+    }
+
+    Ok(dims)
+}
+
+// decompress with the fast integer DCT, which is not exposed by the turbojpeg bindings
+fn decompress_fast_dct(
+    handle: &mut RawHandle,
+    jpeg_data: &[u8],
+    buf: turbojpeg::Image<&mut [u8]>,
+) -> Result<(), JpegTurboError> {
+    use std::ffi::c_int;
+    use turbojpeg::raw;
+
+    let (width, height, pitch) = raw_image_dims(&buf)?;
+
+    // SAFETY: the handle is valid and the JPEG buffer outlives the call
+    let res = unsafe {
+        raw::tj3DecompressHeader(handle.0, jpeg_data.as_ptr(), jpeg_data.len() as raw::size_t)
+    };
+    if res != 0 {
+        return Err(handle.last_error());
+    }
+
+    // the output buffer must hold the full resolution image
+    // SAFETY: the handle is valid
+    let (jpeg_width, jpeg_height) = unsafe {
+        (
+            raw::tj3Get(handle.0, raw::TJPARAM_TJPARAM_JPEGWIDTH as c_int),
+            raw::tj3Get(handle.0, raw::TJPARAM_TJPARAM_JPEGHEIGHT as c_int),
+        )
+    };
+    if (jpeg_width, jpeg_height) != (width, height) {
+        return Err(ImageError::InvalidImageSize(
+            buf.width,
+            buf.height,
+            jpeg_width as usize,
+            jpeg_height as usize,
+        )
+        .into());
+    }
+
+    // SAFETY: the output buffer holds `height` rows of `pitch` bytes, as checked above
+    let res = unsafe {
+        raw::tj3Decompress8(
+            handle.0,
+            jpeg_data.as_ptr(),
+            jpeg_data.len() as raw::size_t,
+            buf.pixels.as_mut_ptr(),
+            pitch,
+            buf.format as c_int,
+        )
+    };
+    if res != 0 {
+        return Err(handle.last_error());
+    }
+
+    Ok(())
+}
+
+// compress with restart markers, which are not exposed by the turbojpeg bindings
+fn compress_with_restarts(
+    buf: turbojpeg::Image<&[u8]>,
+    quality: i32,
+    optimize: bool,
+    restart_rows: u32,
+) -> Result<Vec<u8>, JpegTurboError> {
+    use std::ffi::c_int;
+    use turbojpeg::raw;
+
+    let (width, height, pitch) = raw_image_dims(&buf)?;
+    let restart_rows = c_int::try_from(restart_rows)
+        .map_err(|_| turbojpeg::Error::IntegerOverflow("restart_rows"))?;
+
+    let handle = RawHandle::new(raw::TJINIT_TJINIT_COMPRESS)?;
+    handle.set(raw::TJPARAM_TJPARAM_QUALITY, quality)?;
+    handle.set(
+        raw::TJPARAM_TJPARAM_SUBSAMP,
+        raw::TJSAMP_TJSAMP_444 as c_int,
+    )?;
+    handle.set(raw::TJPARAM_TJPARAM_OPTIMIZE, optimize as c_int)?;
+    handle.set(raw::TJPARAM_TJPARAM_RESTARTROWS, restart_rows)?;
+
+    // let turbojpeg allocate the output buffer
+    let mut jpeg_ptr: *mut u8 = std::ptr::null_mut();
+    let mut jpeg_len: raw::size_t = 0;
+
+    // SAFETY: the input buffer holds `height` rows of `pitch` bytes, as checked above
+    let res = unsafe {
+        raw::tj3Compress8(
+            handle.0,
+            buf.pixels.as_ptr(),
+            width,
+            pitch,
+            height,
+            buf.format as c_int,
+            &mut jpeg_ptr,
+            &mut jpeg_len,
+        )
+    };
+
+    if res != 0 || jpeg_ptr.is_null() {
+        // SAFETY: the buffer was allocated by turbojpeg, freeing null is a no-op
+        unsafe { raw::tj3Free(jpeg_ptr.cast()) };
+        return Err(handle.last_error());
+    }
+
+    // SAFETY: turbojpeg wrote `jpeg_len` bytes to the buffer it allocated
+    let jpeg_data = unsafe { std::slice::from_raw_parts(jpeg_ptr, jpeg_len as usize) }.to_vec();
+    unsafe { raw::tj3Free(jpeg_ptr.cast()) };
+
+    Ok(jpeg_data)
+}
+
+/// Reads the ICC color profile embedded in a JPEG image.
+///
+/// Large profiles are split across multiple APP2 segments, each one tagged with
+/// its sequence number. The chunks are reassembled in order into a single blob.
+///
+/// # Arguments
+///
+/// * `jpeg_data` - The raw JPEG data.
+///
+/// # Returns
+///
+/// The ICC profile data, or `None` if the image has no profile or the chunks of
+/// the profile are incomplete.
+pub fn read_icc_profile(jpeg_data: &[u8]) -> Option<Vec<u8>> {
+    // each chunk payload is: "ICC_PROFILE\0", sequence number (1-based), number of chunks, data
+    let mut chunks = read_jpeg_segments(jpeg_data)
+        .ok()?
+        .into_iter()
+        .filter(|segment| segment.is_icc() && segment.payload().len() >= 14)
+        .map(|segment| {
+            let payload = segment.payload();
+            (payload[12], payload[13], &payload[14..])
+        })
+        .collect::<Vec<_>>();
+
+    chunks.sort_by_key(|&(seq_no, _, _)| seq_no);
+
+    // verify all the chunks are present exactly once
+    let num_chunks = chunks.len();
+    let is_complete = num_chunks > 0
+        && chunks.iter().enumerate().all(|(i, &(seq_no, count, _))| {
+            seq_no as usize == i + 1 && count as usize == num_chunks
+        });
+
+    if !is_complete {
+        return None;
+    }
+
+    let profile = chunks
+        .into_iter()
+        .flat_map(|(_, _, data)| data)
+        .copied()
+        .collect();
+
+    Some(profile)
+}
+
+/// Reads the comment stored in a JPEG image.
+///
+/// The comment is read from the first COM segment of the header. Comments that
+/// are not valid UTF-8 are converted replacing the invalid sequences.
+///
+/// # Arguments
+///
+/// * `jpeg_data` - The raw JPEG data.
+///
+/// # Returns
+///
+/// The comment, or `None` if the image has no comment or the header is corrupted.
+pub fn read_comment(jpeg_data: &[u8]) -> Option<String> {
+    read_jpeg_segments(jpeg_data)
+        .ok()?
+        .iter()
+        .find(|segment| segment.marker == 0xFE)
+        .map(|segment| String::from_utf8_lossy(segment.payload()).into_owned())
+}
+
+/// Reads the quantization tables of a JPEG image.
+///
+/// The tables are read from the DQT segments of the header in order of
+/// appearance. Typical color images have two tables, one for the luma and one
+/// for the chroma components, while grayscale images have a single table.
+///
+/// # Arguments
+///
+/// * `jpeg_data` - The raw JPEG data.
+///
+/// # Returns
+///
+/// The quantization tables with the 64 values of each table in zig-zag order.
+///
+/// # Errors
+///
+/// Returns [`JpegTurboError::CorruptData`] if the header or a DQT segment is malformed.
+pub fn read_jpeg_quant_tables(jpeg_data: &[u8]) -> Result<Vec<[u16; 64]>, JpegTurboError> {
+    let segments = read_jpeg_segments(jpeg_data).map_err(JpegTurboError::CorruptData)?;
+
+    let mut tables = Vec::new();
+
+    for segment in segments.iter().filter(|segment| segment.marker == 0xDB) {
+        // a DQT segment holds one or more tables, each one with a byte with the
+        // precision (0: 8-bit, 1: 16-bit) and the destination id, and the values
+        let mut payload = segment.payload();
+        while let Some((&pq_tq, rest)) = payload.split_first() {
+            let precision = (pq_tq >> 4) as usize + 1;
+            let values = rest.get(..64 * precision).ok_or_else(|| {
+                JpegTurboError::CorruptData("truncated quantization table".to_string())
+            })?;
+
+            let mut table = [0u16; 64];
+            for (value, bytes) in table.iter_mut().zip(values.chunks_exact(precision)) {
+                *value = bytes.iter().fold(0u16, |acc, &b| (acc << 8) | b as u16);
+            }
+            tables.push(table);
+
+            payload = &rest[64 * precision..];
+        }
+    }
+
+    Ok(tables)
+}
+
+/// Re-encodes a JPEG image with a new quality preserving its metadata.
+///
+/// The EXIF (APP1) and ICC profile (APP2) segments of the input are extracted,
+/// the image is decoded and encoded again as RGB with the given quality, and the
+/// preserved segments are inserted back after the JFIF header of the output.
+///
+/// # Arguments
+///
+/// * `jpeg_data` - The raw JPEG data.
+/// * `quality` - The JPEG quality of the output in the range [1, 100].
+///
+/// # Returns
+///
+/// The re-encoded JPEG data including the preserved metadata segments.
+///
+/// # Errors
+///
+/// Returns [`JpegTurboError::CorruptData`] if the JPEG header cannot be parsed.
+pub fn recompress_preserving_exif(
+    jpeg_data: &[u8],
+    quality: i32,
+) -> Result<Vec<u8>, JpegTurboError> {
+    // collect the metadata segments to preserve
+    let metadata = read_jpeg_segments(jpeg_data)
+        .map_err(JpegTurboError::CorruptData)?
+        .into_iter()
+        .filter(|segment| segment.is_exif() || segment.is_icc())
+        .collect::<Vec<_>>();
+
+    // decode and encode the image again with the new quality
+    let image = JpegTurboDecoder::new()?.decode_rgb8(jpeg_data)?;
+    let mut encoder = JpegTurboEncoder::new()?;
+    encoder.set_quality(quality)?;
+    let encoded = encoder.encode_rgb8(&image)?;
+
+    // insert the metadata after the SOI marker and the JFIF segment, if any
+    let segments = read_jpeg_segments(&encoded).map_err(JpegTurboError::CorruptData)?;
+    let insert_pos = match segments.first() {
+        Some(segment) if segment.marker == 0xE0 => segment.offset + segment.data.len(),
+        _ => 2,
+    };
+
+    let metadata_len = metadata.iter().map(|s| s.data.len()).sum::<usize>();
+    let mut output = Vec::with_capacity(encoded.len() + metadata_len);
+    output.extend_from_slice(&encoded[..insert_pos]);
+    for segment in metadata.iter() {
+        output.extend_from_slice(segment.data);
+    }
+    output.extend_from_slice(&encoded[insert_pos..]);
+
+    Ok(output)
+}
+
+/// Crops a JPEG image without recompression.
+///
+/// The DCT blocks of the region are copied as they are, so there is no quality
+/// loss. Lossless cropping requires the top-left corner of the region to lie on
+/// the MCU grid of the image (8x8 pixels for grayscale and 4:4:4 images, 16x8
+/// for 4:2:2 and 16x16 for 4:2:0), so `x` and `y` are snapped down to the
+/// nearest MCU boundary and the region is extended to keep its bottom-right
+/// corner at `(x + w, y + h)`. The output is therefore up to one MCU wider and
+/// taller than requested.
+///
+/// # Arguments
+///
+/// * `jpeg_data` - The raw JPEG data.
+/// * `x` - The x-coordinate of the top-left corner of the region.
+/// * `y` - The y-coordinate of the top-left corner of the region.
+/// * `w` - The width of the region.
+/// * `h` - The height of the region.
+///
+/// # Returns
+///
+/// The JPEG data of the cropped image.
+///
+/// # Errors
+///
+/// Returns [`JpegTurboError::ImageCreationError`] if the region is empty or
+/// exceeds the image bounds, and [`JpegTurboError::CorruptData`] if the JPEG
+/// data cannot be parsed.
+pub fn crop_jpeg_lossless(
+    jpeg_data: &[u8],
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+) -> Result<Vec<u8>, JpegTurboError> {
+    let header = turbojpeg::read_header(jpeg_data).map_err(map_decode_error)?;
+
+    let exceeds =
+        |start: usize, len: usize, max: usize| start.checked_add(len).map_or(true, |end| end > max);
+
+    if w == 0 || h == 0 || exceeds(x, w, header.width) || exceeds(y, h, header.height) {
+        return Err(ImageError::OutOfBounds(x, y, w, h, header.width, header.height).into());
+    }
+
+    // snap the origin to the MCU grid keeping the bottom-right corner
+    let (mcu_width, mcu_height) = header.subsamp.mcu_size();
+    let (x0, y0) = (x - x % mcu_width, y - y % mcu_height);
+
+    let mut transform = turbojpeg::Transform::default();
+    transform.crop = Some(turbojpeg::TransformCrop {
+        x: x0,
+        y: y0,
+        width: Some(x + w - x0),
+        height: Some(y + h - y0),
+    });
+
+    turbojpeg::Transformer::new()?
+        .transform_to_vec(&transform, jpeg_data)
+        .map_err(map_decode_error)
+}
 
 #[cfg(test)]
 mod tests {
+    use crate::jpeg_markers::read_jpeg_segments;
     use crate::jpegturbo::{JpegTurboDecoder, JpegTurboEncoder, JpegTurboError};
     use kornia_image::{Image, ImageSize};
 
@@ -271,6 +1557,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn image_decoder_with_info() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg").unwrap();
+        let mut decoder = JpegTurboDecoder::new()?;
+        let header = decoder.read_header_full(&jpeg_data)?;
+        let (image, header_info) = decoder.decode_rgb8_with_info(&jpeg_data)?;
+        assert_eq!(header_info, header);
+        assert_eq!(image.size(), header.size);
+        assert_eq!(image.num_channels(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn image_decoder_from_reader() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let mut reader = std::io::Cursor::new(jpeg_data);
+        let image = JpegTurboDecoder::new()?.decode_rgb8_from_reader(&mut reader)?;
+        assert_eq!(image.cols(), 258);
+        assert_eq!(image.rows(), 195);
+        assert_eq!(image.num_channels(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn image_decoder_corrupt_data() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg").unwrap();
+        let result = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data[..100]);
+        assert!(matches!(result, Err(JpegTurboError::CorruptData(_))));
+        let result = JpegTurboDecoder::new()?.decode_gray8(&jpeg_data[..100]);
+        assert!(matches!(result, Err(JpegTurboError::CorruptData(_))));
+        Ok(())
+    }
+
     #[test]
     fn image_encoder() -> Result<(), Box<dyn std::error::Error>> {
         let jpeg_data_fs = std::fs::read("../../tests/data/dog.jpeg")?;
@@ -283,6 +1602,805 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn image_encoder_into() -> Result<(), Box<dyn std::error::Error>> {
+        let jpeg_data_fs = std::fs::read("../../tests/data/dog.jpeg")?;
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data_fs)?;
+        let small = Image::<u8, 3>::from_size_val(
+            ImageSize {
+                width: 16,
+                height: 8,
+            },
+            128,
+        )?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let mut decoder = JpegTurboDecoder::new()?;
+        let mut out = Vec::new();
+
+        encoder.encode_rgb8_into(&image, &mut out)?;
+        let image_back = decoder.decode_rgb8(&out)?;
+        assert_eq!(image_back.size(), image.size());
+
+        // the same buffer is reused for a smaller image
+        encoder.encode_rgb8_into(&small, &mut out)?;
+        let small_back = decoder.decode_rgb8(&out)?;
+        assert_eq!(small_back.size(), small.size());
+        assert_eq!(out, encoder.encode_rgb8(&small)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recompress_preserving_exif() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg").unwrap();
+
+        // inject an EXIF and an ICC segment after the JFIF header
+        let exif = [
+            &[0xFF, 0xE1, 0x00, 0x16][..],
+            b"Exif\0\0",
+            b"MM\0\x2a\0\0\0\x08\0\0\0\0\0\0",
+        ]
+        .concat();
+        let icc = [
+            &[0xFF, 0xE2, 0x00, 0x14][..],
+            b"ICC_PROFILE\0\x01\x01",
+            b"fake",
+        ]
+        .concat();
+        let jfif_end = 4 + u16::from_be_bytes([jpeg_data[4], jpeg_data[5]]) as usize;
+        let input = [&jpeg_data[..jfif_end], &exif, &icc, &jpeg_data[jfif_end..]].concat();
+
+        let output = super::recompress_preserving_exif(&input, 50)?;
+
+        let segments = read_jpeg_segments(&output).map_err(JpegTurboError::CorruptData)?;
+        assert_eq!(segments[0].marker, 0xE0);
+        let exif_back = segments.iter().find(|s| s.is_exif()).map(|s| s.data);
+        assert_eq!(exif_back, Some(&exif[..]));
+        let icc_back = segments.iter().find(|s| s.is_icc()).map(|s| s.data);
+        assert_eq!(icc_back, Some(&icc[..]));
+
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&output)?;
+        assert_eq!(image.cols(), 258);
+        assert_eq!(image.rows(), 195);
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_target_psnr() -> Result<(), Box<dyn std::error::Error>> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let (encoded, quality) = encoder.encode_rgb8_target_psnr(&image, 35.0)?;
+        assert!((1..=100).contains(&quality));
+
+        let decoded = JpegTurboDecoder::new()?.decode_rgb8(&encoded)?;
+        let psnr = crate::metrics::psnr_u8(&image, &decoded)?;
+        assert!(psnr >= 35.0, "psnr: {psnr}");
+
+        // a lossless round-trip is not reachable and the quality is kept
+        let result = encoder.encode_rgb8_target_psnr(&image, f64::INFINITY);
+        assert!(matches!(
+            result,
+            Err(JpegTurboError::TargetPsnrNotReached(_, _))
+        ));
+        assert_eq!(encoder.quality, quality);
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_target_size() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let encoded = encoder.encode_rgb8_target_size(&image, 4000)?;
+        assert!(!encoded.is_empty());
+        assert!(encoded.len() <= 4000, "size: {}", encoded.len());
+
+        // a larger budget allows a higher quality
+        let larger = encoder.encode_rgb8_target_size(&image, 20000)?;
+        assert!(larger.len() > encoded.len());
+        assert!(larger.len() <= 20000, "size: {}", larger.len());
+
+        // an unreachable budget returns the smallest attempt
+        let smallest = encoder.encode_rgb8_target_size(&image, 1)?;
+        assert!(smallest.len() <= encoded.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_decoder_dc_preview() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let mut decoder = JpegTurboDecoder::new()?;
+
+        // 258x195 is reduced to ceil(258 / 8) x ceil(195 / 8)
+        let preview = decoder.decode_dc_preview(&jpeg_data)?;
+        assert_eq!(preview.cols(), 33);
+        assert_eq!(preview.rows(), 25);
+
+        // the next decodes are at full scale
+        let image = decoder.decode_rgb8(&jpeg_data)?;
+        assert_eq!(image.cols(), 258);
+        assert_eq!(image.rows(), 195);
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_optimize() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let default = encoder.encode_rgb8(&image)?;
+
+        encoder.set_optimize(true)?;
+        let optimized = encoder.encode_rgb8(&image)?;
+        assert!(optimized.len() <= default.len());
+
+        // the setting persists across encodes
+        assert_eq!(encoder.encode_rgb8(&image)?, optimized);
+
+        let image_back = JpegTurboDecoder::new()?.decode_rgb8(&optimized)?;
+        assert_eq!(image_back.size(), image.size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_jpeg_quant_tables() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+
+        // a color image has a luma and a chroma table
+        let tables = super::read_jpeg_quant_tables(&jpeg_data)?;
+        assert_eq!(tables.len(), 2);
+        for table in &tables {
+            assert!(table.iter().all(|&v| (1..=255).contains(&v)));
+        }
+
+        // a grayscale image has a single table
+        let image = Image::<u8, 1>::from_size_val(
+            ImageSize {
+                width: 16,
+                height: 16,
+            },
+            128,
+        )?;
+        let mut encoder = JpegTurboEncoder::new()?;
+        encoder.set_quality(50)?;
+        let tables = super::read_jpeg_quant_tables(&encoder.encode_gray8(&image)?)?;
+        assert_eq!(tables.len(), 1);
+        // the standard luma table scaled to quality 50 starts with the DC value 16
+        assert_eq!(tables[0][0], 16);
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_with_quant_tables() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+
+        // the tables of an image encoded by libjpeg-turbo
+        let mut encoder = JpegTurboEncoder::new()?;
+        encoder.set_quality(63)?;
+        let tables = super::read_jpeg_quant_tables(&encoder.encode_rgb8(&image)?)?;
+        assert_eq!(tables.len(), 2);
+
+        encoder.set_quality(90)?;
+        let encoded = encoder.encode_rgb8_with_quant_tables(&image, tables[0], tables[1])?;
+        assert_eq!(super::read_jpeg_quant_tables(&encoded)?, tables);
+
+        // the fixture was not encoded with the standard tables
+        let tables = super::read_jpeg_quant_tables(&jpeg_data)?;
+        let result = encoder.encode_rgb8_with_quant_tables(&image, tables[0], tables[1]);
+        assert!(matches!(
+            result,
+            Err(JpegTurboError::UnsupportedQuantTables(_))
+        ));
+
+        let result = encoder.encode_rgb8_with_quant_tables(&image, [0; 64], [1; 64]);
+        assert!(matches!(
+            result,
+            Err(JpegTurboError::UnsupportedQuantTables(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_icc_profile() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        assert_eq!(super::read_icc_profile(&jpeg_data), None);
+
+        // a fake profile with a valid ICC header signature
+        let mut profile = (0..300).map(|i| i as u8).collect::<Vec<_>>();
+        profile[36..40].copy_from_slice(b"acsp");
+
+        // split the profile in two chunks written out of order
+        let icc_segment = |seq_no: u8, data: &[u8]| {
+            let len = (2 + 14 + data.len()) as u16;
+            let mut segment = vec![0xFF, 0xE2];
+            segment.extend_from_slice(&len.to_be_bytes());
+            segment.extend_from_slice(b"ICC_PROFILE\0");
+            segment.extend_from_slice(&[seq_no, 2]);
+            segment.extend_from_slice(data);
+            segment
+        };
+        let jfif_end = 4 + u16::from_be_bytes([jpeg_data[4], jpeg_data[5]]) as usize;
+        let input = [
+            &jpeg_data[..jfif_end],
+            &icc_segment(2, &profile[200..]),
+            &icc_segment(1, &profile[..200]),
+            &jpeg_data[jfif_end..],
+        ]
+        .concat();
+
+        let profile_back = super::read_icc_profile(&input).expect("missing ICC profile");
+        assert_eq!(&profile_back[36..40], b"acsp");
+        assert_eq!(profile_back, profile);
+
+        // a missing chunk invalidates the profile
+        let input = [
+            &jpeg_data[..jfif_end],
+            &icc_segment(2, &profile[200..]),
+            &jpeg_data[jfif_end..],
+        ]
+        .concat();
+        assert_eq!(super::read_icc_profile(&input), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_decoder_fast_dct() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let mut decoder = JpegTurboDecoder::new()?;
+
+        let accurate = decoder.decode_rgb8(&jpeg_data)?;
+
+        decoder.set_fast_dct(true);
+        let fast = decoder.decode_rgb8(&jpeg_data)?;
+        assert_eq!(fast.size(), accurate.size());
+        assert_eq!(fast.num_channels(), 3);
+
+        // the fast DCT only differs by small rounding errors
+        let diffs = fast
+            .as_slice()
+            .iter()
+            .zip(accurate.as_slice().iter())
+            .map(|(&a, &b)| a.abs_diff(b) as u64)
+            .collect::<Vec<_>>();
+        let max_diff = diffs.iter().max().copied().unwrap_or(0);
+        let mean_diff = diffs.iter().sum::<u64>() as f64 / diffs.len() as f64;
+        assert!(max_diff <= 32, "max diff: {max_diff}");
+        assert!(mean_diff < 2.0, "mean diff: {mean_diff}");
+
+        let gray = decoder.decode_gray8(&jpeg_data)?;
+        assert_eq!(gray.size(), accurate.size());
+
+        // disabling it restores the accurate decode
+        decoder.set_fast_dct(false);
+        assert_eq!(
+            decoder.decode_rgb8(&jpeg_data)?.as_slice(),
+            accurate.as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn crop_jpeg_lossless() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let mut decoder = JpegTurboDecoder::new()?;
+        let full = decoder.decode_rgb8(&jpeg_data)?;
+
+        let (x, y, w, h) = (37, 21, 100, 80);
+        let cropped_data = super::crop_jpeg_lossless(&jpeg_data, x, y, w, h)?;
+        let cropped = decoder.decode_rgb8(&cropped_data)?;
+
+        // the origin is snapped down to the MCU grid
+        let (mcu_width, mcu_height) = decoder.read_header_full(&jpeg_data)?.subsamp.mcu_size();
+        let (x0, y0) = (x - x % mcu_width, y - y % mcu_height);
+        assert_eq!(cropped.cols(), x + w - x0);
+        assert_eq!(cropped.rows(), y + h - y0);
+
+        // the blocks are copied as they are, the pixels only differ by the
+        // chroma upsampling at the new image borders
+        let expected = full.crop(x0, y0, cropped.cols(), cropped.rows())?;
+        let diffs = cropped
+            .as_slice()
+            .iter()
+            .zip(expected.as_slice().iter())
+            .map(|(&a, &b)| a.abs_diff(b) as u64)
+            .collect::<Vec<_>>();
+        let mean_diff = diffs.iter().sum::<u64>() as f64 / diffs.len() as f64;
+        assert!(mean_diff < 1.0, "mean diff: {mean_diff}");
+
+        // away from the new borders the decoded pixels are identical
+        let (cols, rows) = (cropped.cols(), cropped.rows());
+        for py in mcu_height..rows - mcu_height {
+            for px in mcu_width..cols - mcu_width {
+                for ch in 0..3 {
+                    assert_eq!(
+                        cropped.get_pixel(px, py, ch)?,
+                        full.get_pixel(x0 + px, y0 + py, ch)?,
+                        "pixel ({px}, {py}, {ch})"
+                    );
+                }
+            }
+        }
+
+        assert!(super::crop_jpeg_lossless(&jpeg_data, 200, 0, 100, 10).is_err());
+        assert!(super::crop_jpeg_lossless(&jpeg_data, 0, 0, 0, 10).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_density() -> Result<(), JpegTurboError> {
+        let image = Image::<u8, 3>::from_size_val(
+            ImageSize {
+                width: 32,
+                height: 16,
+            },
+            100,
+        )?;
+
+        // the JFIF units and the horizontal and vertical densities
+        let jfif_density = |jpeg_data: &[u8]| -> Result<(u8, u16, u16), JpegTurboError> {
+            let segments = read_jpeg_segments(jpeg_data).map_err(JpegTurboError::CorruptData)?;
+            let jfif = segments
+                .iter()
+                .find(|segment| segment.marker == 0xE0 && segment.payload().starts_with(b"JFIF\0"))
+                .ok_or_else(|| JpegTurboError::CorruptData("missing JFIF segment".to_string()))?
+                .payload();
+            Ok((
+                jfif[7],
+                u16::from_be_bytes([jfif[8], jfif[9]]),
+                u16::from_be_bytes([jfif[10], jfif[11]]),
+            ))
+        };
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let jpeg_data = encoder.encode_rgb8(&image)?;
+        assert_eq!(jfif_density(&jpeg_data)?, (0, 1, 1));
+
+        encoder.set_density(300, 300);
+        let jpeg_data = encoder.encode_rgb8(&image)?;
+        assert_eq!(jfif_density(&jpeg_data)?, (1, 300, 300));
+
+        let mut out = Vec::new();
+        encoder.set_density(72, 144);
+        encoder.encode_rgb8_into(&image, &mut out)?;
+        assert_eq!(jfif_density(&out)?, (1, 72, 144));
+
+        // the density does not change the pixels
+        let decoded = JpegTurboDecoder::new()?.decode_rgb8(&out)?;
+        assert_eq!(decoded.size(), image.size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_comment() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let plain = encoder.encode_rgb8(&image)?;
+        assert_eq!(super::read_comment(&plain), None);
+
+        let comment = "generated by kornia, seed=42 ✓";
+        encoder.set_comment(comment)?;
+        let tagged = encoder.encode_rgb8(&image)?;
+        assert_eq!(super::read_comment(&tagged).as_deref(), Some(comment));
+
+        // the comment follows the JFIF header
+        let markers = read_jpeg_segments(&tagged)
+            .map_err(JpegTurboError::CorruptData)?
+            .iter()
+            .map(|segment| segment.marker)
+            .collect::<Vec<_>>();
+        assert_eq!(&markers[..2], &[0xE0, 0xFE]);
+
+        // the first comment is returned when there are several
+        let mut twice = tagged.clone();
+        twice.splice(2..2, [0xFF, 0xFE, 0x00, 0x07, b'f', b'i', b'r', b's', b't']);
+        assert_eq!(super::read_comment(&twice).as_deref(), Some("first"));
+
+        // the comment does not change the pixels
+        let mut decoder = JpegTurboDecoder::new()?;
+        assert_eq!(
+            decoder.decode_rgb8(&tagged)?.as_slice(),
+            decoder.decode_rgb8(&plain)?.as_slice()
+        );
+
+        assert!(matches!(
+            encoder.set_comment(&"x".repeat(65534)),
+            Err(JpegTurboError::CommentTooLong(65534))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_no_markers() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let image = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let with_markers = encoder.encode_rgb8(&image)?;
+
+        encoder.set_no_markers(true);
+        encoder.set_density(300, 300);
+        let minimal = encoder.encode_rgb8(&image)?;
+        assert!(minimal.len() < with_markers.len());
+
+        let markers = |jpeg_data: &[u8]| -> Result<Vec<u8>, JpegTurboError> {
+            Ok(read_jpeg_segments(jpeg_data)
+                .map_err(JpegTurboError::CorruptData)?
+                .iter()
+                .map(|segment| segment.marker)
+                .collect())
+        };
+        assert!(markers(&with_markers)?.contains(&0xE0));
+        assert!(!markers(&minimal)?
+            .iter()
+            .any(|marker| (0xE0..=0xEF).contains(marker)));
+
+        // the pixels are the same without the JFIF header
+        let mut decoder = JpegTurboDecoder::new()?;
+        let decoded = decoder.decode_rgb8(&minimal)?;
+        assert_eq!(decoded.size(), image.size());
+        assert_eq!(
+            decoded.as_slice(),
+            decoder.decode_rgb8(&with_markers)?.as_slice()
+        );
+
+        let mut out = Vec::new();
+        encoder.encode_rgb8_into(&image, &mut out)?;
+        assert_eq!(out, minimal);
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_decoder_cmyk() -> Result<(), JpegTurboError> {
+        let size = ImageSize {
+            width: 32,
+            height: 16,
+        };
+
+        // the left half is red and the right half is dark blue, with the
+        // inverted values written by Adobe applications (255 means no ink)
+        let cmyk = (0..size.width * size.height)
+            .flat_map(|i| {
+                if i % size.width < 16 {
+                    [255, 0, 0, 255]
+                } else {
+                    [0, 0, 255, 127]
+                }
+            })
+            .collect::<Vec<u8>>();
+
+        let mut decoder = JpegTurboDecoder::new()?;
+
+        for colorspace in [turbojpeg::Colorspace::YCCK, turbojpeg::Colorspace::CMYK] {
+            let mut compressor = turbojpeg::Compressor::new()?;
+            compressor.set_quality(95)?;
+            compressor.set_colorspace(colorspace)?;
+            let jpeg_data = compressor.compress_to_vec(turbojpeg::Image {
+                pixels: cmyk.as_slice(),
+                width: size.width,
+                pitch: 4 * size.width,
+                height: size.height,
+                format: turbojpeg::PixelFormat::CMYK,
+            })?;
+            assert!(super::has_adobe_marker(&jpeg_data));
+
+            let (image, header) = decoder.decode_rgb8_with_info(&jpeg_data)?;
+            assert_eq!(header.colorspace, colorspace);
+            assert_eq!(image.size(), size);
+
+            let pixel = |x: usize| -> Result<[u8; 3], JpegTurboError> {
+                Ok([
+                    *image.get_pixel(x, 8, 0)?,
+                    *image.get_pixel(x, 8, 1)?,
+                    *image.get_pixel(x, 8, 2)?,
+                ])
+            };
+            let close = |a: [u8; 3], b: [u8; 3]| a.iter().zip(b).all(|(a, b)| a.abs_diff(b) <= 8);
+            assert!(close(pixel(4)?, [255, 0, 0]), "{:?}", pixel(4)?);
+            assert!(close(pixel(28)?, [0, 0, 127]), "{:?}", pixel(28)?);
+
+            assert!(matches!(
+                decoder.decode_gray8(&jpeg_data),
+                Err(JpegTurboError::UnsupportedColorspace(_))
+            ));
+        }
+
+        // the values are the amount of ink without Adobe segment
+        assert_eq!(
+            super::cmyk_to_rgb(&[0, 255, 255, 0, 0, 0, 0, 255], false),
+            vec![255, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            super::cmyk_to_rgb(&[255, 0, 0, 255, 255, 255, 255, 0], true),
+            vec![255, 0, 0, 0, 0, 0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_decoder_hash() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+
+        // insert a comment (COM) segment after the SOI marker
+        let comment = b"kornia";
+        let mut commented = jpeg_data[..2].to_vec();
+        commented.extend_from_slice(&[0xFF, 0xFE, 0x00, comment.len() as u8 + 2]);
+        commented.extend_from_slice(comment);
+        commented.extend_from_slice(&jpeg_data[2..]);
+
+        let mut decoder = JpegTurboDecoder::new()?;
+        let (image, hash) = decoder.decode_and_hash(&jpeg_data)?;
+        let (image_commented, hash_commented) = decoder.decode_and_hash(&commented)?;
+
+        assert_eq!(image.as_slice(), image_commented.as_slice());
+        assert_eq!(hash, hash_commented);
+
+        // a different image has a different hash
+        let mut encoder = JpegTurboEncoder::new()?;
+        encoder.set_quality(50)?;
+        let (_, hash_other) = decoder.decode_and_hash(&encoder.encode_rgb8(&image)?)?;
+        assert_ne!(hash, hash_other);
+
+        // the reference values of the FNV-1a hash
+        assert_eq!(super::fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(super::fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_scan_ends() {
+        // two scans separated by a DHT table, with a stuffed byte in the second scan
+        let sos = [0xFF, 0xDA, 0x00, 0x08, 1, 1, 0, 0, 63, 0];
+        let mut jpeg_data = vec![0xFF, 0xD8];
+        jpeg_data.extend_from_slice(&sos);
+        jpeg_data.extend_from_slice(&[1, 2, 3]);
+        jpeg_data.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x04, 0, 0]);
+        jpeg_data.extend_from_slice(&sos);
+        jpeg_data.extend_from_slice(&[4, 0xFF, 0x00, 5]);
+        jpeg_data.extend_from_slice(&[0xFF, 0xD9]);
+
+        assert_eq!(super::find_scan_ends(&jpeg_data), Ok(vec![15, 35]));
+        assert!(super::find_scan_ends(&jpeg_data[..34]).is_err());
+    }
+
+    #[test]
+    fn image_decoder_progressive() -> Result<(), JpegTurboError> {
+        let jpeg_data = std::fs::read("../../tests/data/dog.jpeg")?;
+        let mut decoder = JpegTurboDecoder::new()?;
+        let image = decoder.decode_rgb8(&jpeg_data)?;
+
+        // a baseline JPEG reports the complete image once
+        let mut num_scans = 0;
+        let decoded = decoder.decode_rgb8_progressive(&jpeg_data, |_| num_scans += 1)?;
+        assert_eq!(num_scans, 1);
+        assert_eq!(decoded.as_slice(), image.as_slice());
+
+        // encode a progressive JPEG of the image
+        let mut compressor = turbojpeg::Compressor::new()?;
+        compressor.set_progressive(true)?;
+        let progressive = compressor.compress_to_vec(turbojpeg::Image {
+            pixels: image.as_slice(),
+            width: image.width(),
+            pitch: 3 * image.width(),
+            height: image.height(),
+            format: turbojpeg::PixelFormat::RGB,
+        })?;
+        assert!(decoder.read_header_full(&progressive)?.is_progressive);
+
+        let mut partials = Vec::new();
+        let decoded = decoder.decode_rgb8_progressive(&progressive, |partial| {
+            partials.push(partial.clone());
+        })?;
+        assert!(partials.len() > 1, "scans: {}", partials.len());
+
+        // the last scan is the complete image
+        let expected = decoder.decode_rgb8(&progressive)?;
+        assert_eq!(decoded.as_slice(), expected.as_slice());
+        let last = partials
+            .last()
+            .ok_or(JpegTurboError::CorruptData("no scans".into()))?;
+        assert_eq!(last.as_slice(), expected.as_slice());
+        assert!(partials
+            .iter()
+            .all(|partial| partial.size() == image.size()));
+
+        // the first scans are a coarse preview of the image
+        assert_ne!(partials[0].as_slice(), expected.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_strided() -> Result<(), Box<dyn std::error::Error>> {
+        use kornia_tensor::{storage::TensorStorage, CpuAllocator, Tensor};
+
+        // a 32x16 rgb image stored in rows of 40 pixels
+        let (rows, cols, padded_cols) = (16, 32, 40);
+        let data = (0..rows * padded_cols)
+            .flat_map(|i| {
+                let (x, y) = (i % padded_cols, i / padded_cols);
+                [(x * 6) as u8, (y * 12) as u8, 128]
+            })
+            .collect();
+        let image = Image::<u8, 3>(Tensor {
+            storage: TensorStorage::from_vec(data, CpuAllocator),
+            shape: [rows, cols, 3],
+            strides: [padded_cols * 3, 3, 1],
+        });
+        assert!(!image.is_contiguous());
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let jpeg_data = encoder.encode_rgb8(&image)?;
+
+        let decoded = JpegTurboDecoder::new()?.decode_rgb8(&jpeg_data)?;
+        assert_eq!(decoded.cols(), cols);
+        assert_eq!(decoded.rows(), rows);
+
+        // the padding is not encoded
+        let expected = image.to_contiguous();
+        let max_diff = decoded
+            .as_slice()
+            .iter()
+            .zip(expected.as_slice())
+            .map(|(&a, &b)| a.abs_diff(b))
+            .max()
+            .unwrap_or(0);
+        assert!(max_diff < 16, "max diff {max_diff}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_restart_interval() -> Result<(), Box<dyn std::error::Error>> {
+        let image = crate::functional::read_image_any_rgb8("../../tests/data/dog.jpeg")?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        encoder.set_restart_interval(1);
+        let mut jpeg_data = encoder.encode_rgb8(&image)?;
+
+        // the output has a DRI segment and RSTn markers
+        let has_marker = |data: &[u8], marker: fn(u8) -> bool| {
+            data.windows(2).any(|w| w[0] == 0xFF && marker(w[1]))
+        };
+        assert!(has_marker(&jpeg_data, |m| m == 0xDD));
+        assert!(has_marker(&jpeg_data, |m| (0xD0..=0xD7).contains(&m)));
+
+        let mut decoder = JpegTurboDecoder::new()?;
+        let clean = decoder.decode_rgb8(&jpeg_data)?;
+
+        // corrupt a byte of the entropy coded data in the middle of the image
+        let mid = (jpeg_data.len() / 2..jpeg_data.len())
+            .find(|&i| jpeg_data[i - 1] != 0xFF && jpeg_data[i] != 0xFF && jpeg_data[i] != 0xAA)
+            .ok_or("no byte to corrupt")?;
+        jpeg_data[mid] ^= 0x55;
+
+        // the decoder reports the corruption but still fills the image
+        let mut pixels = vec![0u8; clean.as_slice().len()];
+        let buf = turbojpeg::Image {
+            pixels: pixels.as_mut_slice(),
+            width: clean.cols(),
+            pitch: 3 * clean.cols(),
+            height: clean.rows(),
+            format: turbojpeg::PixelFormat::RGB,
+        };
+        let _ = turbojpeg::Decompressor::new()?.decompress(&jpeg_data, buf);
+
+        // the rows before the corruption are intact and the decoder recovers at
+        // the next restart marker, so the bottom of the image is intact too
+        let row_len = 3 * clean.cols();
+        let rows = |data: &[u8], range: std::ops::Range<usize>| {
+            data[range.start * row_len..range.end * row_len].to_vec()
+        };
+        assert_eq!(rows(&pixels, 0..32), rows(clean.as_slice(), 0..32));
+        let bottom = clean.rows() - 32..clean.rows();
+        assert_eq!(
+            rows(&pixels, bottom.clone()),
+            rows(clean.as_slice(), bottom)
+        );
+
+        // disabling the markers restores the default output
+        encoder.set_restart_interval(0);
+        assert!(!has_marker(&encoder.encode_rgb8(&image)?, |m| m == 0xDD));
+
+        Ok(())
+    }
+
+    #[test]
+    fn encode_pixel_format() -> Result<(), JpegTurboError> {
+        use super::PixelFormat;
+
+        let size = ImageSize {
+            width: 16,
+            height: 8,
+        };
+        let rgb = (0..size.width * size.height)
+            .flat_map(|i| [(i * 2) as u8, 128, 255 - (i * 2) as u8])
+            .collect::<Vec<_>>();
+        let bgr = rgb
+            .chunks_exact(3)
+            .flat_map(|px| [px[2], px[1], px[0]])
+            .collect::<Vec<_>>();
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        let mut decoder = JpegTurboDecoder::new()?;
+
+        // the same image in RGB and BGR layouts decodes to the same pixels
+        let from_rgb = decoder.decode_rgb8(&encoder.encode(&rgb, size, PixelFormat::RGB)?)?;
+        let from_bgr = decoder.decode_rgb8(&encoder.encode(&bgr, size, PixelFormat::BGR)?)?;
+        assert_eq!(from_rgb.size(), size);
+        assert_eq!(from_rgb.as_slice(), from_bgr.as_slice());
+
+        // the pitch accounts for the alpha channel
+        let rgba = rgb
+            .chunks_exact(3)
+            .flat_map(|px| [px[0], px[1], px[2], 255])
+            .collect::<Vec<_>>();
+        let from_rgba = decoder.decode_rgb8(&encoder.encode(&rgba, size, PixelFormat::RGBA)?)?;
+        assert_eq!(from_rgba.as_slice(), from_rgb.as_slice());
+
+        let gray = vec![100u8; size.width * size.height];
+        let from_gray = decoder.decode_gray8(&encoder.encode(&gray, size, PixelFormat::GRAY)?)?;
+        assert_eq!(from_gray.size(), size);
+
+        assert!(encoder.encode(&rgb[1..], size, PixelFormat::RGB).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_encoder_gray_alpha() -> Result<(), JpegTurboError> {
+        let size = ImageSize {
+            width: 32,
+            height: 16,
+        };
+
+        // a smooth luminance gradient with a varying alpha
+        let data = (0..size.width * size.height)
+            .flat_map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                [(x * 6 + y * 2) as u8, (i % 256) as u8]
+            })
+            .collect();
+        let image = Image::<u8, 2>::new(size, data)?;
+
+        let mut encoder = JpegTurboEncoder::new()?;
+        encoder.set_quality(95)?;
+        let jpeg_data = encoder.encode_gray_alpha8(&image)?;
+
+        let gray = JpegTurboDecoder::new()?.decode_gray8(&jpeg_data)?;
+        assert_eq!(gray.size(), size);
+        assert!(gray.is_close(&image.channel(0)?, 8.0));
+
+        // the alpha channel is discarded
+        let opaque =
+            Image::<u8, 2>::merge_channels(&[image.channel(0)?, Image::from_size_val(size, 255)?])?;
+        assert_eq!(encoder.encode_gray_alpha8(&opaque)?, jpeg_data);
+
+        Ok(())
+    }
+
     #[test]
     fn image_encoder_decoder_gray() -> Result<(), Box<dyn std::error::Error>> {
         // Create a simple grayscale test image
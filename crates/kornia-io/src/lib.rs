@@ -1,24 +1,67 @@
 #![deny(missing_docs)]
 #![doc = env!("CARGO_PKG_DESCRIPTION")]
 
+/// AVIF image decoding.
+#[cfg(feature = "avif")]
+pub mod avif;
+
+/// BlurHash placeholder encoding and decoding.
+pub mod blurhash;
+
+/// Conversion of raw camera frames to RGB images.
+pub mod convert;
+
 /// Module to handle the error types for the io module.
 pub mod error;
 
+/// OpenEXR HDR image decoding.
+#[cfg(feature = "exr")]
+pub mod exr;
+
 /// Module to handle the camera frame rate.
 pub mod fps_counter;
 
 /// High-level read and write functions for images.
 pub mod functional;
 
+/// GIF image decoding including animated frames.
+#[cfg(feature = "gif")]
+pub mod gif;
+
+/// HEIF/HEIC image decoding.
+#[cfg(feature = "heif")]
+pub mod heif;
+
+// parsing of the JPEG marker segments.
+mod jpeg_markers;
+
 /// TurboJPEG image encoding and decoding.
 #[cfg(feature = "turbojpeg")]
 pub mod jpegturbo;
 
+// image quality metrics used by the encoders.
+mod metrics;
+
+/// Motion JPEG (MJPEG) stream writing.
+#[cfg(feature = "turbojpeg")]
+pub mod mjpeg;
+
+/// Portable FloatMap (PFM) image encoding and decoding.
+pub mod pfm;
+
 /// PNG image encoding and decoding.
 pub mod png;
 
+/// QOI image encoding and decoding.
+#[cfg(feature = "qoi")]
+pub mod qoi;
+
 /// GStreamer video module for real-time video processing.
 #[cfg(feature = "gstreamer")]
 pub mod stream;
 
+/// Multi-page TIFF image encoding and decoding.
+#[cfg(feature = "tiff")]
+pub mod tiff;
+
 pub use crate::error::IoError;
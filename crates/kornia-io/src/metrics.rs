@@ -0,0 +1,53 @@
+use kornia_image::{Image, ImageError};
+
+/// Compute the peak signal-to-noise ratio (PSNR) in dB between two 8-bit images.
+///
+/// Returns `f64::INFINITY` when both images are identical.
+pub(crate) fn psnr_u8<const C: usize>(
+    image1: &Image<u8, C>,
+    image2: &Image<u8, C>,
+) -> Result<f64, ImageError> {
+    if image1.size() != image2.size() {
+        return Err(ImageError::InvalidImageSize(
+            image1.cols(),
+            image1.rows(),
+            image2.cols(),
+            image2.rows(),
+        ));
+    }
+
+    let sq_sum = image1
+        .as_slice()
+        .iter()
+        .zip(image2.as_slice().iter())
+        .map(|(&a, &b)| (a as f64 - b as f64).powi(2))
+        .sum::<f64>();
+
+    let mse = sq_sum / image1.as_slice().len() as f64;
+
+    if mse == 0.0 {
+        return Ok(f64::INFINITY);
+    }
+
+    Ok(10.0 * (255.0 * 255.0 / mse).log10())
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn psnr_u8() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+        let image1 = Image::<u8, 1>::new(size, vec![0, 0])?;
+        let image2 = Image::<u8, 1>::new(size, vec![255, 255])?;
+
+        assert_eq!(super::psnr_u8(&image1, &image1)?, f64::INFINITY);
+        assert_eq!(super::psnr_u8(&image1, &image2)?, 0.0);
+
+        Ok(())
+    }
+}
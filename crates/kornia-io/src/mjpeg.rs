@@ -0,0 +1,145 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use kornia_image::Image;
+
+use crate::{error::IoError, jpegturbo::JpegTurboEncoder};
+
+/// A writer of Motion JPEG (MJPEG) streams.
+///
+/// Each frame is encoded as a complete JPEG image and appended to the file
+/// right after the previous one, without any container, e.g. to dump the
+/// frames of a screen recording. Most video players and tools like `ffmpeg`
+/// read such `.mjpeg` files as a video stream.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::mjpeg::MjpegWriter;
+///
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 64, height: 48 }, 0).unwrap();
+///
+/// let mut writer = MjpegWriter::new("recording.mjpeg").unwrap();
+/// writer.write_frame(&image).unwrap();
+/// writer.finish().unwrap();
+/// ```
+pub struct MjpegWriter {
+    writer: BufWriter<File>,
+    encoder: JpegTurboEncoder,
+    num_frames: usize,
+}
+
+impl MjpegWriter {
+    /// Create a writer of a MJPEG stream, replacing the file if it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the MJPEG file.
+    ///
+    /// # Returns
+    ///
+    /// A new `MjpegWriter` with no frames.
+    pub fn new(file_path: impl AsRef<Path>) -> Result<Self, IoError> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(file_path)?),
+            encoder: JpegTurboEncoder::new()?,
+            num_frames: 0,
+        })
+    }
+
+    /// Sets the quality of the JPEG encoding of the next frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `quality` - The quality in the range [1, 100].
+    pub fn set_quality(&mut self, quality: i32) -> Result<(), IoError> {
+        Ok(self.encoder.set_quality(quality)?)
+    }
+
+    /// Encode an image and append it to the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The RGB8 frame to write. The frames may have different sizes.
+    pub fn write_frame(&mut self, image: &Image<u8, 3>) -> Result<(), IoError> {
+        let jpeg_data = self.encoder.encode_rgb8(image)?;
+        self.writer.write_all(&jpeg_data)?;
+        self.num_frames += 1;
+        Ok(())
+    }
+
+    /// Get the number of frames written to the stream.
+    pub fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+
+    /// Flush the pending data and close the file.
+    ///
+    /// The data is also flushed when the writer is dropped, but the errors
+    /// are then ignored.
+    pub fn finish(mut self) -> Result<(), IoError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MjpegWriter;
+    use crate::{error::IoError, jpegturbo::JpegTurboDecoder};
+    use kornia_image::{Image, ImageSize};
+
+    #[test]
+    fn write_mjpeg() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("recording.mjpeg");
+
+        let size = ImageSize {
+            width: 64,
+            height: 48,
+        };
+
+        let mut writer = MjpegWriter::new(&file_path)?;
+        for i in 0..3u8 {
+            let image = Image::<u8, 3>::from_size_val(size, 40 * (i + 1))?;
+            writer.write_frame(&image)?;
+        }
+        assert_eq!(writer.num_frames(), 3);
+        writer.finish()?;
+
+        // split the stream at the start of image (SOI) and end of image (EOI)
+        // markers, which do not appear within the entropy coded data
+        let data = std::fs::read(&file_path)?;
+        let mut frames = Vec::new();
+        let mut start = None;
+        for i in 0..data.len().saturating_sub(1) {
+            match (data[i], data[i + 1], start) {
+                (0xFF, 0xD8, None) => start = Some(i),
+                (0xFF, 0xD9, Some(s)) => {
+                    frames.push(&data[s..i + 2]);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames.iter().map(|f| f.len()).sum::<usize>(), data.len());
+
+        let mut decoder = JpegTurboDecoder::new()?;
+        for (i, frame) in frames.iter().enumerate() {
+            let image = decoder.decode_rgb8(frame)?;
+            assert_eq!(image.size(), size);
+            let value = *image.get_pixel(10, 10, 0)? as i32;
+            assert!(
+                (value - 40 * (i as i32 + 1)).abs() <= 2,
+                "frame {i}: {value}"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,302 @@
+use std::path::Path;
+
+use kornia_image::{Image, ImageSize};
+
+use crate::error::IoError;
+
+// the PFM specification: https://www.pauldebevec.com/Research/HDR/PFM/
+const PFM_MAGIC_GRAY: &[u8; 2] = b"Pf";
+const PFM_MAGIC_RGB: &[u8; 2] = b"PF";
+
+/// Read a PFM image with a single float channel (gray32f).
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the PFM file.
+///
+/// # Returns
+///
+/// A grayscale image with a single float channel, e.g. a disparity map.
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, does not have a `pfm`
+/// extension, is malformed or is not a grayscale (`Pf`) PFM.
+pub fn read_image_pfm_gray_f32(file_path: impl AsRef<Path>) -> Result<Image<f32, 1>, IoError> {
+    read_pfm_impl(file_path)
+}
+
+/// Read a PFM image with three float channels (rgb32f).
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the PFM file.
+///
+/// # Returns
+///
+/// A RGB image with three float channels.
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, does not have a `pfm`
+/// extension, is malformed or is not a color (`PF`) PFM.
+pub fn read_image_pfm_rgb_f32(file_path: impl AsRef<Path>) -> Result<Image<f32, 3>, IoError> {
+    read_pfm_impl(file_path)
+}
+
+/// Write a grayscale image with a single float channel (gray32f) to a PFM file.
+///
+/// The samples are written in little-endian order.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to save the PFM file.
+/// * `src` - The grayscale image to save.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::pfm::{read_image_pfm_gray_f32, write_image_pfm_gray_f32};
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 2,
+///         height: 1,
+///     },
+///     vec![0.5, -12.25],
+/// ).unwrap();
+///
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.pfm");
+///
+/// write_image_pfm_gray_f32(&file_path, &image).unwrap();
+///
+/// let image_back = read_image_pfm_gray_f32(&file_path).unwrap();
+/// assert_eq!(image_back.as_slice(), image.as_slice());
+/// ```
+pub fn write_image_pfm_gray_f32(
+    file_path: impl AsRef<Path>,
+    src: &Image<f32, 1>,
+) -> Result<(), IoError> {
+    write_pfm_impl(file_path, src)
+}
+
+/// Write a RGB image with three float channels (rgb32f) to a PFM file.
+///
+/// The samples are written in little-endian order.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to save the PFM file.
+/// * `src` - The RGB image to save.
+pub fn write_image_pfm_rgb_f32(
+    file_path: impl AsRef<Path>,
+    src: &Image<f32, 3>,
+) -> Result<(), IoError> {
+    write_pfm_impl(file_path, src)
+}
+
+// encode the interleaved samples with `C` channels (1 or 3) into a little-endian PFM stream
+fn encode_pfm<const C: usize>(samples: &[f32], size: ImageSize) -> Vec<u8> {
+    let magic = if C == 1 {
+        PFM_MAGIC_GRAY
+    } else {
+        PFM_MAGIC_RGB
+    };
+    // a negative scale declares little-endian samples
+    let header = format!("\n{} {}\n-1.0\n", size.width, size.height);
+
+    let mut bytes = Vec::with_capacity(magic.len() + header.len() + samples.len() * 4);
+    bytes.extend_from_slice(magic);
+    bytes.extend_from_slice(header.as_bytes());
+
+    // the rows are stored from bottom to top
+    if size.width > 0 {
+        for row in samples.chunks_exact(size.width * C).rev() {
+            bytes.extend(row.iter().flat_map(|v| v.to_le_bytes()));
+        }
+    }
+
+    bytes
+}
+
+// decode a PFM stream into interleaved samples with `C` channels (1 or 3)
+fn decode_pfm<const C: usize>(bytes: &[u8]) -> Result<(Vec<f32>, ImageSize), IoError> {
+    let magic = if C == 1 {
+        PFM_MAGIC_GRAY
+    } else {
+        PFM_MAGIC_RGB
+    };
+    if !bytes.starts_with(magic) {
+        return Err(IoError::CorruptImageData(format!(
+            "expected a PFM stream starting with {:?}",
+            String::from_utf8_lossy(magic)
+        )));
+    }
+
+    // the width, the height and the scale are separated by whitespace
+    let mut pos = magic.len();
+    let mut next_token = || -> Result<&str, IoError> {
+        while bytes.get(pos).is_some_and(u8::is_ascii_whitespace) {
+            pos += 1;
+        }
+        let start = pos;
+        while bytes.get(pos).is_some_and(|b| !b.is_ascii_whitespace()) {
+            pos += 1;
+        }
+        std::str::from_utf8(&bytes[start..pos])
+            .ok()
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| IoError::CorruptImageData("truncated PFM header".to_string()))
+    };
+
+    let invalid = |name: &str| IoError::CorruptImageData(format!("invalid PFM {name}"));
+    let width = next_token()?
+        .parse::<usize>()
+        .map_err(|_| invalid("width"))?;
+    let height = next_token()?
+        .parse::<usize>()
+        .map_err(|_| invalid("height"))?;
+    let scale = next_token()?.parse::<f32>().map_err(|_| invalid("scale"))?;
+    if scale == 0.0 || !scale.is_finite() {
+        return Err(invalid("scale"));
+    }
+
+    // a single whitespace character separates the header from the samples
+    let data = bytes.get(pos + 1..).unwrap_or_default();
+
+    let row_len = width * C;
+    let expected = row_len
+        .checked_mul(height)
+        .and_then(|len| len.checked_mul(4))
+        .ok_or_else(|| invalid("size"))?;
+    if data.len() < expected {
+        return Err(IoError::CorruptImageData(format!(
+            "expected {expected} bytes of PFM samples, got {}",
+            data.len()
+        )));
+    }
+
+    // a negative scale means little-endian samples
+    let read_sample = |b: &[u8]| {
+        let b = [b[0], b[1], b[2], b[3]];
+        if scale < 0.0 {
+            f32::from_le_bytes(b)
+        } else {
+            f32::from_be_bytes(b)
+        }
+    };
+
+    let mut samples = Vec::with_capacity(row_len * height);
+    if row_len > 0 {
+        // the rows are stored from bottom to top
+        for row in data[..expected].chunks_exact(row_len * 4).rev() {
+            samples.extend(row.chunks_exact(4).map(read_sample));
+        }
+    }
+
+    Ok((samples, ImageSize { width, height }))
+}
+
+// verify the file exists and has the pfm extension
+fn check_pfm_path(file_path: &Path) -> Result<(), IoError> {
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    if file_path
+        .extension()
+        .map_or(true, |ext| !ext.eq_ignore_ascii_case("pfm"))
+    {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+
+    Ok(())
+}
+
+// utility function to read the pfm file
+fn read_pfm_impl<const C: usize>(file_path: impl AsRef<Path>) -> Result<Image<f32, C>, IoError> {
+    let file_path = file_path.as_ref();
+    check_pfm_path(file_path)?;
+
+    let bytes = std::fs::read(file_path)?;
+    let (samples, size) = decode_pfm::<C>(&bytes)?;
+
+    Ok(Image::new(size, samples)?)
+}
+
+// utility function to write the pfm file
+fn write_pfm_impl<const C: usize>(
+    file_path: impl AsRef<Path>,
+    src: &Image<f32, C>,
+) -> Result<(), IoError> {
+    std::fs::write(file_path, encode_pfm::<C>(src.as_slice(), src.size()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use crate::pfm::{
+        read_image_pfm_gray_f32, read_image_pfm_rgb_f32, write_image_pfm_gray_f32,
+        write_image_pfm_rgb_f32,
+    };
+    use kornia_image::{Image, ImageSize};
+
+    #[test]
+    fn write_read_pfm() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let size = ImageSize {
+            width: 5,
+            height: 3,
+        };
+
+        let data = (0..size.width * size.height)
+            .map(|i| i as f32 * 1.5 - 7.25)
+            .collect();
+        let disparity = Image::<f32, 1>::new(size, data)?;
+        let file_path = tmp_dir.path().join("disparity.pfm");
+        write_image_pfm_gray_f32(&file_path, &disparity)?;
+        let disparity_back = read_image_pfm_gray_f32(&file_path)?;
+        assert_eq!(disparity_back.size(), size);
+        assert_eq!(disparity_back.as_slice(), disparity.as_slice());
+
+        // the channels do not match the magic bytes
+        let result = read_image_pfm_rgb_f32(&file_path);
+        assert!(matches!(result, Err(IoError::CorruptImageData(_))));
+
+        let data = (0..size.width * size.height * 3)
+            .map(|i| (i as f32).sqrt() * 1e6)
+            .collect();
+        let image = Image::<f32, 3>::new(size, data)?;
+        let file_path = tmp_dir.path().join("image.pfm");
+        write_image_pfm_rgb_f32(&file_path, &image)?;
+        let image_back = read_image_pfm_rgb_f32(&file_path)?;
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_pfm_big_endian() -> Result<(), IoError> {
+        // a 2x2 big-endian file with the rows stored from bottom to top
+        let mut bytes = b"Pf\n2 2\n1.0\n".to_vec();
+        for v in [3.0f32, 4.0, 1.0, 2.0] {
+            bytes.extend_from_slice(&v.to_be_bytes());
+        }
+
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("big_endian.pfm");
+        std::fs::write(&file_path, &bytes)?;
+
+        let image = read_image_pfm_gray_f32(&file_path)?;
+        assert_eq!(image.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+
+        // truncated samples
+        std::fs::write(&file_path, &bytes[..bytes.len() - 1])?;
+        assert!(read_image_pfm_gray_f32(&file_path).is_err());
+
+        Ok(())
+    }
+}
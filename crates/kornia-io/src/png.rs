@@ -1,7 +1,13 @@
-use std::{fs::File, path::Path};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
 
-use kornia_image::Image;
-use png::{Decoder, Encoder, ColorType};
+use flate2::{Compress, Compression, FlushCompress, Status};
+use kornia_image::{Image, ImageError};
+use png::{BitDepth, ColorType, Decoder, Encoder, Reader, Transformations};
+use rayon::prelude::*;
 
 use crate::error::IoError;
 
@@ -33,8 +39,76 @@ pub fn read_image_png_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>,
     Ok(Image::new(size.into(), buf)?)
 }
 
+/// Read a PNG image with three channels (rgb8) reporting the decoding progress.
+///
+/// The callback is invoked with the fraction of the rows decoded so far, in
+/// the range [0.0, 1.0], after each row, e.g. to update a progress bar while a
+/// large image loads. The last call always reports 1.0. Interlaced images are
+/// decoded in a single step and report only the completion.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the PNG file.
+/// * `on_progress` - The function called with the decoding progress.
+///
+/// # Returns
+///
+/// A RGB image with three channels (rgb8).
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, cannot be decoded or is not an
+/// 8-bit RGB PNG.
+pub fn read_image_png_rgb8_with_progress(
+    file_path: impl AsRef<Path>,
+    mut on_progress: impl FnMut(f32),
+) -> Result<Image<u8, 3>, IoError> {
+    let mut reader = open_png_reader(file_path)?;
+
+    let info = reader.info();
+    if info.color_type != ColorType::Rgb || info.bit_depth != BitDepth::Eight {
+        return Err(IoError::PngDecodeError(format!(
+            "expected a 8-bit RGB PNG, got {:?} with {:?} bit depth",
+            info.color_type, info.bit_depth
+        )));
+    }
+
+    let (width, height) = (info.width as usize, info.height as usize);
+    let is_interlaced = info.interlaced;
+
+    let mut buf = vec![0; reader.output_buffer_size()];
+
+    if is_interlaced {
+        reader
+            .next_frame(&mut buf)
+            .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+    } else {
+        // copy the rows one by one reporting the progress after each of them
+        let row_len = width * 3;
+        for (y, dst_row) in buf.chunks_exact_mut(row_len).enumerate() {
+            let row = reader
+                .next_row()
+                .map_err(|e| IoError::PngDecodeError(e.to_string()))?
+                .ok_or_else(|| IoError::PngDecodeError("missing image rows".to_string()))?;
+            dst_row.copy_from_slice(&row.data()[..row_len]);
+
+            if y + 1 < height {
+                on_progress((y + 1) as f32 / height as f32);
+            }
+        }
+    }
+
+    on_progress(1.0);
+
+    Ok(Image::new([width, height].into(), buf)?)
+}
+
 /// Read a PNG image with a four channels (rgba8).
 ///
+/// The alpha channel of the PNG file is preserved. If the file has no alpha
+/// channel, the alpha of every pixel is set to 255 (fully opaque). Grayscale,
+/// palette and 16-bit PNG files are expanded to 8-bit RGBA.
+///
 /// # Arguments
 ///
 /// * `file_path` - The path to the PNG file.
@@ -42,13 +116,54 @@ pub fn read_image_png_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>,
 /// # Returns
 ///
 /// A RGBA image with four channels (rgba8).
+///
+/// # Example
+///
+/// ```
+/// use kornia_io::png::read_image_png_rgba8;
+///
+/// let image = read_image_png_rgba8("../../tests/data/dog.png").unwrap();
+///
+/// assert_eq!(image.cols(), 258);
+/// assert_eq!(image.rows(), 195);
+/// assert_eq!(image.num_channels(), 4);
+/// ```
 pub fn read_image_png_rgba8(file_path: impl AsRef<Path>) -> Result<Image<u8, 4>, IoError> {
-    let (buf, size) = read_png_impl(file_path)?;
-    Ok(Image::new(size.into(), buf)?)
+    // add an opaque alpha channel to the sources without one
+    let mut reader = open_png_reader_with(
+        file_path,
+        Transformations::ALPHA | Transformations::STRIP_16,
+    )?;
+
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+    buf.truncate(info.buffer_size());
+
+    let buf = match info.color_type {
+        ColorType::Rgba => buf,
+        ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .flat_map(|px| [px[0], px[0], px[0], px[1]])
+            .collect(),
+        color_type => {
+            return Err(IoError::PngDecodeError(format!(
+                "cannot expand {color_type:?} to RGBA"
+            )))
+        }
+    };
+
+    Ok(Image::new(
+        [info.width as usize, info.height as usize].into(),
+        buf,
+    )?)
 }
 
 /// Read a PNG image with a single channel (mono16).
 ///
+/// The big-endian samples of the PNG file are converted to native `u16`.
+///
 /// # Arguments
 ///
 /// * `file_path` - The path to the PNG file.
@@ -56,16 +171,58 @@ pub fn read_image_png_rgba8(file_path: impl AsRef<Path>) -> Result<Image<u8, 4>,
 /// # Returns
 ///
 /// A grayscale image with a single channel (mono16).
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, cannot be decoded or is not a
+/// 16-bit grayscale PNG.
 pub fn read_image_png_mono16(file_path: impl AsRef<Path>) -> Result<Image<u16, 1>, IoError> {
-    let (buf, size) = read_png_impl(file_path)?;
+    let mut reader = open_png_reader(file_path)?;
 
-    // convert the buffer to u16
-    let mut buf_u16 = Vec::with_capacity(buf.len() / 2);
-    for chunk in buf.chunks_exact(2) {
-        buf_u16.push(u16::from_be_bytes([chunk[0], chunk[1]]));
+    let info = reader.info();
+    if info.color_type != ColorType::Grayscale || info.bit_depth != BitDepth::Sixteen {
+        return Err(IoError::PngDecodeError(format!(
+            "expected a 16-bit grayscale PNG, got {:?} with {:?} bit depth",
+            info.color_type, info.bit_depth
+        )));
     }
 
-    Ok(Image::new(size.into(), buf_u16)?)
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+
+    // convert the buffer to u16
+    let buf_u16 = buf[..info.buffer_size()]
+        .chunks_exact(2)
+        .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
+        .collect();
+
+    Ok(Image::new(
+        [info.width as usize, info.height as usize].into(),
+        buf_u16,
+    )?)
+}
+
+/// Read a 16-bit grayscale PNG image with a single channel (gray16).
+///
+/// This is the counterpart of [`write_image_png_gray16`], e.g. to load depth
+/// maps, and the same as [`read_image_png_mono16`].
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the PNG file.
+///
+/// # Returns
+///
+/// A grayscale image with a single channel (gray16).
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, cannot be decoded or is not a
+/// 16-bit grayscale PNG.
+pub fn read_image_png_gray16(file_path: impl AsRef<Path>) -> Result<Image<u16, 1>, IoError> {
+    read_image_png_mono16(file_path)
 }
 
 /// Write a grayscale image with a single channel (gray8) to a PNG file.
@@ -93,7 +250,10 @@ pub fn read_image_png_mono16(file_path: impl AsRef<Path>) -> Result<Image<u16, 1
 ///     vec![0, 255],
 /// ).unwrap();
 ///
-/// write_image_png_gray8("output.png", image).unwrap();
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.png");
+///
+/// write_image_png_gray8(&file_path, &image).unwrap();
 /// ```
 pub fn write_image_png_gray8(file_path: impl AsRef<Path>, src: &Image<u8, 1>) -> Result<(), IoError> {
     let file_path = file_path.as_ref();
@@ -144,29 +304,265 @@ pub fn write_image_png_gray8(file_path: impl AsRef<Path>, src: &Image<u8, 1>) ->
 ///     vec![255, 0, 0, 0, 255, 0],
 /// ).unwrap();
 ///
-/// write_image_png_rgb8("output.png", image).unwrap();
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.png");
+///
+/// write_image_png_rgb8(&file_path, &image).unwrap();
 /// ```
 pub fn write_image_png_rgb8(file_path: impl AsRef<Path>, src: &Image<u8, 3>) -> Result<(), IoError> {
     let file_path = file_path.as_ref();
-    
+
     // Create the output file
     let file = File::create(file_path)?;
-    
+
+    write_png_rgb8_to_writer(BufWriter::new(file), src)
+}
+
+/// Write a RGB image with three channels (rgb8) as PNG to a writer.
+///
+/// # Arguments
+///
+/// * `writer` - The destination of the PNG data, e.g. an in-memory buffer or a file.
+/// * `src` - The RGB image to save.
+///
+/// # Returns
+///
+/// `Ok(())` if the image was successfully written, or an error otherwise.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::png::write_png_rgb8_to_writer;
+///
+/// let image = Image::<u8, 3>::new(
+///     ImageSize {
+///         width: 2,
+///         height: 1,
+///     },
+///     vec![255, 0, 0, 0, 255, 0],
+/// ).unwrap();
+///
+/// let mut png_data = Vec::new();
+/// write_png_rgb8_to_writer(&mut png_data, &image).unwrap();
+///
+/// assert!(png_data.starts_with(b"\x89PNG"));
+/// ```
+pub fn write_png_rgb8_to_writer<W: Write>(writer: W, src: &Image<u8, 3>) -> Result<(), IoError> {
     let width = src.width() as u32;
     let height = src.height() as u32;
-    
+
     // Create PNG encoder
-    let mut encoder = Encoder::new(file, width, height);
+    let mut encoder = Encoder::new(writer, width, height);
     encoder.set_color(ColorType::Rgb);
     encoder.set_depth(png::BitDepth::Eight);
-    
+
     let mut writer = encoder.write_header()
         .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
-    
+
     // Write the image data
     writer.write_image_data(src.as_slice())
         .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
-    
+
+    // flush the remaining data to the destination
+    writer.finish()
+        .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+
+    Ok(())
+}
+
+// the minimum number of rows of a band compressed in parallel, to keep the compression ratio
+const MIN_BAND_ROWS: usize = 32;
+
+// the maximum length of the data of a PNG chunk
+const MAX_CHUNK_LEN: usize = (1 << 31) - 1;
+
+// the modulus of the Adler-32 checksum
+const ADLER_MOD: u64 = 65521;
+
+/// Write a RGB image with three channels (rgb8) to a PNG file compressing it in parallel.
+///
+/// The image is split into horizontal bands, one per thread of the rayon pool,
+/// which are filtered and deflated in parallel. The bands are joined into a
+/// single zlib stream by ending each band but the last one with a sync flush,
+/// and each band is written to its own IDAT chunk. The output is a standard PNG
+/// file that any decoder can read, slightly larger than the single-threaded
+/// output since the compression dictionary restarts at each band.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to save the PNG file.
+/// * `src` - The RGB image to save.
+///
+/// # Returns
+///
+/// `Ok(())` if the image was successfully written, or an error otherwise.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::png::write_image_png_rgb8_parallel;
+///
+/// let image = Image::<u8, 3>::new(
+///     ImageSize {
+///         width: 2,
+///         height: 1,
+///     },
+///     vec![255, 0, 0, 0, 255, 0],
+/// ).unwrap();
+///
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.png");
+///
+/// write_image_png_rgb8_parallel(&file_path, &image).unwrap();
+/// ```
+pub fn write_image_png_rgb8_parallel(
+    file_path: impl AsRef<Path>,
+    src: &Image<u8, 3>,
+) -> Result<(), IoError> {
+    let (width, height) = (src.width(), src.height());
+    if width == 0 || height == 0 {
+        return Err(IoError::PngDecodeError(format!(
+            "invalid image size {width}x{height}"
+        )));
+    }
+
+    let row_len = width * 3;
+    let band_rows = height
+        .div_ceil(rayon::current_num_threads())
+        .max(MIN_BAND_ROWS);
+    let num_bands = height.div_ceil(band_rows);
+
+    // filter and deflate each band, keeping its Adler-32 checksum and length
+    let bands = src
+        .as_slice()
+        .par_chunks(band_rows * row_len)
+        .enumerate()
+        .map(|(i, band)| {
+            // the sub filter stores the difference with the pixel on the left
+            let mut filtered = Vec::with_capacity(band.len() + band.len() / row_len);
+            for row in band.chunks_exact(row_len) {
+                filtered.push(1);
+                filtered.extend_from_slice(&row[..3]);
+                filtered.extend(row[3..].iter().zip(row).map(|(&a, &b)| a.wrapping_sub(b)));
+            }
+
+            let is_last = i + 1 == num_bands;
+            let deflated = deflate_band(&filtered, is_last)?;
+
+            Ok((deflated, adler32(&filtered), filtered.len()))
+        })
+        .collect::<Result<Vec<_>, IoError>>()?;
+
+    // the checksum of the whole stream, combining the checksums of the bands
+    let checksum = bands
+        .iter()
+        .skip(1)
+        .fold(bands[0].1, |acc, &(_, adler, len)| {
+            adler32_combine(acc, adler, len)
+        });
+
+    let mut writer = BufWriter::new(File::create(file_path)?);
+    writer.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+    // width, height, bit depth 8, color type rgb, deflate, adaptive filtering, no interlace
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_png_chunk(&mut writer, b"IHDR", &header)?;
+
+    for (i, (deflated, _, _)) in bands.iter().enumerate() {
+        let mut data = Vec::with_capacity(deflated.len() + 6);
+        if i == 0 {
+            // the zlib header: deflate with a 32K window and the default level
+            data.extend_from_slice(&[0x78, 0x9C]);
+        }
+        data.extend_from_slice(deflated);
+        if i + 1 == bands.len() {
+            data.extend_from_slice(&checksum.to_be_bytes());
+        }
+
+        for chunk in data.chunks(MAX_CHUNK_LEN) {
+            write_png_chunk(&mut writer, b"IDAT", chunk)?;
+        }
+    }
+
+    write_png_chunk(&mut writer, b"IEND", &[])?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+// deflate a band as raw data ending with a sync flush, or with the final block if it is the last one
+fn deflate_band(data: &[u8], is_last: bool) -> Result<Vec<u8>, IoError> {
+    let mut compress = Compress::new(Compression::default(), false);
+    let mut output = Vec::with_capacity(data.len() / 2 + 64);
+    let flush = if is_last {
+        FlushCompress::Finish
+    } else {
+        FlushCompress::Sync
+    };
+
+    loop {
+        if output.len() == output.capacity() {
+            output.reserve(output.capacity().max(1024));
+        }
+
+        let consumed = compress.total_in() as usize;
+        let status = compress
+            .compress_vec(&data[consumed..], &mut output, flush)
+            .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+
+        // the flush is complete once all the input is consumed with room left in the output
+        let flushed = compress.total_in() as usize == data.len()
+            && output.len() < output.capacity()
+            && !is_last;
+        if status == Status::StreamEnd || flushed {
+            return Ok(output);
+        }
+    }
+}
+
+// the Adler-32 checksum of the zlib format
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u64, 0u64);
+    // the sums are reduced every 4096 bytes, well before they can overflow
+    for block in data.chunks(4096) {
+        for &v in block {
+            a += v as u64;
+            b += a;
+        }
+        a %= ADLER_MOD;
+        b %= ADLER_MOD;
+    }
+    ((b << 16) | a) as u32
+}
+
+// combine the Adler-32 checksums of two consecutive blocks given the length of the second one
+fn adler32_combine(adler1: u32, adler2: u32, len2: usize) -> u32 {
+    let (a1, b1) = ((adler1 & 0xFFFF) as u64, (adler1 >> 16) as u64);
+    let (a2, b2) = ((adler2 & 0xFFFF) as u64, (adler2 >> 16) as u64);
+    let rem = len2 as u64 % ADLER_MOD;
+
+    let a = (a1 + a2 + ADLER_MOD - 1) % ADLER_MOD;
+    let b = (b1 + b2 + rem * a1 + ADLER_MOD - rem) % ADLER_MOD;
+
+    ((b << 16) | a) as u32
+}
+
+// write a PNG chunk with its length, type, data and CRC
+fn write_png_chunk<W: Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> Result<(), IoError> {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(kind);
+    hasher.update(data);
+
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+    writer.write_all(&hasher.finalize().to_be_bytes())?;
+
     Ok(())
 }
 
@@ -195,7 +591,10 @@ pub fn write_image_png_rgb8(file_path: impl AsRef<Path>, src: &Image<u8, 3>) ->
 ///     vec![255, 0, 0, 255, 0, 255, 0, 128],
 /// ).unwrap();
 ///
-/// write_image_png_rgba8("output.png", image).unwrap();
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.png");
+///
+/// write_image_png_rgba8(&file_path, &image).unwrap();
 /// ```
 pub fn write_image_png_rgba8(file_path: impl AsRef<Path>, src: &Image<u8, 4>) -> Result<(), IoError> {
     let file_path = file_path.as_ref();
@@ -246,7 +645,10 @@ pub fn write_image_png_rgba8(file_path: impl AsRef<Path>, src: &Image<u8, 4>) ->
 ///     vec![0, 65535],
 /// ).unwrap();
 ///
-/// write_image_png_gray16("output.png", image).unwrap();
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.png");
+///
+/// write_image_png_gray16(&file_path, &image).unwrap();
 /// ```
 pub fn write_image_png_gray16(file_path: impl AsRef<Path>, src: &Image<u16, 1>) -> Result<(), IoError> {
     let file_path = file_path.as_ref();
@@ -279,8 +681,105 @@ pub fn write_image_png_gray16(file_path: impl AsRef<Path>, src: &Image<u16, 1>)
     Ok(())
 }
 
-// utility function to read the png file
-fn read_png_impl(file_path: impl AsRef<Path>) -> Result<(Vec<u8>, [usize; 2]), IoError> {
+/// Write an animated PNG (APNG) from a sequence of RGBA frames.
+///
+/// The frames are written as a sequence of `fcTL`/`fdAT` chunks after the `acTL`
+/// animation control chunk. The first frame is also the default image shown by
+/// decoders without APNG support.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to save the APNG file.
+/// * `frames` - The frames with their delay in centiseconds. All the frames must
+///   have the same size.
+/// * `loops` - The number of times to play the animation, 0 to loop forever.
+///
+/// # Returns
+///
+/// `Ok(())` if the animation was successfully written, or an error otherwise.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::png::write_apng_rgba8;
+///
+/// let size = ImageSize {
+///     width: 2,
+///     height: 2,
+/// };
+///
+/// let frames = vec![
+///     (Image::<u8, 4>::from_size_val(size, 0).unwrap(), 10),
+///     (Image::<u8, 4>::from_size_val(size, 255).unwrap(), 10),
+/// ];
+///
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.png");
+///
+/// write_apng_rgba8(&file_path, &frames, 0).unwrap();
+/// ```
+pub fn write_apng_rgba8(
+    file_path: impl AsRef<Path>,
+    frames: &[(Image<u8, 4>, u16)],
+    loops: u32,
+) -> Result<(), IoError> {
+    let (first, _) = frames
+        .first()
+        .ok_or_else(|| IoError::PngDecodeError("APNG requires at least one frame".to_string()))?;
+
+    // all the frames must have the size of the canvas
+    for (frame, _) in frames.iter() {
+        if frame.size() != first.size() {
+            return Err(ImageError::InvalidImageSize(
+                frame.cols(),
+                frame.rows(),
+                first.cols(),
+                first.rows(),
+            )
+            .into());
+        }
+    }
+
+    let file = File::create(file_path.as_ref())?;
+
+    let mut encoder = Encoder::new(file, first.width() as u32, first.height() as u32);
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .set_animated(frames.len() as u32, loops)
+        .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+
+    for (frame, delay) in frames.iter() {
+        writer
+            .set_frame_delay(*delay, 100)
+            .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+        writer
+            .write_image_data(frame.as_slice())
+            .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+    }
+
+    writer
+        .finish()
+        .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+
+    Ok(())
+}
+
+// utility function to open the png file and read its header
+fn open_png_reader(file_path: impl AsRef<Path>) -> Result<Reader<File>, IoError> {
+    open_png_reader_with(file_path, Transformations::IDENTITY)
+}
+
+// open a png file applying the given transformations to the decoded rows
+fn open_png_reader_with(
+    file_path: impl AsRef<Path>,
+    transformations: Transformations,
+) -> Result<Reader<File>, IoError> {
     // verify the file exists
     let file_path = file_path.as_ref();
     if !file_path.exists() {
@@ -296,10 +795,16 @@ fn read_png_impl(file_path: impl AsRef<Path>) -> Result<(Vec<u8>, [usize; 2]), I
         return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
     }
 
-    let file = File::open(file_path)?;
-    let mut reader = Decoder::new(file)
+    let mut decoder = Decoder::new(File::open(file_path)?);
+    decoder.set_transformations(transformations);
+    decoder
         .read_info()
-        .map_err(|e| IoError::PngDecodeError(e.to_string()))?;
+        .map_err(|e| IoError::PngDecodeError(e.to_string()))
+}
+
+// utility function to read the png file
+fn read_png_impl(file_path: impl AsRef<Path>) -> Result<(Vec<u8>, [usize; 2]), IoError> {
+    let mut reader = open_png_reader(file_path)?;
 
     let mut buf = vec![0; reader.output_buffer_size()];
     let info = reader
@@ -322,10 +827,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn read_png_rgb8_with_progress() -> Result<(), IoError> {
+        use crate::png::{read_image_png_rgb8, read_image_png_rgb8_with_progress};
+        use kornia_image::{Image, ImageSize};
+
+        let temp_dir = tempfile::tempdir()?;
+        let file_path = temp_dir.path().join("tall.png");
+
+        // a tall image with a gradient along the rows
+        let size = ImageSize {
+            width: 3,
+            height: 100,
+        };
+        let data = (0..size.width * size.height * 3)
+            .map(|i| (i / 9) as u8)
+            .collect();
+        let image = Image::<u8, 3>::new(size, data)?;
+        super::write_image_png_rgb8(&file_path, &image)?;
+
+        let mut progress = Vec::new();
+        let decoded = read_image_png_rgb8_with_progress(&file_path, |p| progress.push(p))?;
+
+        assert_eq!(decoded.as_slice(), image.as_slice());
+        assert_eq!(
+            decoded.as_slice(),
+            read_image_png_rgb8(&file_path)?.as_slice()
+        );
+
+        // one call per row, non-decreasing and ending at 1.0
+        assert_eq!(progress.len(), size.height);
+        assert!(progress.windows(2).all(|w| w[0] <= w[1]));
+        assert!(progress.iter().all(|p| (0.0..=1.0).contains(p)));
+        assert_eq!(progress.last(), Some(&1.0));
+
+        let result = read_image_png_rgb8_with_progress("../../tests/data/dog.png", |_| {});
+        assert!(matches!(result, Err(IoError::PngDecodeError(_))));
+
+        Ok(())
+    }
+
     #[test]
     fn write_read_png_gray8() -> Result<(), IoError> {
         use kornia_image::{Image, ImageSize};
-        use std::path::PathBuf;
         use tempfile::tempdir;
         use crate::png::{write_image_png_gray8, read_image_png_mono8};
         
@@ -343,7 +887,7 @@ mod tests {
         )?;
         
         // Write the image to a file
-        write_image_png_gray8(&file_path, image.clone())?;
+        write_image_png_gray8(&file_path, &image)?;
         
         // Read the image back
         let read_image = read_image_png_mono8(&file_path)?;
@@ -355,6 +899,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_png_rgb8_to_writer() -> Result<(), IoError> {
+        use kornia_image::{Image, ImageSize};
+        use crate::png::{read_image_png_rgb8, write_png_rgb8_to_writer};
+
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            (0..12).collect(),
+        )?;
+
+        // encode into memory
+        let mut png_data = Vec::new();
+        write_png_rgb8_to_writer(&mut png_data, &image)?;
+        assert!(png_data.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        let temp_dir = tempfile::tempdir()?;
+        let file_path = temp_dir.path().join("in_memory.png");
+        std::fs::write(&file_path, &png_data)?;
+
+        let image_back = read_image_png_rgb8(&file_path)?;
+        assert_eq!(image_back.size(), image.size());
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
     #[test]
     fn write_read_png_rgb8() -> Result<(), IoError> {
         use kornia_image::{Image, ImageSize};
@@ -380,7 +953,7 @@ mod tests {
         )?;
         
         // Write the image to a file
-        write_image_png_rgb8(&file_path, image.clone())?;
+        write_image_png_rgb8(&file_path, &image)?;
         
         // Read the image back
         let read_image = read_image_png_rgb8(&file_path)?;
@@ -392,6 +965,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_read_png_rgb8_parallel() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::png::{
+            read_image_png_rgb8, write_image_png_rgb8, write_image_png_rgb8_parallel,
+        };
+        use kornia_image::{Image, ImageSize};
+
+        let temp_dir = tempfile::tempdir()?;
+
+        // a large image with gradients and some texture
+        let size = ImageSize {
+            width: 1024,
+            height: 771,
+        };
+        let data = (0..size.width * size.height)
+            .flat_map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                [(x / 4) as u8, (y / 3) as u8, ((x * y) % 251) as u8]
+            })
+            .collect();
+        let image = Image::<u8, 3>::new(size, data)?;
+
+        // compress the bands on a pool with a fixed number of threads
+        let parallel_path = temp_dir.path().join("parallel.png");
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()?
+            .install(|| write_image_png_rgb8_parallel(&parallel_path, &image))?;
+
+        // one IDAT chunk per band
+        let png_data = std::fs::read(&parallel_path)?;
+        let num_idat = png_data.windows(4).filter(|w| w == b"IDAT").count();
+        assert_eq!(num_idat, 4);
+
+        let serial_path = temp_dir.path().join("serial.png");
+        write_image_png_rgb8(&serial_path, &image)?;
+
+        let parallel = read_image_png_rgb8(&parallel_path)?;
+        let serial = read_image_png_rgb8(&serial_path)?;
+        assert_eq!(parallel.size(), size);
+        assert_eq!(parallel.as_slice(), serial.as_slice());
+        assert_eq!(parallel.as_slice(), image.as_slice());
+
+        // the image crate decodes it too
+        let decoded = image::open(&parallel_path)?.to_rgb8();
+        assert_eq!(decoded.as_raw().as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
     #[test]
     fn write_read_png_rgba8() -> Result<(), IoError> {
         use kornia_image::{Image, ImageSize};
@@ -415,7 +1038,7 @@ mod tests {
         )?;
         
         // Write the image to a file
-        write_image_png_rgba8(&file_path, image.clone())?;
+        write_image_png_rgba8(&file_path, &image)?;
         
         // Read the image back
         let read_image = read_image_png_rgba8(&file_path)?;
@@ -447,7 +1070,7 @@ mod tests {
         )?;
         
         // Write the image to a file
-        write_image_png_gray16(&file_path, image.clone())?;
+        write_image_png_gray16(&file_path, &image)?;
         
         // Read the image back
         let read_image = read_image_png_mono16(&file_path)?;
@@ -458,4 +1081,99 @@ mod tests {
         
         Ok(())
     }
+
+    #[test]
+    fn write_read_png_gray16_gradient() -> Result<(), IoError> {
+        use crate::png::{read_image_png_gray16, write_image_png_gray16, write_image_png_gray8};
+        use kornia_image::{Image, ImageSize};
+
+        let temp_dir = tempfile::tempdir()?;
+        let file_path = temp_dir.path().join("depth.png");
+
+        // a gradient covering the full 16-bit range
+        let size = ImageSize {
+            width: 64,
+            height: 32,
+        };
+        let data = (0..size.width * size.height)
+            .map(|i| (i * 65535 / (size.width * size.height - 1)) as u16)
+            .collect();
+        let image = Image::<u16, 1>::new(size, data)?;
+
+        write_image_png_gray16(&file_path, &image)?;
+        let read_image = read_image_png_gray16(&file_path)?;
+        assert_eq!(read_image.size(), size);
+        assert_eq!(read_image.as_slice(), image.as_slice());
+
+        // an 8-bit grayscale PNG is rejected
+        let file_path = temp_dir.path().join("gray8.png");
+        write_image_png_gray8(&file_path, &Image::<u8, 1>::from_size_val(size, 7)?)?;
+        let result = read_image_png_gray16(&file_path);
+        assert!(matches!(result, Err(IoError::PngDecodeError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_apng_rgba8() -> Result<(), IoError> {
+        use crate::png::write_apng_rgba8;
+        use kornia_image::{Image, ImageSize};
+
+        let temp_dir = tempfile::tempdir()?;
+        let file_path = temp_dir.path().join("anim.png");
+
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let frames = [0u8, 128, 255]
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| Ok((Image::<u8, 4>::from_size_val(size, v)?, 10 * (i as u16 + 1))))
+            .collect::<Result<Vec<_>, IoError>>()?;
+
+        write_apng_rgba8(&file_path, &frames, 3)?;
+
+        // walk the chunks after the png signature
+        let bytes = std::fs::read(&file_path)?;
+        let mut chunks = Vec::new();
+        let mut pos = 8;
+        while pos + 8 <= bytes.len() {
+            let len = u32::from_be_bytes(bytes[pos..pos + 4].try_into().unwrap()) as usize;
+            let kind = std::str::from_utf8(&bytes[pos + 4..pos + 8])
+                .unwrap()
+                .to_string();
+            chunks.push((kind, bytes[pos + 8..pos + 8 + len].to_vec()));
+            pos += 12 + len;
+        }
+        let kinds = chunks.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+
+        // animation control: 3 frames played 3 times
+        let (_, actl) = chunks.iter().find(|(k, _)| k == "acTL").unwrap();
+        assert_eq!(&actl[..4], &3u32.to_be_bytes());
+        assert_eq!(&actl[4..], &3u32.to_be_bytes());
+
+        // the first frame control precedes the default image
+        let fctl = kinds.iter().position(|&k| k == "fcTL").unwrap();
+        let idat = kinds.iter().position(|&k| k == "IDAT").unwrap();
+        assert!(fctl < idat);
+
+        assert_eq!(kinds.iter().filter(|&&k| k == "fcTL").count(), 3);
+        assert!(kinds.iter().filter(|&&k| k == "fdAT").count() >= 2);
+        assert_eq!(kinds.last(), Some(&"IEND"));
+
+        // the delays of the frames are stored in centiseconds
+        let delays = chunks
+            .iter()
+            .filter(|(k, _)| k == "fcTL")
+            .map(|(_, d)| {
+                let num = u16::from_be_bytes([d[20], d[21]]);
+                let den = u16::from_be_bytes([d[22], d[23]]);
+                (num, den)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![(10, 100), (20, 100), (30, 100)]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,393 @@
+use std::path::Path;
+
+use kornia_image::{Image, ImageSize};
+
+use crate::error::IoError;
+
+// the QOI specification: https://qoiformat.org/qoi-specification.pdf
+const QOI_MAGIC: &[u8; 4] = b"qoif";
+const QOI_HEADER_SIZE: usize = 14;
+const QOI_END_MARKER: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+
+const QOI_OP_INDEX: u8 = 0x00; // 00xxxxxx
+const QOI_OP_DIFF: u8 = 0x40; // 01xxxxxx
+const QOI_OP_LUMA: u8 = 0x80; // 10xxxxxx
+const QOI_OP_RUN: u8 = 0xc0; // 11xxxxxx
+const QOI_OP_RGB: u8 = 0xfe; // 11111110
+const QOI_OP_RGBA: u8 = 0xff; // 11111111
+const QOI_MASK_2: u8 = 0xc0; // 11000000
+
+// the maximum run length stored in a single QOI_OP_RUN chunk
+const QOI_MAX_RUN: u8 = 62;
+
+// the maximum number of pixels accepted by the decoder, as in the reference implementation
+const QOI_PIXELS_MAX: usize = 400_000_000;
+
+/// Read a QOI image with three channels (rgb8).
+///
+/// If the file stores an alpha channel it is discarded.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the QOI file.
+///
+/// # Returns
+///
+/// A RGB image with three channels (rgb8).
+pub fn read_image_qoi_rgb8(file_path: impl AsRef<Path>) -> Result<Image<u8, 3>, IoError> {
+    read_qoi_impl(file_path)
+}
+
+/// Read a QOI image with four channels (rgba8).
+///
+/// If the file has no alpha channel, the alpha of every pixel is set to 255.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the QOI file.
+///
+/// # Returns
+///
+/// A RGBA image with four channels (rgba8).
+pub fn read_image_qoi_rgba8(file_path: impl AsRef<Path>) -> Result<Image<u8, 4>, IoError> {
+    read_qoi_impl(file_path)
+}
+
+/// Write a RGB image with three channels (rgb8) to a QOI file.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to save the QOI file.
+/// * `src` - The RGB image to save.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::qoi::{read_image_qoi_rgb8, write_image_qoi_rgb8};
+///
+/// let image = Image::<u8, 3>::new(
+///     ImageSize {
+///         width: 2,
+///         height: 1,
+///     },
+///     vec![255, 0, 0, 0, 0, 255],
+/// ).unwrap();
+///
+/// let tmp_dir = tempfile::tempdir().unwrap();
+/// let file_path = tmp_dir.path().join("output.qoi");
+///
+/// write_image_qoi_rgb8(&file_path, &image).unwrap();
+///
+/// let image_back = read_image_qoi_rgb8(&file_path).unwrap();
+/// assert_eq!(image_back.as_slice(), image.as_slice());
+/// ```
+pub fn write_image_qoi_rgb8(
+    file_path: impl AsRef<Path>,
+    src: &Image<u8, 3>,
+) -> Result<(), IoError> {
+    write_qoi_impl(file_path, src)
+}
+
+/// Write a RGBA image with four channels (rgba8) to a QOI file.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to save the QOI file.
+/// * `src` - The RGBA image to save.
+pub fn write_image_qoi_rgba8(
+    file_path: impl AsRef<Path>,
+    src: &Image<u8, 4>,
+) -> Result<(), IoError> {
+    write_qoi_impl(file_path, src)
+}
+
+// compute the position of a pixel in the array of previously seen pixels
+fn qoi_hash(px: [u8; 4]) -> usize {
+    let [r, g, b, a] = px.map(|v| v as usize);
+    (r * 3 + g * 5 + b * 7 + a * 11) % 64
+}
+
+// encode the interleaved pixels with `C` channels (3 or 4) into a QOI stream
+fn encode_qoi<const C: usize>(pixels: &[u8], size: ImageSize) -> Result<Vec<u8>, IoError> {
+    let width = u32::try_from(size.width)
+        .map_err(|_| IoError::QoiError(format!("image width {} is too large", size.width)))?;
+    let height = u32::try_from(size.height)
+        .map_err(|_| IoError::QoiError(format!("image height {} is too large", size.height)))?;
+
+    let num_pixels = size.width * size.height;
+    let mut bytes = Vec::with_capacity(QOI_HEADER_SIZE + num_pixels * (C + 1) + 8);
+
+    // write the header
+    bytes.extend_from_slice(QOI_MAGIC);
+    bytes.extend_from_slice(&width.to_be_bytes());
+    bytes.extend_from_slice(&height.to_be_bytes());
+    bytes.push(C as u8);
+    bytes.push(0); // sRGB with linear alpha
+
+    let mut index = [[0u8; 4]; 64];
+    let mut prev = [0u8, 0, 0, 255];
+    let mut run = 0u8;
+
+    for (i, chunk) in pixels.chunks_exact(C).enumerate() {
+        let mut px = prev;
+        px[..C].copy_from_slice(chunk);
+
+        if px == prev {
+            run += 1;
+            if run == QOI_MAX_RUN || i == num_pixels - 1 {
+                bytes.push(QOI_OP_RUN | (run - 1));
+                run = 0;
+            }
+            continue;
+        }
+
+        if run > 0 {
+            bytes.push(QOI_OP_RUN | (run - 1));
+            run = 0;
+        }
+
+        let index_pos = qoi_hash(px);
+        if index[index_pos] == px {
+            bytes.push(QOI_OP_INDEX | index_pos as u8);
+        } else {
+            index[index_pos] = px;
+
+            if px[3] == prev[3] {
+                let vr = px[0].wrapping_sub(prev[0]) as i8;
+                let vg = px[1].wrapping_sub(prev[1]) as i8;
+                let vb = px[2].wrapping_sub(prev[2]) as i8;
+                let vg_r = vr.wrapping_sub(vg);
+                let vg_b = vb.wrapping_sub(vg);
+
+                if (-2..=1).contains(&vr) && (-2..=1).contains(&vg) && (-2..=1).contains(&vb) {
+                    bytes.push(
+                        QOI_OP_DIFF
+                            | ((vr + 2) as u8) << 4
+                            | ((vg + 2) as u8) << 2
+                            | (vb + 2) as u8,
+                    );
+                } else if (-32..=31).contains(&vg)
+                    && (-8..=7).contains(&vg_r)
+                    && (-8..=7).contains(&vg_b)
+                {
+                    bytes.push(QOI_OP_LUMA | (vg + 32) as u8);
+                    bytes.push(((vg_r + 8) as u8) << 4 | (vg_b + 8) as u8);
+                } else {
+                    bytes.extend_from_slice(&[QOI_OP_RGB, px[0], px[1], px[2]]);
+                }
+            } else {
+                bytes.extend_from_slice(&[QOI_OP_RGBA, px[0], px[1], px[2], px[3]]);
+            }
+        }
+
+        prev = px;
+    }
+
+    bytes.extend_from_slice(&QOI_END_MARKER);
+
+    Ok(bytes)
+}
+
+// decode a QOI stream into interleaved pixels with `C` channels (3 or 4)
+fn decode_qoi<const C: usize>(bytes: &[u8]) -> Result<(Vec<u8>, ImageSize), IoError> {
+    if bytes.len() < QOI_HEADER_SIZE + QOI_END_MARKER.len() || &bytes[..4] != QOI_MAGIC {
+        return Err(IoError::QoiError("invalid QOI header".to_string()));
+    }
+
+    let width = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+    let height = u32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize;
+    let channels = bytes[12];
+    let colorspace = bytes[13];
+
+    if width == 0
+        || height == 0
+        || !(3..=4).contains(&channels)
+        || colorspace > 1
+        || height >= QOI_PIXELS_MAX / width
+    {
+        return Err(IoError::QoiError("invalid QOI header".to_string()));
+    }
+
+    let num_pixels = width * height;
+    let chunks = &bytes[QOI_HEADER_SIZE..bytes.len() - QOI_END_MARKER.len()];
+
+    let mut pixels = Vec::with_capacity(num_pixels * C);
+    let mut index = [[0u8; 4]; 64];
+    let mut px = [0u8, 0, 0, 255];
+    let mut run = 0u8;
+    let mut pos = 0;
+
+    // read the next `n` bytes of the chunks
+    let mut take = |n: usize| -> Result<&[u8], IoError> {
+        let data = chunks
+            .get(pos..pos + n)
+            .ok_or_else(|| IoError::QoiError("unexpected end of QOI data".to_string()))?;
+        pos += n;
+        Ok(data)
+    };
+
+    for _ in 0..num_pixels {
+        if run > 0 {
+            run -= 1;
+        } else {
+            let b1 = take(1)?[0];
+
+            if b1 == QOI_OP_RGB {
+                px[..3].copy_from_slice(take(3)?);
+            } else if b1 == QOI_OP_RGBA {
+                px.copy_from_slice(take(4)?);
+            } else {
+                match b1 & QOI_MASK_2 {
+                    QOI_OP_INDEX => px = index[b1 as usize],
+                    QOI_OP_DIFF => {
+                        px[0] = px[0].wrapping_add(((b1 >> 4) & 0x03).wrapping_sub(2));
+                        px[1] = px[1].wrapping_add(((b1 >> 2) & 0x03).wrapping_sub(2));
+                        px[2] = px[2].wrapping_add((b1 & 0x03).wrapping_sub(2));
+                    }
+                    QOI_OP_LUMA => {
+                        let b2 = take(1)?[0];
+                        let vg = (b1 & 0x3f).wrapping_sub(32);
+                        px[0] = px[0].wrapping_add(vg.wrapping_sub(8).wrapping_add(b2 >> 4));
+                        px[1] = px[1].wrapping_add(vg);
+                        px[2] = px[2].wrapping_add(vg.wrapping_sub(8).wrapping_add(b2 & 0x0f));
+                    }
+                    _ => run = b1 & 0x3f,
+                }
+            }
+
+            index[qoi_hash(px)] = px;
+        }
+
+        pixels.extend_from_slice(&px[..C]);
+    }
+
+    Ok((pixels, ImageSize { width, height }))
+}
+
+// verify the file exists and has the qoi extension
+fn check_qoi_path(file_path: &Path) -> Result<(), IoError> {
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+
+    if file_path
+        .extension()
+        .map_or(true, |ext| !ext.eq_ignore_ascii_case("qoi"))
+    {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+
+    Ok(())
+}
+
+// utility function to read the qoi file
+fn read_qoi_impl<const C: usize>(file_path: impl AsRef<Path>) -> Result<Image<u8, C>, IoError> {
+    let file_path = file_path.as_ref();
+    check_qoi_path(file_path)?;
+
+    let bytes = std::fs::read(file_path)?;
+    let (pixels, size) = decode_qoi::<C>(&bytes)?;
+
+    Ok(Image::new(size, pixels)?)
+}
+
+// utility function to write the qoi file
+fn write_qoi_impl<const C: usize>(
+    file_path: impl AsRef<Path>,
+    src: &Image<u8, C>,
+) -> Result<(), IoError> {
+    let bytes = encode_qoi::<C>(src.as_slice(), src.size())?;
+    std::fs::write(file_path, bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use crate::qoi::{
+        read_image_qoi_rgb8, read_image_qoi_rgba8, write_image_qoi_rgb8, write_image_qoi_rgba8,
+    };
+    use kornia_image::{Image, ImageSize};
+
+    // generate pixels that exercise all the QOI chunk types
+    fn test_pixels(size: ImageSize, channels: usize) -> Vec<u8> {
+        (0..size.width * size.height)
+            .flat_map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                let px = match y % 4 {
+                    // flat rows produce runs
+                    0 => [10, 20, 30, 255],
+                    // small steps produce diffs and lumas
+                    1 => [(x * 2) as u8, (x * 3) as u8, (x * 4) as u8, 255],
+                    // noisy rows produce full rgb(a) and index chunks
+                    2 => [
+                        (x * 37) as u8,
+                        (x * 91) as u8,
+                        (x * 13) as u8,
+                        (x * 7) as u8,
+                    ],
+                    _ => [(x % 3 * 80) as u8, 0, 255, (x % 2 * 255) as u8],
+                };
+                px.into_iter().take(channels)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn write_read_qoi_rgb8() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("image.qoi");
+
+        let size = ImageSize {
+            width: 100,
+            height: 12,
+        };
+        let image = Image::<u8, 3>::new(size, test_pixels(size, 3))?;
+
+        write_image_qoi_rgb8(&file_path, &image)?;
+        let image_back = read_image_qoi_rgb8(&file_path)?;
+        assert_eq!(image_back.size(), image.size());
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        // rgb files are read as opaque rgba
+        let image_rgba = read_image_qoi_rgba8(&file_path)?;
+        assert!(image_rgba.as_slice().chunks_exact(4).all(|px| px[3] == 255));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_read_qoi_rgba8() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("image.qoi");
+
+        let size = ImageSize {
+            width: 100,
+            height: 12,
+        };
+        let image = Image::<u8, 4>::new(size, test_pixels(size, 4))?;
+
+        write_image_qoi_rgba8(&file_path, &image)?;
+        let image_back = read_image_qoi_rgba8(&file_path)?;
+        assert_eq!(image_back.size(), image.size());
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_qoi_invalid() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("image.qoi");
+        std::fs::write(&file_path, b"qoif truncated")?;
+
+        let result = read_image_qoi_rgb8(&file_path);
+        assert!(matches!(result, Err(IoError::QoiError(_))));
+
+        let result = read_image_qoi_rgb8("../../tests/data/dog.png");
+        assert!(matches!(result, Err(IoError::InvalidFileExtension(_))));
+
+        Ok(())
+    }
+}
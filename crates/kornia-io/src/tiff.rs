@@ -0,0 +1,164 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+use kornia_image::{Image, ImageSize};
+use tiff::{
+    decoder::{Decoder, DecodingResult},
+    encoder::{colortype, TiffEncoder},
+    ColorType, TiffError, TiffUnsupportedError,
+};
+
+use crate::error::IoError;
+
+// verify the path has a TIFF extension
+fn check_tiff_extension(file_path: &Path) -> Result<(), IoError> {
+    if file_path.extension().map_or(true, |ext| {
+        !ext.eq_ignore_ascii_case("tif") && !ext.eq_ignore_ascii_case("tiff")
+    }) {
+        return Err(IoError::InvalidFileExtension(file_path.to_path_buf()));
+    }
+    Ok(())
+}
+
+/// Read all the pages of a multi-page TIFF image as RGB8 images.
+///
+/// Each page is stored in its own image file directory (IFD) and may have a
+/// different size, e.g. the frames of a microscopy stack.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the TIFF file.
+///
+/// # Returns
+///
+/// A vector with the image of each page in order.
+///
+/// # Errors
+///
+/// Returns an error if the file does not exist, does not have a `tif` or
+/// `tiff` extension, cannot be decoded or has a page that is not 8-bit RGB.
+pub fn read_tiff_stack_rgb8(file_path: impl AsRef<Path>) -> Result<Vec<Image<u8, 3>>, IoError> {
+    let file_path = file_path.as_ref();
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+    check_tiff_extension(file_path)?;
+
+    let mut decoder = Decoder::new(BufReader::new(File::open(file_path)?))?;
+
+    let mut pages = Vec::new();
+    loop {
+        let color_type = decoder.colortype()?;
+        if color_type != ColorType::RGB(8) {
+            return Err(
+                TiffError::UnsupportedError(TiffUnsupportedError::UnsupportedColorType(color_type))
+                    .into(),
+            );
+        }
+
+        let (width, height) = decoder.dimensions()?;
+        let DecodingResult::U8(data) = decoder.read_image()? else {
+            return Err(IoError::CorruptImageData(
+                "expected 8-bit TIFF samples".to_string(),
+            ));
+        };
+
+        let size = ImageSize {
+            width: width as usize,
+            height: height as usize,
+        };
+        pages.push(Image::new(size, data)?);
+
+        if !decoder.more_images() {
+            break;
+        }
+        decoder.next_image()?;
+    }
+
+    Ok(pages)
+}
+
+/// Write RGB8 images as the pages of a multi-page TIFF image.
+///
+/// Each image is written uncompressed to its own image file directory (IFD)
+/// in order, and the images may have different sizes.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the TIFF file.
+/// * `images` - The images of the pages.
+///
+/// # Errors
+///
+/// Returns an error if the path does not have a `tif` or `tiff` extension, no
+/// images are given or the file cannot be written.
+pub fn write_tiff_stack_rgb8(
+    file_path: impl AsRef<Path>,
+    images: &[Image<u8, 3>],
+) -> Result<(), IoError> {
+    let file_path = file_path.as_ref();
+    check_tiff_extension(file_path)?;
+
+    if images.is_empty() {
+        return Err(IoError::CorruptImageData(
+            "a TIFF stack needs at least one page".to_string(),
+        ));
+    }
+
+    let mut encoder = TiffEncoder::new(BufWriter::new(File::create(file_path)?))?;
+    for image in images {
+        encoder.write_image::<colortype::RGB8>(
+            image.width() as u32,
+            image.height() as u32,
+            image.as_slice(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IoError;
+    use kornia_image::{Image, ImageSize};
+
+    #[test]
+    fn write_read_tiff_stack() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("stack.tiff");
+
+        // three pages of different sizes with a different gradient each
+        let sizes = [(8, 6), (5, 7), (16, 2)];
+        let images = sizes
+            .iter()
+            .enumerate()
+            .map(|(page, &(width, height))| {
+                let data = (0..width * height * 3)
+                    .map(|i| (i * (page + 1)) as u8)
+                    .collect();
+                Image::<u8, 3>::new(ImageSize { width, height }, data)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        super::write_tiff_stack_rgb8(&file_path, &images)?;
+
+        let pages = super::read_tiff_stack_rgb8(&file_path)?;
+        assert_eq!(pages.len(), 3);
+        for (page, image) in pages.iter().zip(&images) {
+            assert_eq!(page.size(), image.size());
+            assert_eq!(page.as_slice(), image.as_slice());
+        }
+
+        assert!(super::write_tiff_stack_rgb8(tmp_dir.path().join("stack.png"), &images).is_err());
+        assert!(super::write_tiff_stack_rgb8(&file_path, &[]).is_err());
+        assert!(matches!(
+            super::read_tiff_stack_rgb8(tmp_dir.path().join("missing.tif")),
+            Err(IoError::FileDoesNotExist(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -21,7 +21,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // compute the histogram per channel
     let histograms = image
-        .split_channels()?
+        .split_channels()
         .iter()
         .map(|ch| {
             let mut hist = vec![0; 256];